@@ -25,12 +25,20 @@ fn main() -> Result<(), anyhow::Error> {
         "abi/curve/child_registries/StableSwapFactoryNG.abi.json",
         "abi/curve/child_registries/TriCryptoFactoryNG.abi.json",
         "abi/curve/child_registries/TwoCryptoFactory.abi.json",
+        "abi/curve/crvusd/Controller.abi.json",
+        "abi/curve/crvusd/ControllerFactory.abi.json",
+        "abi/curve/crvusd/LLAMMA.abi.json",
+        "abi/curve/crvusd/PegKeeper.abi.json",
+        "abi/curve/gauges/ChildGaugeFactory.abi.json",
         "abi/curve/gauges/LiquidityGaugeV1.abi.json",
         "abi/curve/gauges/LiquidityGaugeV2.abi.json",
         "abi/curve/gauges/LiquidityGaugeV3.abi.json",
         "abi/curve/gauges/LiquidityGaugeV4.abi.json",
         "abi/curve/gauges/LiquidityGaugeV5.abi.json",
         "abi/curve/gauges/LiquidityGaugeV6.abi.json",
+        "abi/curve/gauges/RootGaugeFactory.abi.json",
+        "abi/curve/lending/OneWayLendingFactory.abi.json",
+        "abi/curve/lending/Vault.abi.json",
         "abi/curve/ownership_proxies/FactoryOwner.abi.json",
         "abi/curve/ownership_proxies/GaugeManager.abi.json",
         "abi/curve/ownership_proxies/GaugeManagerOld.abi.json",
@@ -40,8 +48,10 @@ fn main() -> Result<(), anyhow::Error> {
         "abi/curve/AddressProvider.abi.json",
         "abi/curve/CRVToken.abi.json",
         "abi/curve/GaugeController.abi.json",
+        "abi/curve/Minter.abi.json",
         "abi/curve/Pool.abi.json",
         "abi/curve/Registry.abi.json",
+        "abi/curve/VotingEscrow.abi.json",
         "abi/oracle/CurveCalculations.abi.json",
         "abi/oracle/Inch.abi.json",
         "abi/oracle/SushiSwap.abi.json",
@@ -61,12 +71,20 @@ fn main() -> Result<(), anyhow::Error> {
         "src/abi/curve/child_registries/stable_swap_factory_ng.rs",
         "src/abi/curve/child_registries/tricrypto_factory_ng.rs",
         "src/abi/curve/child_registries/twocrypto_factory.rs",
+        "src/abi/curve/crvusd/controller.rs",
+        "src/abi/curve/crvusd/controller_factory.rs",
+        "src/abi/curve/crvusd/llamma.rs",
+        "src/abi/curve/crvusd/peg_keeper.rs",
+        "src/abi/curve/gauges/child_gauge_factory.rs",
         "src/abi/curve/gauges/liquidity_gauge_v1.rs",
         "src/abi/curve/gauges/liquidity_gauge_v2.rs",
         "src/abi/curve/gauges/liquidity_gauge_v3.rs",
         "src/abi/curve/gauges/liquidity_gauge_v4.rs",
         "src/abi/curve/gauges/liquidity_gauge_v5.rs",
         "src/abi/curve/gauges/liquidity_gauge_v6.rs",
+        "src/abi/curve/gauges/root_gauge_factory.rs",
+        "src/abi/curve/lending/one_way_lending_factory.rs",
+        "src/abi/curve/lending/vault.rs",
         "src/abi/curve/ownership_proxies/factory_owner.rs",
         "src/abi/curve/ownership_proxies/gauge_manager.rs",
         "src/abi/curve/ownership_proxies/gauge_manager_old.rs",
@@ -76,8 +94,10 @@ fn main() -> Result<(), anyhow::Error> {
         "src/abi/curve/address_provider.rs",
         "src/abi/curve/crv_token.rs",
         "src/abi/curve/gauge_controller.rs",
+        "src/abi/curve/minter.rs",
         "src/abi/curve/pool.rs",
         "src/abi/curve/registry.rs",
+        "src/abi/curve/voting_escrow.rs",
         "src/abi/oracle/curve_calculations.rs",
         "src/abi/oracle/inch.rs",
         "src/abi/oracle/sushiswap.rs",
@@ -92,6 +112,10 @@ fn main() -> Result<(), anyhow::Error> {
         let regex = Regex::new(r#"("\w+"\s?:\s?")_(\w+")"#).unwrap();
         let sanitized_abi_file = regex.replace_all(contents.as_str(), "${1}u_${2}");
 
+        // sanitize parameter names that are reserved Rust keywords (e.g. VotingEscrow's `type`)
+        let keyword_regex = Regex::new(r#"("name"\s?:\s?")(type")"#).unwrap();
+        let sanitized_abi_file = keyword_regex.replace_all(&sanitized_abi_file, "${1}u_${2}");
+
         Abigen::from_bytes("Contract", sanitized_abi_file.as_bytes())?
             .generate()?
             .write_to_file(file_output_names[i])?;
@@ -131,9 +155,15 @@ fn main() -> Result<(), anyhow::Error> {
         }
     }
 
+    // The network is selected with the `CURVE_NETWORK` environment variable, matching one of the
+    // `config/curve-finance-{network}` directories, e.g. `CURVE_NETWORK=arbitrum make build`.
+    println!("cargo:rerun-if-env-changed=CURVE_NETWORK");
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=abi");
+    println!("cargo:rerun-if-changed=config");
+    let network = std::env::var("CURVE_NETWORK").unwrap_or_else(|_| "ethereum".to_string());
     generate_network_config_from_json(
-        // TODO: This will eventually need to be dynamic when we support multiple networks.
-        "config/curve-finance-ethereum/configuration.json",
+        &format!("config/curve-finance-{}/configuration.json", network),
         "./src/network_config.rs",
     )
     .expect("Should have been able to generate the network configuration file");
@@ -152,9 +182,12 @@ fn generate_network_config_from_json(path: &str, output_path: &str) -> Result<()
     // Generated file imports
     output.push_str("use hex_literal::hex;\n");
     output.push_str("use crate::{
-        pb::curve::types::v1::lending_pool::{
-            AaveLending, CompoundLending, CompoundTetherLending, IronBankLending, LendingPoolType,
-            PaxLending, YiEarnLending,
+        pb::curve::types::v1::{
+            lending_pool::{
+                AaveLending, CompoundLending, CompoundTetherLending, IronBankLending,
+                LendingPoolType, PaxLending, YiEarnLending,
+            },
+            StakingWrapper,
         },
         types::registry::{RegistryDetails, RegistryType},
     };\n\n");
@@ -196,11 +229,129 @@ fn generate_network_config_from_json(path: &str, output_path: &str) -> Result<()
             crv_token_address.trim_start_matches("0x")
         ));
     }
-    if let Some(gauge_controller_address) = json["gaugeControllerContract"].as_str() {
-        output.push_str(&format!(
-            "pub const GAUGE_CONTROLLER_ADDRESS: [u8; 20] = hex!(\"{}\");\n",
+    // Gauge weights are voted on mainnet, so sidechains have no GaugeController.
+    match json["gaugeControllerContract"].as_str() {
+        Some(gauge_controller_address) => output.push_str(&format!(
+            "pub const GAUGE_CONTROLLER_ADDRESS: Option<[u8; 20]> = Some(hex!(\"{}\"));\n",
             gauge_controller_address.trim_start_matches("0x")
-        ));
+        )),
+        None => output.push_str("pub const GAUGE_CONTROLLER_ADDRESS: Option<[u8; 20]> = None;\n"),
+    }
+    // CRV is only minted, and locked for veCRV, on mainnet.
+    match json["minterContract"].as_str() {
+        Some(minter_address) => output.push_str(&format!(
+            "pub const MINTER_ADDRESS: Option<[u8; 20]> = Some(hex!(\"{}\"));\n",
+            minter_address.trim_start_matches("0x")
+        )),
+        None => output.push_str("pub const MINTER_ADDRESS: Option<[u8; 20]> = None;\n"),
+    }
+    match json["votingEscrowContract"].as_str() {
+        Some(voting_escrow_address) => output.push_str(&format!(
+            "pub const VOTING_ESCROW_ADDRESS: Option<[u8; 20]> = Some(hex!(\"{}\"));\n",
+            voting_escrow_address.trim_start_matches("0x")
+        )),
+        None => output.push_str("pub const VOTING_ESCROW_ADDRESS: Option<[u8; 20]> = None;\n"),
+    }
+
+    // Sidechain gauges are deployed via a RootGaugeFactory on mainnet, and a ChildGaugeFactory on each
+    // sidechain. Networks only have one of these, so both are optional.
+    match json["rootGaugeFactoryContract"].as_str() {
+        Some(root_gauge_factory_address) => output.push_str(&format!(
+            "pub const ROOT_GAUGE_FACTORY_ADDRESS: Option<[u8; 20]> = Some(hex!(\"{}\"));\n",
+            root_gauge_factory_address.trim_start_matches("0x")
+        )),
+        None => output.push_str("pub const ROOT_GAUGE_FACTORY_ADDRESS: Option<[u8; 20]> = None;\n"),
+    }
+    match json["childGaugeFactoryContract"].as_str() {
+        Some(child_gauge_factory_address) => output.push_str(&format!(
+            "pub const CHILD_GAUGE_FACTORY_ADDRESS: Option<[u8; 20]> = Some(hex!(\"{}\"));\n",
+            child_gauge_factory_address.trim_start_matches("0x")
+        )),
+        None => {
+            output.push_str("pub const CHILD_GAUGE_FACTORY_ADDRESS: Option<[u8; 20]> = None;\n")
+        }
+    }
+    // Bridged CRV reaches child gauges from the bridge contract of the sidechain, or from the null
+    // address where the bridge mints it.
+    match json["childGaugeBridgerContract"].as_str() {
+        Some(bridger_address) => output.push_str(&format!(
+            "pub const CHILD_GAUGE_BRIDGER_ADDRESS: Option<[u8; 20]> = Some(hex!(\"{}\"));\n",
+            bridger_address.trim_start_matches("0x")
+        )),
+        None => {
+            output.push_str("pub const CHILD_GAUGE_BRIDGER_ADDRESS: Option<[u8; 20]> = None;\n")
+        }
+    }
+
+    // crvUSD is only deployed on mainnet, so other networks have no ControllerFactory or PegKeepers.
+    match json["crvUsdControllerFactoryContract"].as_str() {
+        Some(controller_factory_address) => output.push_str(&format!(
+            "pub const CRVUSD_CONTROLLER_FACTORY_ADDRESS: Option<[u8; 20]> = Some(hex!(\"{}\"));\n",
+            controller_factory_address.trim_start_matches("0x")
+        )),
+        None => output
+            .push_str("pub const CRVUSD_CONTROLLER_FACTORY_ADDRESS: Option<[u8; 20]> = None;\n"),
+    }
+    match json["crvUsdTokenAddress"].as_str() {
+        Some(crvusd_token_address) => output.push_str(&format!(
+            "pub const CRVUSD_TOKEN_ADDRESS: Option<[u8; 20]> = Some(hex!(\"{}\"));\n",
+            crvusd_token_address.trim_start_matches("0x")
+        )),
+        None => output.push_str("pub const CRVUSD_TOKEN_ADDRESS: Option<[u8; 20]> = None;\n"),
+    }
+    if let Some(peg_keepers) = json["crvUsdPegKeepers"].as_array() {
+        output.push_str(
+            format!(
+                "pub static CRVUSD_PEG_KEEPERS: [[u8; 20]; {}] = [\n",
+                peg_keepers.len()
+            )
+            .as_str(),
+        );
+        for peg_keeper in peg_keepers {
+            let name = peg_keeper["name"].as_str().unwrap_or_default();
+            let address = peg_keeper["address"]
+                .as_str()
+                .unwrap_or_default()
+                .trim_start_matches("0x");
+            output.push_str(&format!("hex!(\"{}\"), // {}\n", address, name));
+        }
+        output.push_str("];\n");
+    } else {
+        output.push_str("pub static CRVUSD_PEG_KEEPERS: [[u8; 20]; 0] = [];\n");
+    }
+    match json["oneWayLendingFactoryContract"].as_str() {
+        Some(lending_factory_address) => output.push_str(&format!(
+            "pub const ONE_WAY_LENDING_FACTORY_ADDRESS: Option<[u8; 20]> = Some(hex!(\"{}\"));\n",
+            lending_factory_address.trim_start_matches("0x")
+        )),
+        None => output
+            .push_str("pub const ONE_WAY_LENDING_FACTORY_ADDRESS: Option<[u8; 20]> = None;\n"),
+    }
+
+    // Contracts staking LP tokens in gauges on behalf of other protocols, e.g. Convex and Stake DAO.
+    if let Some(staking_wrappers) = json["stakingWrappers"].as_array() {
+        output.push_str(
+            format!(
+                "pub static STAKING_WRAPPERS: [([u8; 20], StakingWrapper); {}] = [\n",
+                staking_wrappers.len()
+            )
+            .as_str(),
+        );
+        for staking_wrapper in staking_wrappers {
+            let name = staking_wrapper["name"].as_str().unwrap_or_default();
+            let address = staking_wrapper["address"]
+                .as_str()
+                .unwrap_or_default()
+                .trim_start_matches("0x");
+            let protocol = staking_wrapper["protocol"].as_str().unwrap_or("Direct");
+            output.push_str(&format!(
+                "(hex!(\"{}\"), StakingWrapper::{}), // {}\n",
+                address, protocol, name
+            ));
+        }
+        output.push_str("];\n");
+    } else {
+        output.push_str("pub static STAKING_WRAPPERS: [([u8; 20], StakingWrapper); 0] = [];\n");
     }
 
     // Generating constants for poolRegistry with types
@@ -346,48 +497,61 @@ fn generate_network_config_from_json(path: &str, output_path: &str) -> Result<()
         output.push_str("];\n");
     }
 
-    if let Some(curve_calcs) = json["curveCalculations"].as_object() {
-        let address = curve_calcs["address"]
-            .as_str()
-            .unwrap_or_default()
-            .trim_start_matches("0x");
-        output.push_str(&format!(
-            "\npub static CURVE_CALCULATIONS: [u8; 20] = hex!(\"{}\");\n",
-            address
-        ));
+    // Price oracles are not deployed on every network, so they are optional.
+    match json["curveCalculations"].as_object() {
+        Some(curve_calcs) => {
+            let address = curve_calcs["address"]
+                .as_str()
+                .unwrap_or_default()
+                .trim_start_matches("0x");
+            output.push_str(&format!(
+                "\npub static CURVE_CALCULATIONS: Option<[u8; 20]> = Some(hex!(\"{}\"));\n",
+                address
+            ));
+        }
+        None => output.push_str("\npub static CURVE_CALCULATIONS: Option<[u8; 20]> = None;\n"),
     }
 
-    if let Some(inch) = json["inch"].as_object() {
-        let address = inch["address"]
-            .as_str()
-            .unwrap_or_default()
-            .trim_start_matches("0x");
-        output.push_str(&format!(
-            "\npub static INCH_ORACLE: [u8; 20] = hex!(\"{}\");\n",
-            address
-        ));
+    match json["inch"].as_object() {
+        Some(inch) => {
+            let address = inch["address"]
+                .as_str()
+                .unwrap_or_default()
+                .trim_start_matches("0x");
+            output.push_str(&format!(
+                "\npub static INCH_ORACLE: Option<[u8; 20]> = Some(hex!(\"{}\"));\n",
+                address
+            ));
+        }
+        None => output.push_str("\npub static INCH_ORACLE: Option<[u8; 20]> = None;\n"),
     }
 
-    if let Some(yearn_lens) = json["yearnLens"].as_object() {
-        let address = yearn_lens["address"]
-            .as_str()
-            .unwrap_or_default()
-            .trim_start_matches("0x");
-        output.push_str(&format!(
-            "\npub static YEARN_LENS: [u8; 20] = hex!(\"{}\");\n",
-            address
-        ));
+    match json["yearnLens"].as_object() {
+        Some(yearn_lens) => {
+            let address = yearn_lens["address"]
+                .as_str()
+                .unwrap_or_default()
+                .trim_start_matches("0x");
+            output.push_str(&format!(
+                "\npub static YEARN_LENS: Option<[u8; 20]> = Some(hex!(\"{}\"));\n",
+                address
+            ));
+        }
+        None => output.push_str("\npub static YEARN_LENS: Option<[u8; 20]> = None;\n"),
     }
 
-    if let Some(sushiswap) = json["sushiswap"].as_object() {
-        let address = sushiswap["address"]
-            .as_str()
-            .unwrap_or_default()
-            .trim_start_matches("0x");
-        output.push_str(&format!(
-            "\npub static SUSHISWAP: [u8; 20] = hex!(\"{}\");\n",
-            address
-        ));
+    match json["sushiswap"].as_object() {
+        Some(sushiswap) => {
+            let address = sushiswap["address"]
+                .as_str()
+                .unwrap_or_default()
+                .trim_start_matches("0x");
+            output.push_str(&format!(
+                "\npub static SUSHISWAP: Option<[u8; 20]> = Some(hex!(\"{}\"));\n",
+                address
+            ));
+        }
+        None => output.push_str("\npub static SUSHISWAP: Option<[u8; 20]> = None;\n"),
     }
 
     if let Some(curve_calcs_blacklist) = json["curveCalculationsBlacklist"].as_array() {
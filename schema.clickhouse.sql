@@ -0,0 +1,141 @@
+-- ClickHouse tables populated by the `db_out` module, mirroring the Postgres tables of `schema.sql`.
+-- `db_out` writes complete rows, so each table keeps the latest row per `id` once merged. Query
+-- with `FINAL` to read deduplicated rows before a merge has happened.
+
+create table if not exists pools
+(
+    id                     String,
+    name                   String,
+    symbol                 String,
+    -- PLAIN, CRYPTO, TRICRYPTO, TWOCRYPTO, META, LENDING or WILDCARD
+    pool_type              LowCardinality(String),
+    output_token           String,
+    registry               String,
+    total_value_locked_usd Float64,
+    cumulative_volume_usd  Float64,
+    output_token_supply    Decimal(76, 0),
+    created_timestamp      UInt64,
+    created_block_number   UInt64
+)
+engine = ReplacingMergeTree()
+order by id;
+
+create table if not exists tokens
+(
+    id                      String,
+    name                    String,
+    symbol                  String,
+    decimals                Int32,
+    is_base_pool_lp_token   Bool,
+    last_price_usd          Float64,
+    last_price_block_number UInt64
+)
+engine = ReplacingMergeTree(last_price_block_number)
+order by id;
+
+-- { Pool address }-{ Token address }
+create table if not exists pool_tokens
+(
+    id          String,
+    pool        String,
+    token       String,
+    token_index Int32,
+    balance     Decimal(76, 0)
+)
+engine = ReplacingMergeTree()
+order by id;
+
+-- { Fee type }-{ Pool address }
+create table if not exists pool_fees
+(
+    id             String,
+    pool           String,
+    fee_type       LowCardinality(String),
+    fee_percentage Float64
+)
+engine = ReplacingMergeTree()
+order by id;
+
+create table if not exists swaps
+(
+    id               String,
+    pool             String,
+    trader           String,
+    token_in         String,
+    amount_in        Decimal(76, 0),
+    amount_in_usd    Float64,
+    token_out        String,
+    amount_out       Decimal(76, 0),
+    amount_out_usd   Float64,
+    transaction_hash String,
+    log_index        UInt64,
+    block_number     UInt64,
+    timestamp        UInt64
+)
+engine = ReplacingMergeTree(block_number)
+order by id;
+
+-- Input token addresses and amounts are comma separated lists, in pool token order.
+create table if not exists deposits
+(
+    id                  String,
+    pool                String,
+    provider            String,
+    input_tokens        String,
+    input_token_amounts String,
+    output_token_amount Decimal(76, 0),
+    amount_usd          Float64,
+    transaction_hash    String,
+    log_index           UInt64,
+    block_number        UInt64,
+    timestamp           UInt64
+)
+engine = ReplacingMergeTree(block_number)
+order by id;
+
+create table if not exists withdraws
+(
+    id                  String,
+    pool                String,
+    provider            String,
+    input_tokens        String,
+    input_token_amounts String,
+    output_token_amount Decimal(76, 0),
+    amount_usd          Float64,
+    transaction_hash    String,
+    log_index           UInt64,
+    block_number        UInt64,
+    timestamp           UInt64
+)
+engine = ReplacingMergeTree(block_number)
+order by id;
+
+create table if not exists gauges
+(
+    id                   String,
+    pool                 String,
+    -- Set once the gauge is added to the GaugeController
+    gauge_type           Nullable(String),
+    is_killed            Bool,
+    staked_supply        Decimal(76, 0),
+    created_timestamp    UInt64,
+    created_block_number UInt64
+)
+engine = ReplacingMergeTree()
+order by id;
+
+-- { Pool address }-{ Day ID }, holding the state of the pool at its last activity of the day
+create table if not exists pool_daily_snapshots
+(
+    id                     String,
+    pool                   String,
+    day                    Int64,
+    total_value_locked_usd Float64,
+    daily_volume_usd       Float64,
+    cumulative_volume_usd  Float64,
+    output_token_supply    Decimal(76, 0),
+    block_number           UInt64,
+    timestamp              UInt64
+)
+engine = ReplacingMergeTree(block_number)
+order by id;
@@ -0,0 +1,132 @@
+-- Postgres tables populated by the `db_out` module. Row IDs match the entity IDs of
+-- `schema.graphql`. The ClickHouse equivalent is `schema.clickhouse.sql`.
+
+create table if not exists pools
+(
+    id                     text    not null constraint pools_pk primary key,
+    name                   text    not null,
+    symbol                 text    not null,
+    -- PLAIN, CRYPTO, TRICRYPTO, TWOCRYPTO, META, LENDING or WILDCARD
+    pool_type              text    not null,
+    output_token           text    not null,
+    registry               text    not null,
+    total_value_locked_usd numeric not null,
+    cumulative_volume_usd  numeric not null,
+    output_token_supply    numeric not null,
+    created_timestamp      bigint  not null,
+    created_block_number   bigint  not null
+);
+
+create table if not exists tokens
+(
+    id                      text    not null constraint tokens_pk primary key,
+    name                    text    not null,
+    symbol                  text    not null,
+    decimals                integer not null,
+    is_base_pool_lp_token   boolean not null,
+    last_price_usd          numeric not null,
+    last_price_block_number bigint  not null
+);
+
+-- { Pool address }-{ Token address }
+create table if not exists pool_tokens
+(
+    id          text    not null constraint pool_tokens_pk primary key,
+    pool        text    not null,
+    token       text    not null,
+    token_index integer not null,
+    balance     numeric not null
+);
+
+create index if not exists pool_tokens_pool_idx on pool_tokens (pool);
+
+-- { Fee type }-{ Pool address }
+create table if not exists pool_fees
+(
+    id             text    not null constraint pool_fees_pk primary key,
+    pool           text    not null,
+    fee_type       text    not null,
+    fee_percentage numeric not null
+);
+
+create table if not exists swaps
+(
+    id               text    not null constraint swaps_pk primary key,
+    pool             text    not null,
+    trader           text    not null,
+    token_in         text    not null,
+    amount_in        numeric not null,
+    amount_in_usd    numeric not null,
+    token_out        text    not null,
+    amount_out       numeric not null,
+    amount_out_usd   numeric not null,
+    transaction_hash text    not null,
+    log_index        bigint  not null,
+    block_number     bigint  not null,
+    timestamp        bigint  not null
+);
+
+create index if not exists swaps_pool_idx on swaps (pool, block_number);
+
+-- Input token addresses and amounts are comma separated lists, in pool token order.
+create table if not exists deposits
+(
+    id                  text    not null constraint deposits_pk primary key,
+    pool                text    not null,
+    provider            text    not null,
+    input_tokens        text    not null,
+    input_token_amounts text    not null,
+    output_token_amount numeric not null,
+    amount_usd          numeric not null,
+    transaction_hash    text    not null,
+    log_index           bigint  not null,
+    block_number        bigint  not null,
+    timestamp           bigint  not null
+);
+
+create index if not exists deposits_pool_idx on deposits (pool, block_number);
+
+create table if not exists withdraws
+(
+    id                  text    not null constraint withdraws_pk primary key,
+    pool                text    not null,
+    provider            text    not null,
+    input_tokens        text    not null,
+    input_token_amounts text    not null,
+    output_token_amount numeric not null,
+    amount_usd          numeric not null,
+    transaction_hash    text    not null,
+    log_index           bigint  not null,
+    block_number        bigint  not null,
+    timestamp           bigint  not null
+);
+
+create index if not exists withdraws_pool_idx on withdraws (pool, block_number);
+
+create table if not exists gauges
+(
+    id                   text    not null constraint gauges_pk primary key,
+    pool                 text    not null,
+    -- Set once the gauge is added to the GaugeController
+    gauge_type           text,
+    is_killed            boolean not null,
+    staked_supply        numeric not null,
+    created_timestamp    bigint  not null,
+    created_block_number bigint  not null
+);
+
+-- { Pool address }-{ Day ID }, holding the state of the pool at its last activity of the day
+create table if not exists pool_daily_snapshots
+(
+    id                     text    not null constraint pool_daily_snapshots_pk primary key,
+    pool                   text    not null,
+    day                    bigint  not null,
+    total_value_locked_usd numeric not null,
+    daily_volume_usd       numeric not null,
+    cumulative_volume_usd  numeric not null,
+    output_token_supply    numeric not null,
+    block_number           bigint  not null,
+    timestamp              bigint  not null
+);
+
+create index if not exists pool_daily_snapshots_pool_idx on pool_daily_snapshots (pool, day);
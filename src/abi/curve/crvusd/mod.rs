@@ -0,0 +1,4 @@
+pub mod controller;
+pub mod controller_factory;
+pub mod llamma;
+pub mod peg_keeper;
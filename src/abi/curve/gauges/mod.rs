@@ -1,6 +1,8 @@
+pub mod child_gauge_factory;
 pub mod liquidity_gauge_v1;
 pub mod liquidity_gauge_v2;
 pub mod liquidity_gauge_v3;
 pub mod liquidity_gauge_v4;
 pub mod liquidity_gauge_v5;
 pub mod liquidity_gauge_v6;
+pub mod root_gauge_factory;
@@ -0,0 +1,2 @@
+pub mod one_way_lending_factory;
+pub mod vault;
@@ -2,8 +2,12 @@ pub mod pools;
 pub mod ownership_proxies;
 pub mod gauges;
 pub mod child_registries;
+pub mod crvusd;
+pub mod lending;
 pub mod address_provider;
 pub mod crv_token;
 pub mod gauge_controller;
+pub mod minter;
 pub mod pool;
 pub mod registry;
+pub mod voting_escrow;
@@ -0,0 +1,98 @@
+use substreams::{scalar::BigInt, Hex};
+use substreams_ethereum::{
+    pb::eth::v2::{self as eth, Log, TransactionTrace},
+    Event,
+};
+
+use crate::{
+    abi::curve::crvusd::controller,
+    pb::curve::types::v1::{
+        controller_loan_event::LoanEventType, ControllerLiquidationEvent, ControllerLoanEvent,
+    },
+    rpc,
+};
+
+// crvUSD and LlamaLend markets share the same Controller implementation, so their loan and
+// liquidation events are extracted alike.
+pub fn extract_controller_events(
+    controller: &str,
+    trx: &TransactionTrace,
+    blk: &eth::Block,
+    log: &Log,
+    loan_events: &mut Vec<ControllerLoanEvent>,
+    liquidation_events: &mut Vec<ControllerLiquidationEvent>,
+) {
+    let controller_address = Hex::decode(controller).unwrap();
+
+    let loan_event = if let Some(borrow) = controller::events::Borrow::match_and_decode(log) {
+        Some((
+            borrow.user,
+            LoanEventType::Borrow,
+            borrow.collateral_increase,
+            borrow.loan_increase,
+        ))
+    } else if let Some(repay) = controller::events::Repay::match_and_decode(log) {
+        Some((
+            repay.user,
+            LoanEventType::Repay,
+            repay.collateral_decrease,
+            repay.loan_decrease,
+        ))
+    } else if let Some(remove_collateral) =
+        controller::events::RemoveCollateral::match_and_decode(log)
+    {
+        Some((
+            remove_collateral.user,
+            LoanEventType::RemoveCollateral,
+            remove_collateral.collateral_decrease,
+            BigInt::zero(),
+        ))
+    } else {
+        None
+    };
+
+    if let Some((user, loan_event_type, collateral_amount, debt_amount)) = loan_event {
+        // Debt accrues interest and collateral is converted during soft-liquidation, so the
+        // resulting loan is read from the contract rather than derived from the event amounts.
+        let (user_collateral, user_stablecoin, user_debt) =
+            rpc::crvusd::get_user_state(&controller_address, &user);
+        let total_debt = rpc::crvusd::get_total_debt(&controller_address);
+        loan_events.push(ControllerLoanEvent {
+            controller: controller.to_string(),
+            user: Hex::encode(&user),
+            r#type: loan_event_type as i32,
+            collateral_amount: collateral_amount.to_string(),
+            debt_amount: debt_amount.to_string(),
+            user_collateral: user_collateral.to_string(),
+            user_stablecoin: user_stablecoin.to_string(),
+            user_debt: user_debt.to_string(),
+            total_debt: total_debt.to_string(),
+            transaction_hash: Hex::encode(&trx.hash),
+            tx_index: trx.index,
+            log_index: log.index,
+            log_ordinal: log.ordinal,
+            timestamp: blk.timestamp_seconds(),
+            block_number: blk.number,
+        });
+        return;
+    }
+
+    if let Some(liquidate) = controller::events::Liquidate::match_and_decode(log) {
+        let total_debt = rpc::crvusd::get_total_debt(&controller_address);
+        liquidation_events.push(ControllerLiquidationEvent {
+            controller: controller.to_string(),
+            liquidator: Hex::encode(&liquidate.liquidator),
+            user: Hex::encode(&liquidate.user),
+            collateral_received: liquidate.collateral_received.to_string(),
+            stablecoin_received: liquidate.stablecoin_received.to_string(),
+            debt: liquidate.debt.to_string(),
+            total_debt: total_debt.to_string(),
+            transaction_hash: Hex::encode(&trx.hash),
+            tx_index: trx.index,
+            log_index: log.index,
+            log_ordinal: log.ordinal,
+            timestamp: blk.timestamp_seconds(),
+            block_number: blk.number,
+        });
+    }
+}
@@ -0,0 +1,109 @@
+use substreams::{log, scalar::BigInt};
+
+use crate::{
+    constants::{
+        crv_initial_rate, CRV_INFLATION_START_TIME, CRV_RATE_REDUCTION_COEFFICIENT,
+        CRV_RATE_REDUCTION_TIME,
+    },
+    rpc,
+};
+
+// Calculates the CRV inflation rate (CRV per second) at a given timestamp from the emission schedule.
+// The reduction is applied once per elapsed epoch, using the same integer arithmetic as the CRV
+// token's `_update_mining_parameters`, so the result matches the on-chain rate exactly.
+pub fn calculate_crv_inflation_rate(timestamp: i64) -> BigInt {
+    if timestamp < CRV_INFLATION_START_TIME as i64 {
+        return BigInt::zero();
+    }
+
+    let epochs = (timestamp as u64 - CRV_INFLATION_START_TIME) / CRV_RATE_REDUCTION_TIME;
+    let mut rate = crv_initial_rate();
+    for _ in 0..epochs {
+        rate = rate * BigInt::from(10).pow(18) / BigInt::from(CRV_RATE_REDUCTION_COEFFICIENT);
+    }
+    rate
+}
+
+// Returns the CRV inflation rate at a given timestamp, cross-checking the emission schedule against
+// the CRV token. The on-chain rate is only updated when `update_mining_parameters` is called, so it
+// is only used when the token's current epoch covers the timestamp. Otherwise the schedule is used.
+pub fn get_crv_inflation_rate(timestamp: i64) -> BigInt {
+    let scheduled_rate = calculate_crv_inflation_rate(timestamp);
+
+    let (onchain_rate, start_epoch_time) = match (
+        rpc::crv_token::get_crv_rate(),
+        rpc::crv_token::get_crv_start_epoch_time(),
+    ) {
+        (Some(rate), Some(start_epoch_time)) => (rate, start_epoch_time),
+        _ => return scheduled_rate,
+    };
+
+    let epoch_end = start_epoch_time.to_u64() + CRV_RATE_REDUCTION_TIME;
+    if (timestamp as u64) >= epoch_end {
+        return scheduled_rate;
+    }
+
+    if onchain_rate != scheduled_rate {
+        log::debug!(
+            "CRV inflation rate mismatch at {}: on-chain {}, scheduled {}",
+            timestamp,
+            onchain_rate,
+            scheduled_rate
+        );
+    }
+    onchain_rate
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn rate(value: &str) -> BigInt {
+        BigInt::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_calculate_crv_inflation_rate_before_start() {
+        let timestamp = CRV_INFLATION_START_TIME as i64 - 1;
+        assert_eq!(calculate_crv_inflation_rate(timestamp), BigInt::zero());
+    }
+
+    #[test]
+    fn test_calculate_crv_inflation_rate_first_epoch() {
+        let start = CRV_INFLATION_START_TIME as i64;
+        let epoch_end = start + CRV_RATE_REDUCTION_TIME as i64 - 1;
+        assert_eq!(
+            calculate_crv_inflation_rate(start),
+            rate("8714335457889396245")
+        );
+        assert_eq!(
+            calculate_crv_inflation_rate(epoch_end),
+            rate("8714335457889396245")
+        );
+    }
+
+    #[test]
+    fn test_calculate_crv_inflation_rate_epoch_boundaries() {
+        // Expected rates match the CRV token's `rate()` after each `update_mining_parameters`
+        let epoch_start =
+            |epoch: u64| (CRV_INFLATION_START_TIME + epoch * CRV_RATE_REDUCTION_TIME) as i64;
+        assert_eq!(
+            calculate_crv_inflation_rate(epoch_start(1)),
+            rate("7327853447857530670")
+        );
+        assert_eq!(
+            calculate_crv_inflation_rate(epoch_start(2)),
+            rate("6161965695807970181")
+        );
+        assert_eq!(
+            calculate_crv_inflation_rate(epoch_start(3)),
+            rate("5181574864521283150")
+        );
+        assert_eq!(
+            calculate_crv_inflation_rate(epoch_start(3) - 1),
+            rate("6161965695807970181")
+        );
+    }
+}
@@ -1,4 +1,6 @@
+pub mod controller_events;
 pub mod conversion;
+pub mod crv_inflation;
 pub mod event_extraction;
 pub mod format;
 pub mod pool_utils;
@@ -8,10 +8,11 @@ use substreams::{
 use crate::{
     key_management::store_key_manager::StoreKey,
     pb::curve::types::v1::{
-        events::pool_event::{SwapUnderlyingMetaEvent, TokenSource},
+        events::pool_event::{SwapEvent, SwapUnderlyingMetaEvent, TokenAmount, TokenSource},
         pool::PoolType,
-        Pool, Token,
+        Pool, PoolFees, Token,
     },
+    rpc,
 };
 
 pub fn get_input_token_balances(
@@ -66,6 +67,17 @@ pub fn is_metapool(pool: &Pool) -> bool {
     matches!(pool.pool_type, Some(PoolType::MetaPool(_)))
 }
 
+// CryptoSwap pools (legacy crypto, tricrypto and twocrypto) share event layouts distinct from
+// StableSwap pools.
+pub fn is_crypto_pool(pool: &Pool) -> bool {
+    matches!(
+        pool.pool_type,
+        Some(PoolType::CryptoPool(_))
+            | Some(PoolType::TricryptoPool(_))
+            | Some(PoolType::TwocryptoPool(_))
+    )
+}
+
 // Checks whether a TokenExchangeUnderlying event is a Metapool Asset -> Base Pool Asset exchange
 pub fn is_meta_to_base_exchange(swap_underlying: &SwapUnderlyingMetaEvent) -> bool {
     swap_underlying.token_in_ref().source() == TokenSource::MetaPool
@@ -77,3 +89,122 @@ pub fn is_base_to_meta_exchange(swap_underlying: &SwapUnderlyingMetaEvent) -> bo
     swap_underlying.token_in_ref().source() == TokenSource::BasePool
         && swap_underlying.token_out_ref().source() == TokenSource::MetaPool
 }
+
+// Gets the multiplier a StableSwap-NG pool applies to a token, relative to its face value. Stored rates
+// are scaled by `10^(36 - decimals)`, so scaling them back down leaves just the oracle or vault rate.
+pub fn get_token_rate_multiplier(pool: &Pool, token: &Token) -> Option<BigDecimal> {
+    // Tokens with more than 36 decimals would underflow the rate precision, which no NG pool supports.
+    let rate_decimals = 36u64.checked_sub(token.decimals)?;
+    let index = token.index.parse::<usize>().ok()?;
+    let rates = rpc::pool::get_pool_stored_rates(&pool.address_vec())?;
+    rates.get(index).map(|rate| rate.to_decimal(rate_decimals))
+}
+
+// Rebasing tokens change balance without emitting pool events, so their balance is read from the pool.
+pub fn get_rebasing_token_balance(pool: &Pool, token: &Token) -> Option<BigInt> {
+    let index = token.index.parse::<usize>().ok()?;
+    rpc::pool::get_pool_balance(&pool.address_vec(), index)
+}
+
+// Gets the fee percentage charged on a swap. For pools with an off-peg fee multiplier this depends on how
+// balanced the pool is, using the average of the balances before and after the swap as the contracts do.
+pub fn get_swap_effective_fee_percentage(
+    pool: &Pool,
+    pool_fees: &PoolFees,
+    swap: &SwapEvent,
+    ordinal: u64,
+    input_token_balances_store: &StoreGetBigInt,
+) -> BigDecimal {
+    if pool_fees.offpeg_fee_multiplier.is_none() {
+        return pool_fees.trading_fee().fee_percentage_big_decimal();
+    }
+
+    let average_balance = |token_amount: &TokenAmount, is_token_in: bool| -> Option<BigDecimal> {
+        let token = pool
+            .input_tokens
+            .iter()
+            .find(|t| t.address == token_amount.token_address)?;
+        let balance = input_token_balances_store
+            .get_at(
+                ordinal,
+                StoreKey::input_token_balance_key(&pool.address, &token.address),
+            )?
+            .to_decimal(token.decimals);
+        Some(get_average_swap_balance(
+            balance,
+            token_amount.amount_big().to_decimal(token.decimals),
+            is_token_in,
+        ))
+    };
+
+    match (
+        average_balance(swap.token_in_ref(), true),
+        average_balance(swap.token_out_ref(), false),
+    ) {
+        (Some(xpi), Some(xpj)) => pool_fees.effective_trading_fee_percentage(&xpi, &xpj),
+        _ => pool_fees.trading_fee().fee_percentage_big_decimal(),
+    }
+}
+
+// Balances in the store are those after the swap, so half of the amount exchanged is removed from the
+// token in, and added to the token out, to get the average balance over the swap.
+fn get_average_swap_balance(
+    balance_after: BigDecimal,
+    amount: BigDecimal,
+    is_token_in: bool,
+) -> BigDecimal {
+    let half_amount = amount / BigDecimal::from(2);
+    if is_token_in {
+        balance_after - half_amount
+    } else {
+        balance_after + half_amount
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::rpc::pool::calculate_pool_fees;
+
+    fn decimal(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_get_average_swap_balance() {
+        // 10 tokens in and 10 tokens out of a pool holding 1000 of each before the swap.
+        assert_eq!(
+            get_average_swap_balance(decimal("1010"), decimal("10"), true),
+            decimal("1005")
+        );
+        assert_eq!(
+            get_average_swap_balance(decimal("990"), decimal("10"), false),
+            decimal("995")
+        );
+    }
+
+    #[test]
+    fn test_swap_effective_fee_balanced_and_imbalanced_pool() {
+        let fees = calculate_pool_fees(
+            BigInt::from(4000000),
+            BigInt::from(5000000000 as i64),
+            Some(BigInt::from(20000000000 as i64)),
+            &vec![0u8; 20],
+        );
+
+        // The pool is balanced on average over the swap, so the base fee is charged.
+        let xpi = get_average_swap_balance(decimal("1005"), decimal("10"), true);
+        let xpj = get_average_swap_balance(decimal("995"), decimal("10"), false);
+        assert_eq!(
+            fees.effective_trading_fee_percentage(&xpi, &xpj),
+            decimal("0.04")
+        );
+
+        // Selling into the heavier side of an imbalanced pool charges more than the base fee.
+        let xpi = get_average_swap_balance(decimal("1510"), decimal("20"), true);
+        let xpj = get_average_swap_balance(decimal("490"), decimal("20"), false);
+        assert!(fees.effective_trading_fee_percentage(&xpi, &xpj) > decimal("0.04"));
+    }
+}
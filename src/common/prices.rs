@@ -7,11 +7,12 @@ use substreams::{
 };
 
 use crate::{
+    common::pool_utils,
     constants::{one_usd_value, BLACKLISTED_TOKENS},
     key_management::store_key_manager::StoreKey,
     network_config::HARDCODED_STABLES,
     pb::{
-        curve::types::v1::{events::pool_event::PriceSource, Token},
+        curve::types::v1::{events::pool_event::PriceSource, AssetType, Pool, Token},
         uniswap_pricing::v1::Erc20Price,
     },
     rpc::oracle::{
@@ -59,6 +60,34 @@ pub fn get_token_usd_price(
     price
 }
 
+// Gets the USD price of a token held by a pool. Oracle and ERC4626 tokens in StableSwap-NG pools that
+// can't be priced directly are valued in the pool's unit of account, using the price of one of its
+// standard tokens and the rate multiplier the pool applies to the token.
+pub fn get_pool_token_usd_price(
+    pool: &Pool,
+    token: &Token,
+    uniswap_prices: &StoreGetProto<Erc20Price>,
+    chainlink_prices: &StoreGetBigDecimal,
+) -> (BigDecimal, PriceSource) {
+    let (price, price_source) = get_token_usd_price(token, uniswap_prices, chainlink_prices);
+    if price_source != PriceSource::Unknown || !token.is_rated_asset() {
+        return (price, price_source);
+    }
+
+    pool_utils::get_token_rate_multiplier(pool, token)
+        .and_then(|rate_multiplier| {
+            pool.input_tokens
+                .iter()
+                .filter(|input_token| input_token.asset_type() == AssetType::Standard)
+                .map(|input_token| {
+                    get_token_usd_price(input_token, uniswap_prices, chainlink_prices)
+                })
+                .find(|(_, source)| *source != PriceSource::Unknown)
+                .map(|(price, source)| (price * rate_multiplier, source))
+        })
+        .unwrap_or((price, price_source))
+}
+
 fn get_usd_price_from_chainlink(
     token: &Token,
     chainlink_prices: &StoreGetBigDecimal,
@@ -1,4 +1,13 @@
-use crate::network_config::{self, PROTOCOL_ADDRESS};
+use substreams::{
+    store::{StoreGet, StoreGetString},
+    Hex,
+};
+
+use crate::{
+    key_management::store_key_manager::StoreKey,
+    network_config::{self, PROTOCOL_ADDRESS, STAKING_WRAPPERS},
+    pb::curve::types::v1::StakingWrapper,
+};
 
 use super::format::format_address_vec;
 
@@ -6,8 +15,29 @@ pub fn get_protocol_id() -> String {
     format_address_vec(&PROTOCOL_ADDRESS.to_vec())
 }
 
+// Gauge providers that are not one of the known wrapper contracts are direct stakers.
+pub fn get_staking_wrapper(provider_address: &Vec<u8>) -> StakingWrapper {
+    STAKING_WRAPPERS
+        .iter()
+        .find(|(address, _)| address.as_ref() == provider_address.as_slice())
+        .map(|(_, staking_wrapper)| *staking_wrapper)
+        .unwrap_or(StakingWrapper::Direct)
+}
+
 pub fn is_base_pool_lp_token(lp_token_address: &Vec<u8>) -> bool {
     network_config::BASE_POOLS_LP_TOKEN
         .iter()
         .any(|&token_address| token_address.as_ref() == lp_token_address.as_slice())
 }
+
+// Base pools registered through `BasePoolAdded` events are tracked in `store_base_pools`, with the
+// static list in the network config covering those that predate the registries.
+pub fn is_registered_base_pool_lp_token(
+    lp_token_address: &str,
+    base_pools_store: &StoreGetString,
+) -> bool {
+    base_pools_store.has_last(StoreKey::base_pool_lp_token_key(lp_token_address))
+        || Hex::decode(lp_token_address)
+            .map(|address| is_base_pool_lp_token(&address))
+            .unwrap_or(false)
+}
@@ -8,7 +8,7 @@ use substreams::{
 
 use crate::{
     network_config::{PoolDetails, CRV_TOKEN_ADDRESS, MISSING_OLD_POOLS_DATA},
-    pb::curve::types::v1::Token,
+    pb::curve::types::v1::{AssetType, Token},
 };
 
 // Chain Specific Contracts:
@@ -105,6 +105,28 @@ pub fn one_usd_value() -> BigDecimal {
 
 pub const FEE_DECIMALS: u64 = 10;
 pub const SECONDS_PER_DAY: u64 = 86400;
+pub const DAYS_PER_YEAR: u64 = 365;
+
+// Maximum lock duration of the VotingEscrow contract (`MAXTIME`), used to derive veCRV voting power.
+pub const VOTING_ESCROW_MAX_LOCK_TIME: u64 = 4 * 365 * SECONDS_PER_DAY;
+
+// CRV emission schedule, mirroring the constants of the CRV token contract. The rate is reduced by
+// `RATE_REDUCTION_COEFFICIENT` (2 ** (1/4) * 1e18) at the start of every yearly mining epoch.
+pub const CRV_RATE_REDUCTION_TIME: u64 = 365 * SECONDS_PER_DAY;
+pub const CRV_RATE_REDUCTION_COEFFICIENT: u64 = 1189207115002721024;
+// Start of the first mining epoch, when emissions began at `INITIAL_RATE`.
+pub const CRV_INFLATION_START_TIME: u64 = 1597357048;
+
+// Share of a gauge deposit (in percentage) that counts towards its working balance without any veCRV
+// boost (`TOKENLESS_PRODUCTION`). The maximum boost of 2.5x brings a deposit to its full balance.
+pub const GAUGE_TOKENLESS_PRODUCTION: u64 = 40;
+
+// Rolling window sizes (in days) for the aggregates exposed on the protocol and pool entities.
+pub const ROLLING_WINDOW_7D: i64 = 7;
+pub const ROLLING_WINDOW_30D: i64 = 30;
+
+// By default, pruners keep data for the timeframe that has just ended, as it is needed for snapshots.
+pub const DEFAULT_PRUNE_RETENTION: i64 = 1;
 
 pub fn default_pool_fee() -> BigInt {
     BigInt::from(4000000)
@@ -114,6 +136,12 @@ pub fn default_admin_fee() -> BigInt {
     BigInt::from(5000000000 as i64)
 }
 
+// Initial CRV emission rate (274,815,283 CRV over the first year), in CRV per second.
+pub fn crv_initial_rate() -> BigInt {
+    BigInt::from(274815283 as u64) * BigInt::from(10).pow(18)
+        / BigInt::from(CRV_RATE_REDUCTION_TIME)
+}
+
 pub fn curve_token() -> Token {
     Token {
         index: "0".to_string(),
@@ -124,5 +152,6 @@ pub fn curve_token() -> Token {
         total_supply: "0".to_string(),
         is_base_pool_lp_token: false,
         gauge: None,
+        asset_type: AssetType::Standard as i32,
     }
 }
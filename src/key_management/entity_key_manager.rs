@@ -9,13 +9,37 @@ pub enum EntityKey {
     LiquidityPoolFee(String, String),
     LiquidityPoolDailySnapshot(String, i64),
     LiquidityPoolHourlySnapshot(String, i64),
+    LiquidityPoolWeeklySnapshot(String, i64),
+    LiquidityPoolMonthlySnapshot(String, i64),
     ProtocolDailyFinancialsSnapshot(i64),
+    ProtocolHourlyFinancialsSnapshot(i64),
+    ProtocolWeeklyFinancialsSnapshot(i64),
+    ProtocolMonthlyFinancialsSnapshot(i64),
     Token(String),
     RewardToken(String),
     PoolRewardToken(String, String),
+    PoolToken(String, String),
     Deposit(String, String),
     Swap(String, String),
     Withdraw(String, String),
+    GaugeVote(String, String),
+    GaugeWeightSnapshot(String, i64),
+    GaugeTypeWeight(i32),
+    VotingEscrowLock(String),
+    VotingEscrowDailySnapshot(i64),
+    GaugeDailySnapshot(String, i64),
+    GaugePosition(String, String, i64),
+    RewardClaim(String, String),
+    LiquidityGauge(String),
+    RootGauge(String),
+    CrvUsdMarket(String),
+    CrvUsdPosition(String, String),
+    CrvUsdLiquidation(String, String),
+    LlammaExchange(String, String),
+    PegKeeper(String),
+    LendingMarket(String),
+    LendingPosition(String, String),
+    LendingLiquidation(String, String),
 }
 
 impl EntityKey {
@@ -45,10 +69,30 @@ impl EntityKey {
         EntityKey::LiquidityPoolHourlySnapshot(pool_address.to_string(), *hour_id).to_key_string()
     }
 
+    pub fn pool_weekly_snapshot_key(pool_address: &str, week_id: &i64) -> String {
+        EntityKey::LiquidityPoolWeeklySnapshot(pool_address.to_string(), *week_id).to_key_string()
+    }
+
+    pub fn pool_monthly_snapshot_key(pool_address: &str, month_id: &i64) -> String {
+        EntityKey::LiquidityPoolMonthlySnapshot(pool_address.to_string(), *month_id).to_key_string()
+    }
+
     pub fn protocol_daily_financials_key(day_id: &i64) -> String {
         EntityKey::ProtocolDailyFinancialsSnapshot(*day_id).to_key_string()
     }
 
+    pub fn protocol_hourly_financials_key(hour_id: &i64) -> String {
+        EntityKey::ProtocolHourlyFinancialsSnapshot(*hour_id).to_key_string()
+    }
+
+    pub fn protocol_weekly_financials_key(week_id: &i64) -> String {
+        EntityKey::ProtocolWeeklyFinancialsSnapshot(*week_id).to_key_string()
+    }
+
+    pub fn protocol_monthly_financials_key(month_id: &i64) -> String {
+        EntityKey::ProtocolMonthlyFinancialsSnapshot(*month_id).to_key_string()
+    }
+
     pub fn token_key(token_address: &str) -> String {
         EntityKey::Token(token_address.to_string()).to_key_string()
     }
@@ -62,6 +106,10 @@ impl EntityKey {
             .to_key_string()
     }
 
+    pub fn pool_token_key(pool_address: &str, token_address: &str) -> String {
+        EntityKey::PoolToken(pool_address.to_string(), token_address.to_string()).to_key_string()
+    }
+
     pub fn deposit_key(transaction_hash: &str, log_index: &u32) -> String {
         EntityKey::Deposit(transaction_hash.to_string(), log_index.to_string()).to_key_string()
     }
@@ -74,6 +122,92 @@ impl EntityKey {
         EntityKey::Withdraw(transaction_hash.to_string(), log_index.to_string()).to_key_string()
     }
 
+    pub fn gauge_vote_key(transaction_hash: &str, log_index: &u32) -> String {
+        EntityKey::GaugeVote(transaction_hash.to_string(), log_index.to_string()).to_key_string()
+    }
+
+    pub fn gauge_weight_snapshot_key(gauge_address: &str, week_id: &i64) -> String {
+        EntityKey::GaugeWeightSnapshot(gauge_address.to_string(), *week_id).to_key_string()
+    }
+
+    pub fn gauge_type_weight_key(gauge_type: &i32) -> String {
+        EntityKey::GaugeTypeWeight(*gauge_type).to_key_string()
+    }
+
+    pub fn voting_escrow_lock_key(provider_address: &str) -> String {
+        EntityKey::VotingEscrowLock(provider_address.to_string()).to_key_string()
+    }
+
+    pub fn voting_escrow_daily_snapshot_key(day_id: &i64) -> String {
+        EntityKey::VotingEscrowDailySnapshot(*day_id).to_key_string()
+    }
+
+    pub fn gauge_daily_snapshot_key(gauge_address: &str, day_id: &i64) -> String {
+        EntityKey::GaugeDailySnapshot(gauge_address.to_string(), *day_id).to_key_string()
+    }
+
+    pub fn gauge_position_key(
+        gauge_address: &str,
+        user_address: &str,
+        position_id: &i64,
+    ) -> String {
+        EntityKey::GaugePosition(
+            gauge_address.to_string(),
+            user_address.to_string(),
+            *position_id,
+        )
+        .to_key_string()
+    }
+
+    pub fn reward_claim_key(transaction_hash: &str, log_index: &u32) -> String {
+        EntityKey::RewardClaim(transaction_hash.to_string(), log_index.to_string()).to_key_string()
+    }
+
+    pub fn liquidity_gauge_key(gauge_address: &str) -> String {
+        EntityKey::LiquidityGauge(gauge_address.to_string()).to_key_string()
+    }
+
+    pub fn root_gauge_key(gauge_address: &str) -> String {
+        EntityKey::RootGauge(gauge_address.to_string()).to_key_string()
+    }
+
+    pub fn crvusd_market_key(controller_address: &str) -> String {
+        EntityKey::CrvUsdMarket(controller_address.to_string()).to_key_string()
+    }
+
+    pub fn crvusd_position_key(controller_address: &str, user_address: &str) -> String {
+        EntityKey::CrvUsdPosition(controller_address.to_string(), user_address.to_string())
+            .to_key_string()
+    }
+
+    pub fn crvusd_liquidation_key(transaction_hash: &str, log_index: &u32) -> String {
+        EntityKey::CrvUsdLiquidation(transaction_hash.to_string(), log_index.to_string())
+            .to_key_string()
+    }
+
+    pub fn llamma_exchange_key(transaction_hash: &str, log_index: &u32) -> String {
+        EntityKey::LlammaExchange(transaction_hash.to_string(), log_index.to_string())
+            .to_key_string()
+    }
+
+    pub fn peg_keeper_key(peg_keeper_address: &str) -> String {
+        EntityKey::PegKeeper(peg_keeper_address.to_string()).to_key_string()
+    }
+
+    pub fn lending_market_key(controller_address: &str) -> String {
+        EntityKey::LendingMarket(controller_address.to_string()).to_key_string()
+    }
+
+    pub fn lending_position_key(controller_address: &str, user_address: &str) -> String {
+        EntityKey::LendingPosition(controller_address.to_string(), user_address.to_string())
+            .to_key_string()
+    }
+
+    pub fn lending_liquidation_key(transaction_hash: &str, log_index: &u32) -> String {
+        EntityKey::LendingLiquidation(transaction_hash.to_string(), log_index.to_string())
+            .to_key_string()
+    }
+
     fn to_key_string(&self) -> String {
         match self {
             EntityKey::Protocol => utils::get_protocol_id(),
@@ -95,7 +229,24 @@ impl EntityKey {
                     hour_id.to_string()
                 )
             }
+            EntityKey::LiquidityPoolWeeklySnapshot(pool_address, week_id) => {
+                format!(
+                    "{}-{}",
+                    format_address_string(pool_address),
+                    week_id.to_string()
+                )
+            }
+            EntityKey::LiquidityPoolMonthlySnapshot(pool_address, month_id) => {
+                format!(
+                    "{}-{}",
+                    format_address_string(pool_address),
+                    month_id.to_string()
+                )
+            }
             EntityKey::ProtocolDailyFinancialsSnapshot(day_id) => day_id.to_string(),
+            EntityKey::ProtocolHourlyFinancialsSnapshot(hour_id) => hour_id.to_string(),
+            EntityKey::ProtocolWeeklyFinancialsSnapshot(week_id) => week_id.to_string(),
+            EntityKey::ProtocolMonthlyFinancialsSnapshot(month_id) => month_id.to_string(),
             EntityKey::Token(token_address) => format_address_string(token_address),
             EntityKey::RewardToken(reward_token_address) => {
                 format_address_string(reward_token_address)
@@ -107,6 +258,13 @@ impl EntityKey {
                     format_address_string(reward_token_address)
                 )
             }
+            EntityKey::PoolToken(pool_address, token_address) => {
+                format!(
+                    "{}-{}",
+                    format_address_string(pool_address),
+                    format_address_string(token_address)
+                )
+            }
             EntityKey::Deposit(tx_hash, log_index) => {
                 format!("deposit-0x{}-{}", tx_hash, log_index)
             }
@@ -116,6 +274,71 @@ impl EntityKey {
             EntityKey::Withdraw(tx_hash, log_index) => {
                 format!("withdraw-0x{}-{}", tx_hash, log_index)
             }
+            EntityKey::GaugeVote(tx_hash, log_index) => {
+                format!("vote-0x{}-{}", tx_hash, log_index)
+            }
+            EntityKey::GaugeWeightSnapshot(gauge_address, week_id) => {
+                format!(
+                    "{}-{}",
+                    format_address_string(gauge_address),
+                    week_id.to_string()
+                )
+            }
+            EntityKey::GaugeTypeWeight(gauge_type) => gauge_type.to_string(),
+            EntityKey::VotingEscrowLock(provider_address) => {
+                format_address_string(provider_address)
+            }
+            EntityKey::VotingEscrowDailySnapshot(day_id) => day_id.to_string(),
+            EntityKey::GaugeDailySnapshot(gauge_address, day_id) => {
+                format!(
+                    "{}-{}",
+                    format_address_string(gauge_address),
+                    day_id.to_string()
+                )
+            }
+            EntityKey::GaugePosition(gauge_address, user_address, position_id) => {
+                format!(
+                    "{}-{}-{}",
+                    format_address_string(gauge_address),
+                    format_address_string(user_address),
+                    position_id.to_string()
+                )
+            }
+            EntityKey::RewardClaim(tx_hash, log_index) => {
+                format!("claim-0x{}-{}", tx_hash, log_index)
+            }
+            EntityKey::LiquidityGauge(gauge_address) => format_address_string(gauge_address),
+            EntityKey::RootGauge(gauge_address) => format_address_string(gauge_address),
+            EntityKey::CrvUsdMarket(controller_address) => {
+                format_address_string(controller_address)
+            }
+            EntityKey::CrvUsdPosition(controller_address, user_address) => {
+                format!(
+                    "{}-{}",
+                    format_address_string(controller_address),
+                    format_address_string(user_address)
+                )
+            }
+            EntityKey::CrvUsdLiquidation(tx_hash, log_index) => {
+                format!("liquidate-0x{}-{}", tx_hash, log_index)
+            }
+            EntityKey::LlammaExchange(tx_hash, log_index) => {
+                format!("exchange-0x{}-{}", tx_hash, log_index)
+            }
+            EntityKey::PegKeeper(peg_keeper_address) => format_address_string(peg_keeper_address),
+            EntityKey::LendingMarket(controller_address) => {
+                format_address_string(controller_address)
+            }
+            EntityKey::LendingPosition(controller_address, user_address) => {
+                format!(
+                    "{}-{}",
+                    format_address_string(controller_address),
+                    format_address_string(user_address)
+                )
+            }
+            EntityKey::LendingLiquidation(tx_hash, log_index) => {
+                format!("liquidate-0x{}-{}", tx_hash, log_index)
+            }
         }
     }
 }
@@ -1,3 +1,5 @@
+use crate::types::pool_fees::RevenueSide;
+
 pub enum StoreKey {
     // Curve sustream specific store key variants
     Pool(String),
@@ -9,25 +11,94 @@ pub enum StoreKey {
     PoolDailyVolumeUsdPrune(i64),
     PoolHourlyVolumeUsd(i64, String),
     PoolHourlyVolumeUsdPrune(i64),
+    PoolWeeklyVolumeUsd(i64, String),
+    PoolWeeklyVolumeUsdPrune(i64),
+    PoolMonthlyVolumeUsd(i64, String),
+    PoolMonthlyVolumeUsdPrune(i64),
     PoolTokenDailyVolumeNative(i64, String, String),
     PoolTokenDailyVolumeNativePrune(i64),
     PoolTokenHourlyVolumeNative(i64, String, String),
     PoolTokenHourlyVolumeNativePrune(i64),
+    PoolTokenWeeklyVolumeNative(i64, String, String),
+    PoolTokenWeeklyVolumeNativePrune(i64),
+    PoolTokenMonthlyVolumeNative(i64, String, String),
+    PoolTokenMonthlyVolumeNativePrune(i64),
     PoolTokenDailyVolumeUsd(i64, String, String),
     PoolTokenDailyVolumeUsdPrune(i64),
     PoolTokenHourlyVolumeUsd(i64, String, String),
     PoolTokenHourlyVolumeUsdPrune(i64),
+    PoolTokenWeeklyVolumeUsd(i64, String, String),
+    PoolTokenWeeklyVolumeUsdPrune(i64),
+    PoolTokenMonthlyVolumeUsd(i64, String, String),
+    PoolTokenMonthlyVolumeUsdPrune(i64),
     PoolTvl(String),
+    PoolTotalRevenueUsd(String),
+    PoolProtocolSideRevenueUsd(String),
+    PoolSupplySideRevenueUsd(String),
+    PoolDailyRevenueUsd(i64, RevenueSide, String),
+    PoolDailyRevenueUsdPrune(i64),
+    PoolHourlyRevenueUsd(i64, RevenueSide, String),
+    PoolHourlyRevenueUsdPrune(i64),
+    PoolWeeklyRevenueUsd(i64, RevenueSide, String),
+    PoolWeeklyRevenueUsdPrune(i64),
+    PoolMonthlyRevenueUsd(i64, RevenueSide, String),
+    PoolMonthlyRevenueUsdPrune(i64),
     PoolTokenTvl(String, String),
     LiquidityGauge(String),
+    RootGauge(String),
+    GaugeCrvBridged(String),
+    GaugeVote(String, String),
+    VotingEscrowLock(String),
+    GaugeUserBalance(String, String),
+    GaugeStakedSupply(String),
+    GaugeWrapperStakedSupply(String, String),
+    GaugeUserPositionCount(String, String),
+    GaugeUserCrvMinted(String, String),
+    GaugeCrvMinted(String),
+    GaugeDailyCrvMinted(i64, String),
+    GaugeDailyCrvMintedPrune(i64),
+    GaugeRewardClaimed(String, String),
+    GaugeUserRewardClaimed(String, String, String),
+    RewardTokenDistributor(String, String),
+    RewardTokenFunded(String, String),
+    RewardTokenTotalFunded(String),
     LiquidityGaugeRewardToken(String, i64),
     LiquidityGaugeRewardTokenCount(String),
     ControllerGaugeAdded(String),
+    GaugeType(String),
+    GaugeKillStatus(String),
     ProtocolPoolCount,
+    ProtocolGaugeCount,
+    GaugeAddress(i64),
     ProtocolVolumeUsd,
     ProtocolDailyVolumeUsd(i64),
+    ProtocolHourlyVolumeUsd(i64),
+    ProtocolWeeklyVolumeUsd(i64),
+    ProtocolMonthlyVolumeUsd(i64),
+    ProtocolRevenueUsd(RevenueSide),
+    ProtocolDailyRevenueUsd(i64, RevenueSide),
+    ProtocolHourlyRevenueUsd(i64, RevenueSide),
+    ProtocolWeeklyRevenueUsd(i64, RevenueSide),
+    ProtocolMonthlyRevenueUsd(i64, RevenueSide),
     ProtocolTvl,
     Token(String),
+    BasePoolLpToken(String),
+    CrvUsdMarket(String),
+    CrvUsdMarketAmm(String),
+    CrvUsdMarketCollateral(String),
+    CrvUsdMarketStablecoin(String),
+    CrvUsdMarketBorrowed(String),
+    CrvUsdMarketRepaid(String),
+    CrvUsdMarketLiquidatedDebt(String),
+    CrvUsdMarketLiquidatedCollateral(String),
+    PegKeeperDebt(String),
+    LendingMarket(String),
+    LendingMarketVault(String),
+    LendingMarketDeposited(String),
+    LendingMarketWithdrawn(String),
+    LendingMarketBorrowed(String),
+    LendingMarketRepaid(String),
+    LendingMarketLiquidatedDebt(String),
     OutputTokenSupply(String),
     InputTokenBalance(String, String),
     ActiveUser(String),
@@ -46,8 +117,35 @@ pub enum StoreKey {
     DepositHourlyCount(i64),
     WithdrawDailyCount(i64),
     WithdrawHourlyCount(i64),
+    PoolActiveUserDaily(i64, String, String),
+    PoolActiveUserDailyPrune(i64),
+    PoolActiveUserHourly(i64, String, String),
+    PoolActiveUserHourlyPrune(i64),
+    PoolActiveUserDailyCount(i64, String),
+    PoolActiveUserDailyCountPrune(i64),
+    PoolActiveUserHourlyCount(i64, String),
+    PoolActiveUserHourlyCountPrune(i64),
+    PoolSwapDailyCount(i64, String),
+    PoolSwapDailyCountPrune(i64),
+    PoolSwapHourlyCount(i64, String),
+    PoolSwapHourlyCountPrune(i64),
+    PoolDepositDailyCount(i64, String),
+    PoolDepositDailyCountPrune(i64),
+    PoolDepositHourlyCount(i64, String),
+    PoolDepositHourlyCountPrune(i64),
+    PoolWithdrawDailyCount(i64, String),
+    PoolWithdrawDailyCountPrune(i64),
+    PoolWithdrawHourlyCount(i64, String),
+    PoolWithdrawHourlyCountPrune(i64),
+    UserLastActiveDay(String),
+    PoolUserLastActiveDay(String, String),
+    UserLastActiveDayCount(i64),
+    PoolUserLastActiveDayCount(i64, String),
+    PoolUserLastActiveDayCountPrune(i64),
     CurrentDayId,
     CurrentHourId,
+    CurrentWeekId,
+    CurrentMonthId,
     CRVInflationRate,
     // External packages store key variants
     UniswapPriceByTokenAddress(String),
@@ -92,6 +190,22 @@ impl StoreKey {
         StoreKey::PoolHourlyVolumeUsdPrune(*hour_id).to_key_string()
     }
 
+    pub fn pool_volume_usd_weekly_key(week_id: &i64, pool_address: &str) -> String {
+        StoreKey::PoolWeeklyVolumeUsd(*week_id, pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_volume_usd_weekly_prune_key(week_id: &i64) -> String {
+        StoreKey::PoolWeeklyVolumeUsdPrune(*week_id).to_key_string()
+    }
+
+    pub fn pool_volume_usd_monthly_key(month_id: &i64, pool_address: &str) -> String {
+        StoreKey::PoolMonthlyVolumeUsd(*month_id, pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_volume_usd_monthly_prune_key(month_id: &i64) -> String {
+        StoreKey::PoolMonthlyVolumeUsdPrune(*month_id).to_key_string()
+    }
+
     pub fn pool_token_volume_native_daily_key(
         day_id: &i64,
         pool_address: &str,
@@ -126,6 +240,40 @@ impl StoreKey {
         StoreKey::PoolTokenHourlyVolumeNativePrune(*hour_id).to_key_string()
     }
 
+    pub fn pool_token_volume_native_weekly_key(
+        week_id: &i64,
+        pool_address: &str,
+        token_address: &str,
+    ) -> String {
+        StoreKey::PoolTokenWeeklyVolumeNative(
+            *week_id,
+            pool_address.to_string(),
+            token_address.to_string(),
+        )
+        .to_key_string()
+    }
+
+    pub fn pool_token_volume_native_weekly_prune_key(week_id: &i64) -> String {
+        StoreKey::PoolTokenWeeklyVolumeNativePrune(*week_id).to_key_string()
+    }
+
+    pub fn pool_token_volume_native_monthly_key(
+        month_id: &i64,
+        pool_address: &str,
+        token_address: &str,
+    ) -> String {
+        StoreKey::PoolTokenMonthlyVolumeNative(
+            *month_id,
+            pool_address.to_string(),
+            token_address.to_string(),
+        )
+        .to_key_string()
+    }
+
+    pub fn pool_token_volume_native_monthly_prune_key(month_id: &i64) -> String {
+        StoreKey::PoolTokenMonthlyVolumeNativePrune(*month_id).to_key_string()
+    }
+
     pub fn pool_token_volume_usd_daily_key(
         day_id: &i64,
         pool_address: &str,
@@ -160,14 +308,124 @@ impl StoreKey {
         StoreKey::PoolTokenHourlyVolumeUsdPrune(*hour_id).to_key_string()
     }
 
+    pub fn pool_token_volume_usd_weekly_key(
+        week_id: &i64,
+        pool_address: &str,
+        token_address: &str,
+    ) -> String {
+        StoreKey::PoolTokenWeeklyVolumeUsd(
+            *week_id,
+            pool_address.to_string(),
+            token_address.to_string(),
+        )
+        .to_key_string()
+    }
+
+    pub fn pool_token_volume_usd_weekly_prune_key(week_id: &i64) -> String {
+        StoreKey::PoolTokenWeeklyVolumeUsdPrune(*week_id).to_key_string()
+    }
+
+    pub fn pool_token_volume_usd_monthly_key(
+        month_id: &i64,
+        pool_address: &str,
+        token_address: &str,
+    ) -> String {
+        StoreKey::PoolTokenMonthlyVolumeUsd(
+            *month_id,
+            pool_address.to_string(),
+            token_address.to_string(),
+        )
+        .to_key_string()
+    }
+
+    pub fn pool_token_volume_usd_monthly_prune_key(month_id: &i64) -> String {
+        StoreKey::PoolTokenMonthlyVolumeUsdPrune(*month_id).to_key_string()
+    }
+
     pub fn protocol_pool_count_key() -> String {
         StoreKey::ProtocolPoolCount.to_key_string()
     }
 
+    pub fn protocol_gauge_count_key() -> String {
+        StoreKey::ProtocolGaugeCount.to_key_string()
+    }
+
+    pub fn gauge_address_key(current_count: &i64) -> String {
+        StoreKey::GaugeAddress(current_count.to_owned()).to_key_string()
+    }
+
     pub fn pool_tvl_key(pool_address: &str) -> String {
         StoreKey::PoolTvl(pool_address.to_string()).to_key_string()
     }
 
+    pub fn pool_total_revenue_usd_key(pool_address: &str) -> String {
+        StoreKey::PoolTotalRevenueUsd(pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_protocol_side_revenue_usd_key(pool_address: &str) -> String {
+        StoreKey::PoolProtocolSideRevenueUsd(pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_supply_side_revenue_usd_key(pool_address: &str) -> String {
+        StoreKey::PoolSupplySideRevenueUsd(pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_revenue_usd_key(side: &RevenueSide, pool_address: &str) -> String {
+        match side {
+            RevenueSide::Total => StoreKey::pool_total_revenue_usd_key(pool_address),
+            RevenueSide::ProtocolSide => StoreKey::pool_protocol_side_revenue_usd_key(pool_address),
+            RevenueSide::SupplySide => StoreKey::pool_supply_side_revenue_usd_key(pool_address),
+        }
+    }
+
+    pub fn pool_revenue_usd_daily_key(
+        day_id: &i64,
+        side: &RevenueSide,
+        pool_address: &str,
+    ) -> String {
+        StoreKey::PoolDailyRevenueUsd(*day_id, *side, pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_revenue_usd_daily_prune_key(day_id: &i64) -> String {
+        StoreKey::PoolDailyRevenueUsdPrune(*day_id).to_key_string()
+    }
+
+    pub fn pool_revenue_usd_hourly_key(
+        hour_id: &i64,
+        side: &RevenueSide,
+        pool_address: &str,
+    ) -> String {
+        StoreKey::PoolHourlyRevenueUsd(*hour_id, *side, pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_revenue_usd_hourly_prune_key(hour_id: &i64) -> String {
+        StoreKey::PoolHourlyRevenueUsdPrune(*hour_id).to_key_string()
+    }
+
+    pub fn pool_revenue_usd_weekly_key(
+        week_id: &i64,
+        side: &RevenueSide,
+        pool_address: &str,
+    ) -> String {
+        StoreKey::PoolWeeklyRevenueUsd(*week_id, *side, pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_revenue_usd_weekly_prune_key(week_id: &i64) -> String {
+        StoreKey::PoolWeeklyRevenueUsdPrune(*week_id).to_key_string()
+    }
+
+    pub fn pool_revenue_usd_monthly_key(
+        month_id: &i64,
+        side: &RevenueSide,
+        pool_address: &str,
+    ) -> String {
+        StoreKey::PoolMonthlyRevenueUsd(*month_id, *side, pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_revenue_usd_monthly_prune_key(month_id: &i64) -> String {
+        StoreKey::PoolMonthlyRevenueUsdPrune(*month_id).to_key_string()
+    }
+
     pub fn pool_token_tvl_key(pool_address: &str, token_address: &str) -> String {
         StoreKey::PoolTokenTvl(pool_address.to_string(), token_address.to_string()).to_key_string()
     }
@@ -176,6 +434,93 @@ impl StoreKey {
         StoreKey::LiquidityGauge(gauge_address.to_string()).to_key_string()
     }
 
+    pub fn root_gauge_key(gauge_address: &str) -> String {
+        StoreKey::RootGauge(gauge_address.to_string()).to_key_string()
+    }
+
+    pub fn gauge_crv_bridged_key(gauge_address: &str) -> String {
+        StoreKey::GaugeCrvBridged(gauge_address.to_string()).to_key_string()
+    }
+
+    pub fn gauge_vote_key(gauge_address: &str, user_address: &str) -> String {
+        StoreKey::GaugeVote(gauge_address.to_string(), user_address.to_string()).to_key_string()
+    }
+
+    pub fn voting_escrow_lock_key(provider_address: &str) -> String {
+        StoreKey::VotingEscrowLock(provider_address.to_string()).to_key_string()
+    }
+
+    pub fn gauge_user_balance_key(gauge_address: &str, user_address: &str) -> String {
+        StoreKey::GaugeUserBalance(gauge_address.to_string(), user_address.to_string())
+            .to_key_string()
+    }
+
+    pub fn gauge_staked_supply_key(gauge_address: &str) -> String {
+        StoreKey::GaugeStakedSupply(gauge_address.to_string()).to_key_string()
+    }
+
+    pub fn gauge_wrapper_staked_supply_key(gauge_address: &str, staking_wrapper: &str) -> String {
+        StoreKey::GaugeWrapperStakedSupply(gauge_address.to_string(), staking_wrapper.to_string())
+            .to_key_string()
+    }
+
+    pub fn gauge_user_position_count_key(gauge_address: &str, user_address: &str) -> String {
+        StoreKey::GaugeUserPositionCount(gauge_address.to_string(), user_address.to_string())
+            .to_key_string()
+    }
+
+    pub fn gauge_user_crv_minted_key(gauge_address: &str, user_address: &str) -> String {
+        StoreKey::GaugeUserCrvMinted(gauge_address.to_string(), user_address.to_string())
+            .to_key_string()
+    }
+
+    pub fn gauge_crv_minted_key(gauge_address: &str) -> String {
+        StoreKey::GaugeCrvMinted(gauge_address.to_string()).to_key_string()
+    }
+
+    pub fn gauge_daily_crv_minted_key(day_id: &i64, gauge_address: &str) -> String {
+        StoreKey::GaugeDailyCrvMinted(*day_id, gauge_address.to_string()).to_key_string()
+    }
+
+    pub fn gauge_daily_crv_minted_prune_key(day_id: &i64) -> String {
+        StoreKey::GaugeDailyCrvMintedPrune(*day_id).to_key_string()
+    }
+
+    pub fn gauge_reward_claimed_key(gauge_address: &str, reward_token_address: &str) -> String {
+        StoreKey::GaugeRewardClaimed(gauge_address.to_string(), reward_token_address.to_string())
+            .to_key_string()
+    }
+
+    pub fn gauge_user_reward_claimed_key(
+        gauge_address: &str,
+        user_address: &str,
+        reward_token_address: &str,
+    ) -> String {
+        StoreKey::GaugeUserRewardClaimed(
+            gauge_address.to_string(),
+            user_address.to_string(),
+            reward_token_address.to_string(),
+        )
+        .to_key_string()
+    }
+
+    pub fn reward_token_distributor_key(gauge_address: &str, reward_token_address: &str) -> String {
+        StoreKey::RewardTokenDistributor(
+            gauge_address.to_string(),
+            reward_token_address.to_string(),
+        )
+        .to_key_string()
+    }
+
+    pub fn reward_token_funded_key(gauge_address: &str, reward_token_address: &str) -> String {
+        StoreKey::RewardTokenFunded(gauge_address.to_string(), reward_token_address.to_string())
+            .to_key_string()
+    }
+
+    pub fn reward_token_total_funded_key(reward_token_address: &str) -> String {
+        StoreKey::RewardTokenTotalFunded(reward_token_address.to_string()).to_key_string()
+    }
+
     pub fn liquidity_gauge_reward_token_key(gauge_address: &str, token_index: &i64) -> String {
         StoreKey::LiquidityGaugeRewardToken(gauge_address.to_string(), *token_index).to_key_string()
     }
@@ -188,6 +533,14 @@ impl StoreKey {
         StoreKey::ControllerGaugeAdded(gauge_address.to_string()).to_key_string()
     }
 
+    pub fn gauge_type_key(gauge_address: &str) -> String {
+        StoreKey::GaugeType(gauge_address.to_string()).to_key_string()
+    }
+
+    pub fn gauge_kill_status_key(gauge_address: &str) -> String {
+        StoreKey::GaugeKillStatus(gauge_address.to_string()).to_key_string()
+    }
+
     pub fn protocol_volume_usd_key() -> String {
         StoreKey::ProtocolVolumeUsd.to_key_string()
     }
@@ -196,6 +549,38 @@ impl StoreKey {
         StoreKey::ProtocolDailyVolumeUsd(*day_id).to_key_string()
     }
 
+    pub fn protocol_hourly_volume_usd_key(hour_id: &i64) -> String {
+        StoreKey::ProtocolHourlyVolumeUsd(*hour_id).to_key_string()
+    }
+
+    pub fn protocol_weekly_volume_usd_key(week_id: &i64) -> String {
+        StoreKey::ProtocolWeeklyVolumeUsd(*week_id).to_key_string()
+    }
+
+    pub fn protocol_monthly_volume_usd_key(month_id: &i64) -> String {
+        StoreKey::ProtocolMonthlyVolumeUsd(*month_id).to_key_string()
+    }
+
+    pub fn protocol_revenue_usd_key(side: &RevenueSide) -> String {
+        StoreKey::ProtocolRevenueUsd(*side).to_key_string()
+    }
+
+    pub fn protocol_daily_revenue_usd_key(day_id: &i64, side: &RevenueSide) -> String {
+        StoreKey::ProtocolDailyRevenueUsd(*day_id, *side).to_key_string()
+    }
+
+    pub fn protocol_hourly_revenue_usd_key(hour_id: &i64, side: &RevenueSide) -> String {
+        StoreKey::ProtocolHourlyRevenueUsd(*hour_id, *side).to_key_string()
+    }
+
+    pub fn protocol_weekly_revenue_usd_key(week_id: &i64, side: &RevenueSide) -> String {
+        StoreKey::ProtocolWeeklyRevenueUsd(*week_id, *side).to_key_string()
+    }
+
+    pub fn protocol_monthly_revenue_usd_key(month_id: &i64, side: &RevenueSide) -> String {
+        StoreKey::ProtocolMonthlyRevenueUsd(*month_id, *side).to_key_string()
+    }
+
     pub fn protocol_tvl_key() -> String {
         StoreKey::ProtocolTvl.to_key_string()
     }
@@ -204,6 +589,74 @@ impl StoreKey {
         StoreKey::Token(token_address.to_string()).to_key_string()
     }
 
+    pub fn base_pool_lp_token_key(lp_token_address: &str) -> String {
+        StoreKey::BasePoolLpToken(lp_token_address.to_string()).to_key_string()
+    }
+
+    pub fn crvusd_market_key(controller_address: &str) -> String {
+        StoreKey::CrvUsdMarket(controller_address.to_string()).to_key_string()
+    }
+
+    pub fn crvusd_market_amm_key(amm_address: &str) -> String {
+        StoreKey::CrvUsdMarketAmm(amm_address.to_string()).to_key_string()
+    }
+
+    pub fn crvusd_market_collateral_key(controller_address: &str) -> String {
+        StoreKey::CrvUsdMarketCollateral(controller_address.to_string()).to_key_string()
+    }
+
+    pub fn crvusd_market_stablecoin_key(controller_address: &str) -> String {
+        StoreKey::CrvUsdMarketStablecoin(controller_address.to_string()).to_key_string()
+    }
+
+    pub fn crvusd_market_borrowed_key(controller_address: &str) -> String {
+        StoreKey::CrvUsdMarketBorrowed(controller_address.to_string()).to_key_string()
+    }
+
+    pub fn crvusd_market_repaid_key(controller_address: &str) -> String {
+        StoreKey::CrvUsdMarketRepaid(controller_address.to_string()).to_key_string()
+    }
+
+    pub fn crvusd_market_liquidated_debt_key(controller_address: &str) -> String {
+        StoreKey::CrvUsdMarketLiquidatedDebt(controller_address.to_string()).to_key_string()
+    }
+
+    pub fn crvusd_market_liquidated_collateral_key(controller_address: &str) -> String {
+        StoreKey::CrvUsdMarketLiquidatedCollateral(controller_address.to_string()).to_key_string()
+    }
+
+    pub fn peg_keeper_debt_key(peg_keeper_address: &str) -> String {
+        StoreKey::PegKeeperDebt(peg_keeper_address.to_string()).to_key_string()
+    }
+
+    pub fn lending_market_key(controller_address: &str) -> String {
+        StoreKey::LendingMarket(controller_address.to_string()).to_key_string()
+    }
+
+    pub fn lending_market_vault_key(vault_address: &str) -> String {
+        StoreKey::LendingMarketVault(vault_address.to_string()).to_key_string()
+    }
+
+    pub fn lending_market_deposited_key(controller_address: &str) -> String {
+        StoreKey::LendingMarketDeposited(controller_address.to_string()).to_key_string()
+    }
+
+    pub fn lending_market_withdrawn_key(controller_address: &str) -> String {
+        StoreKey::LendingMarketWithdrawn(controller_address.to_string()).to_key_string()
+    }
+
+    pub fn lending_market_borrowed_key(controller_address: &str) -> String {
+        StoreKey::LendingMarketBorrowed(controller_address.to_string()).to_key_string()
+    }
+
+    pub fn lending_market_repaid_key(controller_address: &str) -> String {
+        StoreKey::LendingMarketRepaid(controller_address.to_string()).to_key_string()
+    }
+
+    pub fn lending_market_liquidated_debt_key(controller_address: &str) -> String {
+        StoreKey::LendingMarketLiquidatedDebt(controller_address.to_string()).to_key_string()
+    }
+
     pub fn output_token_supply_key(pool_address: &str) -> String {
         StoreKey::OutputTokenSupply(pool_address.to_string()).to_key_string()
     }
@@ -277,6 +730,117 @@ impl StoreKey {
         StoreKey::WithdrawHourlyCount(*hour_id).to_key_string()
     }
 
+    pub fn pool_active_user_daily_key(
+        day_id: &i64,
+        pool_address: &str,
+        user_address: &str,
+    ) -> String {
+        StoreKey::PoolActiveUserDaily(*day_id, pool_address.to_string(), user_address.to_string())
+            .to_key_string()
+    }
+
+    pub fn pool_active_user_daily_prune_key(day_id: &i64) -> String {
+        StoreKey::PoolActiveUserDailyPrune(*day_id).to_key_string()
+    }
+
+    pub fn pool_active_user_hourly_key(
+        hour_id: &i64,
+        pool_address: &str,
+        user_address: &str,
+    ) -> String {
+        StoreKey::PoolActiveUserHourly(*hour_id, pool_address.to_string(), user_address.to_string())
+            .to_key_string()
+    }
+
+    pub fn pool_active_user_hourly_prune_key(hour_id: &i64) -> String {
+        StoreKey::PoolActiveUserHourlyPrune(*hour_id).to_key_string()
+    }
+
+    pub fn pool_active_user_daily_count_key(day_id: &i64, pool_address: &str) -> String {
+        StoreKey::PoolActiveUserDailyCount(*day_id, pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_active_user_daily_count_prune_key(day_id: &i64) -> String {
+        StoreKey::PoolActiveUserDailyCountPrune(*day_id).to_key_string()
+    }
+
+    pub fn pool_active_user_hourly_count_key(hour_id: &i64, pool_address: &str) -> String {
+        StoreKey::PoolActiveUserHourlyCount(*hour_id, pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_active_user_hourly_count_prune_key(hour_id: &i64) -> String {
+        StoreKey::PoolActiveUserHourlyCountPrune(*hour_id).to_key_string()
+    }
+
+    pub fn pool_swap_daily_count_key(day_id: &i64, pool_address: &str) -> String {
+        StoreKey::PoolSwapDailyCount(*day_id, pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_swap_daily_count_prune_key(day_id: &i64) -> String {
+        StoreKey::PoolSwapDailyCountPrune(*day_id).to_key_string()
+    }
+
+    pub fn pool_swap_hourly_count_key(hour_id: &i64, pool_address: &str) -> String {
+        StoreKey::PoolSwapHourlyCount(*hour_id, pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_swap_hourly_count_prune_key(hour_id: &i64) -> String {
+        StoreKey::PoolSwapHourlyCountPrune(*hour_id).to_key_string()
+    }
+
+    pub fn pool_deposit_daily_count_key(day_id: &i64, pool_address: &str) -> String {
+        StoreKey::PoolDepositDailyCount(*day_id, pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_deposit_daily_count_prune_key(day_id: &i64) -> String {
+        StoreKey::PoolDepositDailyCountPrune(*day_id).to_key_string()
+    }
+
+    pub fn pool_deposit_hourly_count_key(hour_id: &i64, pool_address: &str) -> String {
+        StoreKey::PoolDepositHourlyCount(*hour_id, pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_deposit_hourly_count_prune_key(hour_id: &i64) -> String {
+        StoreKey::PoolDepositHourlyCountPrune(*hour_id).to_key_string()
+    }
+
+    pub fn pool_withdraw_daily_count_key(day_id: &i64, pool_address: &str) -> String {
+        StoreKey::PoolWithdrawDailyCount(*day_id, pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_withdraw_daily_count_prune_key(day_id: &i64) -> String {
+        StoreKey::PoolWithdrawDailyCountPrune(*day_id).to_key_string()
+    }
+
+    pub fn pool_withdraw_hourly_count_key(hour_id: &i64, pool_address: &str) -> String {
+        StoreKey::PoolWithdrawHourlyCount(*hour_id, pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_withdraw_hourly_count_prune_key(hour_id: &i64) -> String {
+        StoreKey::PoolWithdrawHourlyCountPrune(*hour_id).to_key_string()
+    }
+
+    pub fn user_last_active_day_key(user_address: &str) -> String {
+        StoreKey::UserLastActiveDay(user_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_user_last_active_day_key(pool_address: &str, user_address: &str) -> String {
+        StoreKey::PoolUserLastActiveDay(pool_address.to_string(), user_address.to_string())
+            .to_key_string()
+    }
+
+    pub fn user_last_active_day_count_key(day_id: &i64) -> String {
+        StoreKey::UserLastActiveDayCount(*day_id).to_key_string()
+    }
+
+    pub fn pool_user_last_active_day_count_key(day_id: &i64, pool_address: &str) -> String {
+        StoreKey::PoolUserLastActiveDayCount(*day_id, pool_address.to_string()).to_key_string()
+    }
+
+    pub fn pool_user_last_active_day_count_prune_key(day_id: &i64) -> String {
+        StoreKey::PoolUserLastActiveDayCountPrune(*day_id).to_key_string()
+    }
+
     pub fn current_day_id_key() -> String {
         StoreKey::CurrentDayId.to_key_string()
     }
@@ -285,6 +849,14 @@ impl StoreKey {
         StoreKey::CurrentHourId.to_key_string()
     }
 
+    pub fn current_week_id_key() -> String {
+        StoreKey::CurrentWeekId.to_key_string()
+    }
+
+    pub fn current_month_id_key() -> String {
+        StoreKey::CurrentMonthId.to_key_string()
+    }
+
     pub fn crv_inflation_rate_key() -> String {
         StoreKey::CRVInflationRate.to_key_string()
     }
@@ -320,6 +892,18 @@ impl StoreKey {
             StoreKey::PoolHourlyVolumeUsdPrune(hour_id) => {
                 format!("PoolHourlyVolumeUsd:{}:", hour_id.to_string())
             }
+            StoreKey::PoolWeeklyVolumeUsd(week_id, addr) => {
+                format!("PoolWeeklyVolumeUsd:{}:{}", week_id.to_string(), addr)
+            }
+            StoreKey::PoolWeeklyVolumeUsdPrune(week_id) => {
+                format!("PoolWeeklyVolumeUsd:{}:", week_id.to_string())
+            }
+            StoreKey::PoolMonthlyVolumeUsd(month_id, addr) => {
+                format!("PoolMonthlyVolumeUsd:{}:{}", month_id.to_string(), addr)
+            }
+            StoreKey::PoolMonthlyVolumeUsdPrune(month_id) => {
+                format!("PoolMonthlyVolumeUsd:{}:", month_id.to_string())
+            }
             StoreKey::PoolTokenDailyVolumeNative(day_id, pool_addr, token_addr) => {
                 format!(
                     "PoolTokenDailyVolumeNative:{}:{}:{}",
@@ -342,6 +926,28 @@ impl StoreKey {
             StoreKey::PoolTokenHourlyVolumeNativePrune(hour_id) => {
                 format!("PoolTokenHourlyVolumeNative:{}:", hour_id.to_string(),)
             }
+            StoreKey::PoolTokenWeeklyVolumeNative(week_id, pool_addr, token_addr) => {
+                format!(
+                    "PoolTokenWeeklyVolumeNative:{}:{}:{}",
+                    week_id.to_string(),
+                    pool_addr,
+                    token_addr
+                )
+            }
+            StoreKey::PoolTokenWeeklyVolumeNativePrune(week_id) => {
+                format!("PoolTokenWeeklyVolumeNative:{}:", week_id.to_string())
+            }
+            StoreKey::PoolTokenMonthlyVolumeNative(month_id, pool_addr, token_addr) => {
+                format!(
+                    "PoolTokenMonthlyVolumeNative:{}:{}:{}",
+                    month_id.to_string(),
+                    pool_addr,
+                    token_addr
+                )
+            }
+            StoreKey::PoolTokenMonthlyVolumeNativePrune(month_id) => {
+                format!("PoolTokenMonthlyVolumeNative:{}:", month_id.to_string())
+            }
             StoreKey::PoolTokenDailyVolumeUsd(day_id, pool_addr, token_addr) => {
                 format!(
                     "PoolTokenDailyVolumeUsd:{}:{}:{}",
@@ -364,11 +970,147 @@ impl StoreKey {
             StoreKey::PoolTokenHourlyVolumeUsdPrune(hour_id) => {
                 format!("PoolTokenHourlyVolumeUsd:{}:", hour_id.to_string(),)
             }
+            StoreKey::PoolTokenWeeklyVolumeUsd(week_id, pool_addr, token_addr) => {
+                format!(
+                    "PoolTokenWeeklyVolumeUsd:{}:{}:{}",
+                    week_id.to_string(),
+                    pool_addr,
+                    token_addr
+                )
+            }
+            StoreKey::PoolTokenWeeklyVolumeUsdPrune(week_id) => {
+                format!("PoolTokenWeeklyVolumeUsd:{}:", week_id.to_string())
+            }
+            StoreKey::PoolTokenMonthlyVolumeUsd(month_id, pool_addr, token_addr) => {
+                format!(
+                    "PoolTokenMonthlyVolumeUsd:{}:{}:{}",
+                    month_id.to_string(),
+                    pool_addr,
+                    token_addr
+                )
+            }
+            StoreKey::PoolTokenMonthlyVolumeUsdPrune(month_id) => {
+                format!("PoolTokenMonthlyVolumeUsd:{}:", month_id.to_string())
+            }
             StoreKey::PoolTvl(addr) => format!("PoolTvl:{}", addr),
+            StoreKey::PoolTotalRevenueUsd(addr) => format!("PoolTotalRevenueUsd:{}", addr),
+            StoreKey::PoolProtocolSideRevenueUsd(addr) => {
+                format!("PoolProtocolSideRevenueUsd:{}", addr)
+            }
+            StoreKey::PoolSupplySideRevenueUsd(addr) => {
+                format!("PoolSupplySideRevenueUsd:{}", addr)
+            }
+            StoreKey::PoolDailyRevenueUsd(day_id, side, addr) => {
+                format!("PoolDailyRevenueUsd:{}:{}:{}", day_id, side.as_str(), addr)
+            }
+            StoreKey::PoolDailyRevenueUsdPrune(day_id) => {
+                format!("PoolDailyRevenueUsd:{}:", day_id)
+            }
+            StoreKey::PoolHourlyRevenueUsd(hour_id, side, addr) => {
+                format!(
+                    "PoolHourlyRevenueUsd:{}:{}:{}",
+                    hour_id,
+                    side.as_str(),
+                    addr
+                )
+            }
+            StoreKey::PoolHourlyRevenueUsdPrune(hour_id) => {
+                format!("PoolHourlyRevenueUsd:{}:", hour_id)
+            }
+            StoreKey::PoolWeeklyRevenueUsd(week_id, side, addr) => {
+                format!(
+                    "PoolWeeklyRevenueUsd:{}:{}:{}",
+                    week_id,
+                    side.as_str(),
+                    addr
+                )
+            }
+            StoreKey::PoolWeeklyRevenueUsdPrune(week_id) => {
+                format!("PoolWeeklyRevenueUsd:{}:", week_id)
+            }
+            StoreKey::PoolMonthlyRevenueUsd(month_id, side, addr) => {
+                format!(
+                    "PoolMonthlyRevenueUsd:{}:{}:{}",
+                    month_id,
+                    side.as_str(),
+                    addr
+                )
+            }
+            StoreKey::PoolMonthlyRevenueUsdPrune(month_id) => {
+                format!("PoolMonthlyRevenueUsd:{}:", month_id)
+            }
             StoreKey::PoolTokenTvl(pool, token) => format!("PoolTokenTvl:{}:{}", pool, token),
             StoreKey::LiquidityGauge(gauge_address) => {
                 format!("LiquidityGauge:{}", gauge_address)
             }
+            StoreKey::RootGauge(gauge_address) => format!("RootGauge:{}", gauge_address),
+            StoreKey::GaugeCrvBridged(gauge_address) => {
+                format!("GaugeCrvBridged:{}", gauge_address)
+            }
+            StoreKey::GaugeVote(gauge_address, user_address) => {
+                format!("GaugeVote:{}:{}", gauge_address, user_address)
+            }
+            StoreKey::VotingEscrowLock(provider_address) => {
+                format!("VotingEscrowLock:{}", provider_address)
+            }
+            StoreKey::GaugeUserBalance(gauge_address, user_address) => {
+                format!("GaugeUserBalance:{}:{}", gauge_address, user_address)
+            }
+            StoreKey::GaugeStakedSupply(gauge_address) => {
+                format!("GaugeStakedSupply:{}", gauge_address)
+            }
+            StoreKey::GaugeWrapperStakedSupply(gauge_address, staking_wrapper) => {
+                format!(
+                    "GaugeWrapperStakedSupply:{}:{}",
+                    gauge_address, staking_wrapper
+                )
+            }
+            StoreKey::GaugeUserPositionCount(gauge_address, user_address) => {
+                format!("GaugeUserPositionCount:{}:{}", gauge_address, user_address)
+            }
+            StoreKey::GaugeUserCrvMinted(gauge_address, user_address) => {
+                format!("GaugeUserCrvMinted:{}:{}", gauge_address, user_address)
+            }
+            StoreKey::GaugeCrvMinted(gauge_address) => {
+                format!("GaugeCrvMinted:{}", gauge_address)
+            }
+            StoreKey::GaugeDailyCrvMinted(day_id, gauge_address) => {
+                format!(
+                    "GaugeDailyCrvMinted:{}:{}",
+                    day_id.to_string(),
+                    gauge_address
+                )
+            }
+            StoreKey::GaugeDailyCrvMintedPrune(day_id) => {
+                format!("GaugeDailyCrvMinted:{}:", day_id.to_string())
+            }
+            StoreKey::GaugeRewardClaimed(gauge_address, reward_token_address) => {
+                format!(
+                    "GaugeRewardClaimed:{}:{}",
+                    gauge_address, reward_token_address
+                )
+            }
+            StoreKey::GaugeUserRewardClaimed(gauge_address, user_address, reward_token_address) => {
+                format!(
+                    "GaugeUserRewardClaimed:{}:{}:{}",
+                    gauge_address, user_address, reward_token_address
+                )
+            }
+            StoreKey::RewardTokenDistributor(gauge_address, reward_token_address) => {
+                format!(
+                    "RewardTokenDistributor:{}:{}",
+                    gauge_address, reward_token_address
+                )
+            }
+            StoreKey::RewardTokenFunded(gauge_address, reward_token_address) => {
+                format!(
+                    "RewardTokenFunded:{}:{}",
+                    gauge_address, reward_token_address
+                )
+            }
+            StoreKey::RewardTokenTotalFunded(reward_token_address) => {
+                format!("RewardTokenTotalFunded:{}", reward_token_address)
+            }
             StoreKey::LiquidityGaugeRewardToken(gauge_address, token_index) => {
                 format!(
                     "LiquidityGaugeRewardToken:{}:{}",
@@ -380,13 +1122,88 @@ impl StoreKey {
                 format!("LiquidityGaugeRewardTokenCount:{}", gauge_address)
             }
             StoreKey::ControllerGaugeAdded(gauge) => format!("ControllerGaugeAdded:{}", gauge),
+            StoreKey::GaugeType(gauge) => format!("GaugeType:{}", gauge),
+            StoreKey::GaugeKillStatus(gauge) => format!("GaugeKillStatus:{}", gauge),
             StoreKey::ProtocolPoolCount => "ProtocolPoolCount".to_string(),
+            StoreKey::ProtocolGaugeCount => "ProtocolGaugeCount".to_string(),
+            StoreKey::GaugeAddress(count) => format!("GaugeAddress:{}", count.to_string()),
             StoreKey::ProtocolVolumeUsd => "ProtocolVolumeUsd".to_string(),
             StoreKey::ProtocolDailyVolumeUsd(day_id) => {
                 format!("ProtocolDailyVolumeUsd:{}", day_id.to_string())
             }
+            StoreKey::ProtocolHourlyVolumeUsd(hour_id) => {
+                format!("ProtocolHourlyVolumeUsd:{}", hour_id.to_string())
+            }
+            StoreKey::ProtocolWeeklyVolumeUsd(week_id) => {
+                format!("ProtocolWeeklyVolumeUsd:{}", week_id.to_string())
+            }
+            StoreKey::ProtocolMonthlyVolumeUsd(month_id) => {
+                format!("ProtocolMonthlyVolumeUsd:{}", month_id.to_string())
+            }
+            StoreKey::ProtocolRevenueUsd(side) => format!("ProtocolRevenueUsd:{}", side.as_str()),
+            StoreKey::ProtocolDailyRevenueUsd(day_id, side) => {
+                format!("ProtocolDailyRevenueUsd:{}:{}", day_id, side.as_str())
+            }
+            StoreKey::ProtocolHourlyRevenueUsd(hour_id, side) => {
+                format!("ProtocolHourlyRevenueUsd:{}:{}", hour_id, side.as_str())
+            }
+            StoreKey::ProtocolWeeklyRevenueUsd(week_id, side) => {
+                format!("ProtocolWeeklyRevenueUsd:{}:{}", week_id, side.as_str())
+            }
+            StoreKey::ProtocolMonthlyRevenueUsd(month_id, side) => {
+                format!("ProtocolMonthlyRevenueUsd:{}:{}", month_id, side.as_str())
+            }
             StoreKey::ProtocolTvl => "ProtocolTvl".to_string(),
             StoreKey::Token(addr) => format!("Token:{}", addr),
+            StoreKey::BasePoolLpToken(lp_token_address) => {
+                format!("BasePoolLpToken:{}", lp_token_address)
+            }
+            StoreKey::CrvUsdMarket(controller_address) => {
+                format!("CrvUsdMarket:{}", controller_address)
+            }
+            StoreKey::CrvUsdMarketAmm(amm_address) => format!("CrvUsdMarketAmm:{}", amm_address),
+            StoreKey::CrvUsdMarketCollateral(controller_address) => {
+                format!("CrvUsdMarketCollateral:{}", controller_address)
+            }
+            StoreKey::CrvUsdMarketStablecoin(controller_address) => {
+                format!("CrvUsdMarketStablecoin:{}", controller_address)
+            }
+            StoreKey::CrvUsdMarketBorrowed(controller_address) => {
+                format!("CrvUsdMarketBorrowed:{}", controller_address)
+            }
+            StoreKey::CrvUsdMarketRepaid(controller_address) => {
+                format!("CrvUsdMarketRepaid:{}", controller_address)
+            }
+            StoreKey::CrvUsdMarketLiquidatedDebt(controller_address) => {
+                format!("CrvUsdMarketLiquidatedDebt:{}", controller_address)
+            }
+            StoreKey::CrvUsdMarketLiquidatedCollateral(controller_address) => {
+                format!("CrvUsdMarketLiquidatedCollateral:{}", controller_address)
+            }
+            StoreKey::PegKeeperDebt(peg_keeper_address) => {
+                format!("PegKeeperDebt:{}", peg_keeper_address)
+            }
+            StoreKey::LendingMarket(controller_address) => {
+                format!("LendingMarket:{}", controller_address)
+            }
+            StoreKey::LendingMarketVault(vault_address) => {
+                format!("LendingMarketVault:{}", vault_address)
+            }
+            StoreKey::LendingMarketDeposited(controller_address) => {
+                format!("LendingMarketDeposited:{}", controller_address)
+            }
+            StoreKey::LendingMarketWithdrawn(controller_address) => {
+                format!("LendingMarketWithdrawn:{}", controller_address)
+            }
+            StoreKey::LendingMarketBorrowed(controller_address) => {
+                format!("LendingMarketBorrowed:{}", controller_address)
+            }
+            StoreKey::LendingMarketRepaid(controller_address) => {
+                format!("LendingMarketRepaid:{}", controller_address)
+            }
+            StoreKey::LendingMarketLiquidatedDebt(controller_address) => {
+                format!("LendingMarketLiquidatedDebt:{}", controller_address)
+            }
             StoreKey::OutputTokenSupply(addr) => format!("OutputTokenSupply:{}", addr),
             StoreKey::InputTokenBalance(pool_addr, token_addr) => {
                 format!("InputTokenBalance:{}:{}", pool_addr, token_addr)
@@ -435,8 +1252,117 @@ impl StoreKey {
             StoreKey::WithdrawHourlyCount(hour_id) => {
                 format!("WithdrawHourlyCount:{}", hour_id.to_string())
             }
+            StoreKey::PoolActiveUserDaily(day_id, pool_addr, user_addr) => {
+                format!(
+                    "PoolActiveUserDaily:{}:{}:{}",
+                    day_id.to_string(),
+                    pool_addr,
+                    user_addr
+                )
+            }
+            StoreKey::PoolActiveUserDailyPrune(day_id) => {
+                format!("PoolActiveUserDaily:{}:", day_id.to_string())
+            }
+            StoreKey::PoolActiveUserHourly(hour_id, pool_addr, user_addr) => {
+                format!(
+                    "PoolActiveUserHourly:{}:{}:{}",
+                    hour_id.to_string(),
+                    pool_addr,
+                    user_addr
+                )
+            }
+            StoreKey::PoolActiveUserHourlyPrune(hour_id) => {
+                format!("PoolActiveUserHourly:{}:", hour_id.to_string())
+            }
+            StoreKey::PoolActiveUserDailyCount(day_id, pool_addr) => {
+                format!(
+                    "PoolActiveUserDailyCount:{}:{}",
+                    day_id.to_string(),
+                    pool_addr
+                )
+            }
+            StoreKey::PoolActiveUserDailyCountPrune(day_id) => {
+                format!("PoolActiveUserDailyCount:{}:", day_id.to_string())
+            }
+            StoreKey::PoolActiveUserHourlyCount(hour_id, pool_addr) => {
+                format!(
+                    "PoolActiveUserHourlyCount:{}:{}",
+                    hour_id.to_string(),
+                    pool_addr
+                )
+            }
+            StoreKey::PoolActiveUserHourlyCountPrune(hour_id) => {
+                format!("PoolActiveUserHourlyCount:{}:", hour_id.to_string())
+            }
+            StoreKey::PoolSwapDailyCount(day_id, pool_addr) => {
+                format!("PoolSwapDailyCount:{}:{}", day_id.to_string(), pool_addr)
+            }
+            StoreKey::PoolSwapDailyCountPrune(day_id) => {
+                format!("PoolSwapDailyCount:{}:", day_id.to_string())
+            }
+            StoreKey::PoolSwapHourlyCount(hour_id, pool_addr) => {
+                format!("PoolSwapHourlyCount:{}:{}", hour_id.to_string(), pool_addr)
+            }
+            StoreKey::PoolSwapHourlyCountPrune(hour_id) => {
+                format!("PoolSwapHourlyCount:{}:", hour_id.to_string())
+            }
+            StoreKey::PoolDepositDailyCount(day_id, pool_addr) => {
+                format!("PoolDepositDailyCount:{}:{}", day_id.to_string(), pool_addr)
+            }
+            StoreKey::PoolDepositDailyCountPrune(day_id) => {
+                format!("PoolDepositDailyCount:{}:", day_id.to_string())
+            }
+            StoreKey::PoolDepositHourlyCount(hour_id, pool_addr) => {
+                format!(
+                    "PoolDepositHourlyCount:{}:{}",
+                    hour_id.to_string(),
+                    pool_addr
+                )
+            }
+            StoreKey::PoolDepositHourlyCountPrune(hour_id) => {
+                format!("PoolDepositHourlyCount:{}:", hour_id.to_string())
+            }
+            StoreKey::PoolWithdrawDailyCount(day_id, pool_addr) => {
+                format!(
+                    "PoolWithdrawDailyCount:{}:{}",
+                    day_id.to_string(),
+                    pool_addr
+                )
+            }
+            StoreKey::PoolWithdrawDailyCountPrune(day_id) => {
+                format!("PoolWithdrawDailyCount:{}:", day_id.to_string())
+            }
+            StoreKey::PoolWithdrawHourlyCount(hour_id, pool_addr) => {
+                format!(
+                    "PoolWithdrawHourlyCount:{}:{}",
+                    hour_id.to_string(),
+                    pool_addr
+                )
+            }
+            StoreKey::PoolWithdrawHourlyCountPrune(hour_id) => {
+                format!("PoolWithdrawHourlyCount:{}:", hour_id.to_string())
+            }
+            StoreKey::UserLastActiveDay(user_addr) => format!("UserLastActiveDay:{}", user_addr),
+            StoreKey::PoolUserLastActiveDay(pool_addr, user_addr) => {
+                format!("PoolUserLastActiveDay:{}:{}", pool_addr, user_addr)
+            }
+            StoreKey::UserLastActiveDayCount(day_id) => {
+                format!("UserLastActiveDayCount:{}", day_id.to_string())
+            }
+            StoreKey::PoolUserLastActiveDayCount(day_id, pool_addr) => {
+                format!(
+                    "PoolUserLastActiveDayCount:{}:{}",
+                    day_id.to_string(),
+                    pool_addr
+                )
+            }
+            StoreKey::PoolUserLastActiveDayCountPrune(day_id) => {
+                format!("PoolUserLastActiveDayCount:{}:", day_id.to_string())
+            }
             StoreKey::CurrentDayId => "CurrentDayId".to_string(),
             StoreKey::CurrentHourId => "CurrentHourId".to_string(),
+            StoreKey::CurrentWeekId => "CurrentWeekId".to_string(),
+            StoreKey::CurrentMonthId => "CurrentMonthId".to_string(),
             StoreKey::CRVInflationRate => "CRVInflationRate".to_string(),
             StoreKey::UniswapPriceByTokenAddress(addr) => {
                 format!("UsdPriceByTokenAddress:{}", addr)
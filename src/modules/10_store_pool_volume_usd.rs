@@ -11,7 +11,7 @@ use substreams::{
 use crate::{
     common::{
         pool_utils::{is_base_to_meta_exchange, is_meta_to_base_exchange},
-        prices::get_token_usd_price,
+        prices::get_pool_token_usd_price,
     },
     key_management::store_key_manager::StoreKey,
     pb::{
@@ -26,7 +26,7 @@ use crate::{
             },
             setup_timeframe_pruning,
         },
-        utils::calculate_day_hour_id,
+        utils::{calculate_day_hour_id, calculate_week_month_id},
     },
 };
 
@@ -54,7 +54,9 @@ pub fn store_pool_volume_usd(
         &[&pool_volume_usd_pruner, &token_volume_usd_pruner],
     );
 
-    let (day_id, hour_id) = calculate_day_hour_id(clock.timestamp.unwrap().seconds);
+    let timestamp = clock.timestamp.unwrap().seconds;
+    let (day_id, hour_id) = calculate_day_hour_id(timestamp);
+    let (week_id, month_id) = calculate_week_month_id(timestamp);
 
     for event in events.pool_events {
         if let Some(event_type) = &event.r#type {
@@ -80,10 +82,18 @@ pub fn store_pool_volume_usd(
                     let token_in = token_in.unwrap();
                     let token_out = token_out.unwrap();
 
-                    let (token_in_price, _) =
-                        get_token_usd_price(&token_in, &uniswap_prices, &chainlink_prices);
-                    let (token_out_price, _) =
-                        get_token_usd_price(&token_out, &uniswap_prices, &chainlink_prices);
+                    let (token_in_price, _) = get_pool_token_usd_price(
+                        &pool,
+                        token_in,
+                        &uniswap_prices,
+                        &chainlink_prices,
+                    );
+                    let (token_out_price, _) = get_pool_token_usd_price(
+                        &pool,
+                        token_out,
+                        &uniswap_prices,
+                        &chainlink_prices,
+                    );
 
                     let token_in_amount_usd =
                         token_in_price * swap.token_in_amount_big().to_decimal(token_in.decimals);
@@ -99,6 +109,8 @@ pub fn store_pool_volume_usd(
                             StoreKey::pool_volume_usd_key(&event.pool_address),
                             StoreKey::pool_volume_usd_daily_key(&day_id, &event.pool_address),
                             StoreKey::pool_volume_usd_hourly_key(&hour_id, &event.pool_address),
+                            StoreKey::pool_volume_usd_weekly_key(&week_id, &event.pool_address),
+                            StoreKey::pool_volume_usd_monthly_key(&month_id, &event.pool_address),
                         ],
                         &volume_usd,
                     );
@@ -115,6 +127,16 @@ pub fn store_pool_volume_usd(
                                 &event.pool_address,
                                 &swap.token_in_ref().token_address,
                             ),
+                            StoreKey::pool_token_volume_usd_weekly_key(
+                                &week_id,
+                                &event.pool_address,
+                                &swap.token_in_ref().token_address,
+                            ),
+                            StoreKey::pool_token_volume_usd_monthly_key(
+                                &month_id,
+                                &event.pool_address,
+                                &swap.token_in_ref().token_address,
+                            ),
                         ],
                         token_in_amount_usd,
                     );
@@ -131,6 +153,16 @@ pub fn store_pool_volume_usd(
                                 &event.pool_address,
                                 &swap.token_out_ref().token_address,
                             ),
+                            StoreKey::pool_token_volume_usd_weekly_key(
+                                &week_id,
+                                &event.pool_address,
+                                &swap.token_out_ref().token_address,
+                            ),
+                            StoreKey::pool_token_volume_usd_monthly_key(
+                                &month_id,
+                                &event.pool_address,
+                                &swap.token_out_ref().token_address,
+                            ),
                         ],
                         token_out_amount_usd,
                     );
@@ -151,8 +183,9 @@ pub fn store_pool_volume_usd(
                         };
 
                         if let Some(meta_token) = meta_token_opt {
-                            let (meta_token_price, _) = get_token_usd_price(
-                                &meta_token,
+                            let (meta_token_price, _) = get_pool_token_usd_price(
+                                &pool,
+                                meta_token,
                                 &uniswap_prices,
                                 &chainlink_prices,
                             );
@@ -176,6 +209,14 @@ pub fn store_pool_volume_usd(
                                         &hour_id,
                                         &event.pool_address,
                                     ),
+                                    StoreKey::pool_volume_usd_weekly_key(
+                                        &week_id,
+                                        &event.pool_address,
+                                    ),
+                                    StoreKey::pool_volume_usd_monthly_key(
+                                        &month_id,
+                                        &event.pool_address,
+                                    ),
                                     StoreKey::pool_token_volume_usd_daily_key(
                                         &day_id,
                                         &event.pool_address,
@@ -186,6 +227,16 @@ pub fn store_pool_volume_usd(
                                         &event.pool_address,
                                         &meta_token.address,
                                     ),
+                                    StoreKey::pool_token_volume_usd_weekly_key(
+                                        &week_id,
+                                        &event.pool_address,
+                                        &meta_token.address,
+                                    ),
+                                    StoreKey::pool_token_volume_usd_monthly_key(
+                                        &month_id,
+                                        &event.pool_address,
+                                        &meta_token.address,
+                                    ),
                                 ],
                                 &meta_token_amount_usd,
                             );
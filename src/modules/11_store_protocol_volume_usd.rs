@@ -13,7 +13,7 @@ use crate::{
             pruners::protocol_volume_usd_pruner::ProtocolVolumeUsdPruneAction,
             setup_timeframe_pruning,
         },
-        utils::calculate_day_hour_id,
+        utils::{calculate_day_hour_id, calculate_week_month_id},
     },
 };
 
@@ -32,7 +32,9 @@ pub fn store_protocol_volume_usd(
     };
     setup_timeframe_pruning(&current_time_deltas, &[&protocol_volume_usd_pruner]);
 
-    let (day_id, _) = calculate_day_hour_id(clock.timestamp.unwrap().seconds);
+    let timestamp = clock.timestamp.unwrap().seconds;
+    let (day_id, hour_id) = calculate_day_hour_id(timestamp);
+    let (week_id, month_id) = calculate_week_month_id(timestamp);
 
     for delta in pool_volume_deltas.iter() {
         if key::first_segment(&delta.key) == "PoolVolumeUsd" {
@@ -42,6 +44,9 @@ pub fn store_protocol_volume_usd(
                 &vec![
                     StoreKey::protocol_volume_usd_key(),
                     StoreKey::protocol_daily_volume_usd_key(&day_id),
+                    StoreKey::protocol_hourly_volume_usd_key(&hour_id),
+                    StoreKey::protocol_weekly_volume_usd_key(&week_id),
+                    StoreKey::protocol_monthly_volume_usd_key(&month_id),
                 ],
                 tvl_diff,
             );
@@ -10,9 +10,12 @@ use substreams::{
 };
 
 use crate::{
-    common::prices::get_token_usd_price,
+    common::{pool_utils::get_rebasing_token_balance, prices::get_pool_token_usd_price},
     key_management::store_key_manager::StoreKey,
-    pb::{curve::types::v1::Pool, uniswap_pricing::v1::Erc20Price},
+    pb::{
+        curve::types::v1::{AssetType, Pool},
+        uniswap_pricing::v1::Erc20Price,
+    },
 };
 
 #[substreams::handlers::store]
@@ -31,8 +34,16 @@ pub fn store_pool_tvl(
         let pool = pools_store.must_get_last(StoreKey::pool_key(&pool_address));
         let mut tvl = BigDecimal::zero();
 
-        for token in pool.input_tokens {
-            let store_balance = if token.address == token_address {
+        for token in &pool.input_tokens {
+            let store_balance = if token.asset_type() == AssetType::Rebasing {
+                // Rebasing balances drift from those tracked through pool events, so use the pool's own.
+                get_rebasing_token_balance(&pool, token).or_else(|| {
+                    balances_store.get_last(StoreKey::input_token_balance_key(
+                        &pool_address,
+                        &token.address,
+                    ))
+                })
+            } else if token.address == token_address {
                 // We know that a delta matches this token address,
                 // so get the specific store value using ordinal.
                 balances_store.get_at(
@@ -51,7 +62,8 @@ pub fn store_pool_tvl(
                 // TODO: We may be able to optimise here by getting the token price upstream when the balances change.
                 //       Check if we get the price in any other modules that use the balances store, and if so, we can
                 //       minimise the amount of store calls by getting it once and setting it alongside the balance changes.
-                let (price_usd, _) = get_token_usd_price(&token, &uniswap_prices, &chainlink_prices);
+                let (price_usd, _) =
+                    get_pool_token_usd_price(&pool, token, &uniswap_prices, &chainlink_prices);
                 let token_tvl = balance.to_decimal(token.decimals) * price_usd;
 
                 // Store Input Token TVL for a specific Pool
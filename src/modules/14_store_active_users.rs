@@ -8,7 +8,10 @@ use crate::{
     pb::curve::types::v1::Events,
     timeframe_management::{
         pruning::{
-            pruners::protocol_active_user_pruner::ProtocolActiveUserPruneAction,
+            pruners::{
+                pool_active_user_pruner::PoolActiveUserPruneAction,
+                protocol_active_user_pruner::ProtocolActiveUserPruneAction,
+            },
             setup_timeframe_pruning,
         },
         utils::calculate_day_hour_id,
@@ -22,14 +25,20 @@ pub fn store_active_users(
     current_time_deltas: Deltas<DeltaInt64>,
     output_store: StoreSetIfNotExistsInt64,
 ) {
-    // Initialise pruning for active users data using `ProtocolActiveUserPruneAction`.
+    // Initialise pruning for active users data using `ProtocolActiveUserPruneAction`/`PoolActiveUserPruneAction`.
     // This setup registers the pruner to execute when new timeframes (day/hour) are detected,
     // ensuring outdated data is removed to maintain store efficiency.
     let protocol_active_user_pruner = ProtocolActiveUserPruneAction {
         store: &output_store,
     };
+    let pool_active_user_pruner = PoolActiveUserPruneAction {
+        store: &output_store,
+    };
 
-    setup_timeframe_pruning(&current_time_deltas, &[&protocol_active_user_pruner]);
+    setup_timeframe_pruning(
+        &current_time_deltas,
+        &[&protocol_active_user_pruner, &pool_active_user_pruner],
+    );
 
     let (day_id, hour_id) = calculate_day_hour_id(clock.timestamp.unwrap().seconds);
 
@@ -40,6 +49,16 @@ pub fn store_active_users(
                 StoreKey::active_user_key(&event.from_address),
                 StoreKey::active_user_daily_key(&day_id, &event.from_address),
                 StoreKey::active_user_hourly_key(&hour_id, &event.from_address),
+                StoreKey::pool_active_user_daily_key(
+                    &day_id,
+                    &event.pool_address,
+                    &event.from_address,
+                ),
+                StoreKey::pool_active_user_hourly_key(
+                    &hour_id,
+                    &event.pool_address,
+                    &event.from_address,
+                ),
             ],
             &1,
         );
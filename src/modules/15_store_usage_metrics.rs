@@ -1,16 +1,21 @@
 use num_traits::ToPrimitive;
 use substreams::{
     key,
-    pb::substreams::Clock,
+    pb::substreams::{store_delta::Operation, Clock},
     store::{DeltaInt64, Deltas, StoreAdd, StoreAddInt64, StoreNew},
 };
 
 use crate::{
+    constants::ROLLING_WINDOW_7D,
     key_management::store_key_manager::StoreKey,
     pb::curve::types::v1::{events::pool_event::Type, Events},
     timeframe_management::{
         pruning::{
-            pruners::protocol_usage_metrics_pruner::ProtocolUsageMetricsPruneAction,
+            pruners::{
+                pool_usage_metrics_pruner::PoolUsageMetricsPruneAction,
+                protocol_usage_metrics_pruner::ProtocolUsageMetricsPruneAction,
+                rolling_active_users_pruner::RollingActiveUsersPruner,
+            },
             setup_timeframe_pruning,
         },
         utils::calculate_day_hour_id,
@@ -22,22 +27,40 @@ pub fn store_usage_metrics(
     clock: Clock,
     events: Events,
     active_users_deltas: Deltas<DeltaInt64>,
+    user_last_active_day_deltas: Deltas<DeltaInt64>,
     current_time_deltas: Deltas<DeltaInt64>,
     output_store: StoreAddInt64,
 ) {
-    // Initialise pruning for usage metrics data using `ProtocolUsageMetricsPruneAction`.
-    // This setup registers the pruner to execute when new timeframes (day/hour) are detected,
+    // Initialise pruning for usage metrics data using `ProtocolUsageMetricsPruneAction`/`PoolUsageMetricsPruneAction`/`RollingActiveUsersPruner`.
+    // This setup registers the pruners to execute when new timeframes (day/hour) are detected,
     // ensuring outdated data is removed to maintain store efficiency.
     let protocol_usage_metrics_pruner = ProtocolUsageMetricsPruneAction {
         store: &output_store,
     };
+    let pool_usage_metrics_pruner = PoolUsageMetricsPruneAction {
+        store: &output_store,
+    };
+    let rolling_active_users_pruner = RollingActiveUsersPruner {
+        store: &output_store,
+    };
 
-    setup_timeframe_pruning(&current_time_deltas, &[&protocol_usage_metrics_pruner]);
+    setup_timeframe_pruning(
+        &current_time_deltas,
+        &[
+            &protocol_usage_metrics_pruner,
+            &pool_usage_metrics_pruner,
+            &rolling_active_users_pruner,
+        ],
+    );
 
     let (day_id, hour_id) = calculate_day_hour_id(clock.timestamp.unwrap().seconds);
 
     let (general, daily, hourly) = separate_active_users_deltas(&active_users_deltas);
 
+    update_last_active_day_counts(&output_store, &user_last_active_day_deltas, &day_id);
+
+    update_pool_active_user_counts(&output_store, &active_users_deltas);
+
     if !general.deltas.is_empty() {
         output_store.add(
             0,
@@ -68,23 +91,32 @@ pub fn store_usage_metrics(
             total_events_count += 1;
 
             if let Some(event_type) = &event.r#type {
-                match event_type {
-                    Type::SwapEvent(_) => {
-                        swap_events_count += 1;
-                    }
-                    Type::SwapUnderlyingMetaEvent(_) => {
-                        swap_events_count += 1;
-                    }
-                    Type::SwapUnderlyingLendingEvent(_) => {
+                let pool_count_keys = match event_type {
+                    Type::SwapEvent(_)
+                    | Type::SwapUnderlyingMetaEvent(_)
+                    | Type::SwapUnderlyingLendingEvent(_) => {
                         swap_events_count += 1;
+                        vec![
+                            StoreKey::pool_swap_daily_count_key(&day_id, &event.pool_address),
+                            StoreKey::pool_swap_hourly_count_key(&hour_id, &event.pool_address),
+                        ]
                     }
                     Type::DepositEvent(_) => {
                         deposit_events_count += 1;
+                        vec![
+                            StoreKey::pool_deposit_daily_count_key(&day_id, &event.pool_address),
+                            StoreKey::pool_deposit_hourly_count_key(&hour_id, &event.pool_address),
+                        ]
                     }
                     Type::WithdrawEvent(_) => {
                         withdraw_events_count += 1;
+                        vec![
+                            StoreKey::pool_withdraw_daily_count_key(&day_id, &event.pool_address),
+                            StoreKey::pool_withdraw_hourly_count_key(&hour_id, &event.pool_address),
+                        ]
                     }
-                }
+                };
+                output_store.add_many(0, &pool_count_keys, 1);
             }
         }
 
@@ -124,6 +156,74 @@ pub fn store_usage_metrics(
     }
 }
 
+// Keeps a count of the users whose most recent activity was on a given day.
+// When a user is active again on a later day, they are moved from the count of their previous
+// day to the current day. Summing these counts over a rolling window gives the unique users in that window.
+fn update_last_active_day_counts(
+    output_store: &StoreAddInt64,
+    user_last_active_day_deltas: &Deltas<DeltaInt64>,
+    day_id: &i64,
+) {
+    for delta in user_last_active_day_deltas.iter() {
+        if delta.operation == Operation::Update && delta.old_value == delta.new_value {
+            continue;
+        }
+
+        let (previous_day_key, current_day_key) = match key::first_segment(&delta.key) {
+            "UserLastActiveDay" => (
+                StoreKey::user_last_active_day_count_key(&delta.old_value),
+                StoreKey::user_last_active_day_count_key(&delta.new_value),
+            ),
+            "PoolUserLastActiveDay" => {
+                let pool_address = key::segment_at(&delta.key, 1);
+                (
+                    StoreKey::pool_user_last_active_day_count_key(&delta.old_value, pool_address),
+                    StoreKey::pool_user_last_active_day_count_key(&delta.new_value, pool_address),
+                )
+            }
+            _ => continue,
+        };
+
+        // Only decrement the previous day if it is still within the rolling window,
+        // otherwise it has already been pruned and there is nothing to move the user from.
+        if delta.operation == Operation::Update && delta.old_value > day_id - ROLLING_WINDOW_7D {
+            output_store.add(delta.ordinal, previous_day_key, -1);
+        }
+        output_store.add(delta.ordinal, current_day_key, 1);
+    }
+}
+
+// Counts the unique users of each pool per day and hour.
+// The active users store only emits a create delta the first time a user interacts with a pool in a
+// timeframe, so each create delta is a new unique user. Deletes from pruning are ignored.
+fn update_pool_active_user_counts(
+    output_store: &StoreAddInt64,
+    active_users_deltas: &Deltas<DeltaInt64>,
+) {
+    for delta in active_users_deltas.iter() {
+        if delta.operation != Operation::Create {
+            continue;
+        }
+
+        let count_key = match key::first_segment(&delta.key) {
+            "PoolActiveUserDaily" => StoreKey::pool_active_user_daily_count_key(
+                &parse_time_frame_id(&delta.key),
+                key::segment_at(&delta.key, 2),
+            ),
+            "PoolActiveUserHourly" => StoreKey::pool_active_user_hourly_count_key(
+                &parse_time_frame_id(&delta.key),
+                key::segment_at(&delta.key, 2),
+            ),
+            _ => continue,
+        };
+        output_store.add(delta.ordinal, count_key, 1);
+    }
+}
+
+fn parse_time_frame_id(key: &str) -> i64 {
+    key::segment_at(key, 1).parse::<i64>().unwrap_or_default()
+}
+
 fn separate_active_users_deltas(
     deltas: &Deltas<DeltaInt64>,
 ) -> (Deltas<DeltaInt64>, Deltas<DeltaInt64>, Deltas<DeltaInt64>) {
@@ -3,12 +3,19 @@ use substreams::{
     store::{StoreNew, StoreSet, StoreSetInt64},
 };
 
-use crate::{key_management::store_key_manager::StoreKey, timeframe_management::utils::calculate_day_hour_id};
+use crate::{
+    key_management::store_key_manager::StoreKey,
+    timeframe_management::utils::{calculate_day_hour_id, calculate_week_month_id},
+};
 
 // TODO: Move this to the first module
 #[substreams::handlers::store]
 pub fn store_current_time(clock: Clock, store: StoreSetInt64) {
-    let (day_id, hour_id) = calculate_day_hour_id(clock.timestamp.unwrap().seconds);
+    let timestamp = clock.timestamp.unwrap().seconds;
+    let (day_id, hour_id) = calculate_day_hour_id(timestamp);
+    let (week_id, month_id) = calculate_week_month_id(timestamp);
     store.set(0, StoreKey::current_day_id_key(), &day_id);
     store.set(0, StoreKey::current_hour_id_key(), &hour_id);
+    store.set(0, StoreKey::current_week_id_key(), &week_id);
+    store.set(0, StoreKey::current_month_id_key(), &month_id);
 }
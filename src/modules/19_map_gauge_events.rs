@@ -1,12 +1,22 @@
 use std::collections::HashSet;
 
 use crate::{
-    abi::curve::{gauges, ownership_proxies},
+    abi::{
+        common::erc20,
+        curve::{gauges, minter, ownership_proxies},
+    },
+    common::utils,
     key_management::store_key_manager::StoreKey,
+    network_config::{
+        CHILD_GAUGE_BRIDGER_ADDRESS, CHILD_GAUGE_FACTORY_ADDRESS, CRV_TOKEN_ADDRESS, MINTER_ADDRESS,
+    },
     pb::curve::types::v1::{
-        AddRewardEvent, GaugeLiquidityEventType, LiquidityEvent, LiquidityGauge,
-        LiquidityGaugeEvents,
+        AddRewardEvent, ChildGaugeEmissionEvent, CrvMintedEvent, DepositRewardTokenEvent,
+        GaugeKillStatusEvent, GaugeLiquidityEventType, GaugeTransferEvent, LiquidityEvent,
+        LiquidityGauge, LiquidityGaugeEvents, RewardClaimEvent, RootGauge, RootGaugeEmissionEvent,
+        SetRewardDistributorEvent,
     },
+    rpc,
 };
 use substreams::{
     errors::Error,
@@ -16,22 +26,101 @@ use substreams::{
 };
 use substreams_ethereum::{
     pb::eth::v2::{self as eth, Log, TransactionTrace},
-    Event, Function,
+    Event, Function, NULL_ADDRESS,
 };
 
 #[substreams::handlers::map]
 pub fn map_gauge_events(
     blk: eth::Block,
     gauge_store: StoreGetProto<LiquidityGauge>,
+    root_gauge_store: StoreGetProto<RootGauge>,
 ) -> Result<LiquidityGaugeEvents, Error> {
     let mut gauge_events = LiquidityGaugeEvents::default();
     let mut liquidity_events: Vec<LiquidityEvent> = Vec::new();
     let mut unique_add_reward_events: Vec<AddRewardEvent> = Vec::new();
+    let mut crv_minted_events: Vec<CrvMintedEvent> = Vec::new();
+    let mut transfer_events: Vec<GaugeTransferEvent> = Vec::new();
+    let mut reward_claim_events: Vec<RewardClaimEvent> = Vec::new();
+    let mut kill_status_events: Vec<GaugeKillStatusEvent> = Vec::new();
+    let mut set_reward_distributor_events: Vec<SetRewardDistributorEvent> = Vec::new();
+    let mut deposit_reward_token_events: Vec<DepositRewardTokenEvent> = Vec::new();
+    let mut root_gauge_emission_events: Vec<RootGaugeEmissionEvent> = Vec::new();
+    let mut child_gauge_emission_events: Vec<ChildGaugeEmissionEvent> = Vec::new();
     let mut seen_tx_hashes = HashSet::new();
 
     for trx in blk.transactions() {
         // Handle liquidity events (`Deposit`, `Withdraw`) by digging into the logs.
         for (log, _) in trx.logs_with_calls() {
+            // Handle CRV claims (`Minted`), which are emitted from the Minter rather than the gauge.
+            if MINTER_ADDRESS.map_or(false, |minter| log.address == minter) {
+                if let Some(minted) = minter::events::Minted::match_and_decode(&log) {
+                    // Root gauges mint their own emissions before bridging them to the child gauge.
+                    let gauge_address = Hex::encode(&minted.gauge);
+                    match root_gauge_store.get_last(StoreKey::root_gauge_key(&gauge_address)) {
+                        Some(root_gauge) => {
+                            root_gauge_emission_events.push(RootGaugeEmissionEvent {
+                                gauge: root_gauge.gauge,
+                                chain_id: root_gauge.chain_id,
+                                total_transmitted: minted.minted.to_string(),
+                                transaction_hash: Hex::encode(&trx.hash),
+                                tx_index: trx.index,
+                                log_index: log.index,
+                                log_ordinal: log.ordinal,
+                                timestamp: blk.timestamp_seconds(),
+                                block_number: blk.number,
+                            })
+                        }
+                        None => handle_crv_minted_event(
+                            &minted.recipient,
+                            &minted.gauge,
+                            &minted.minted,
+                            &trx,
+                            &blk,
+                            &log,
+                            &gauge_store,
+                            &mut crv_minted_events,
+                        ),
+                    }
+                }
+                continue;
+            }
+
+            // On sidechains, CRV is claimed from the ChildGaugeFactory, which acts as the Minter.
+            if let Some(child_gauge_factory) = CHILD_GAUGE_FACTORY_ADDRESS {
+                if log.address == child_gauge_factory {
+                    if let Some(minted) =
+                        gauges::child_gauge_factory::events::Minted::match_and_decode(&log)
+                    {
+                        handle_crv_minted_event(
+                            &minted.u_user,
+                            &minted.u_gauge,
+                            &minted.u_new_total,
+                            &trx,
+                            &blk,
+                            &log,
+                            &gauge_store,
+                            &mut crv_minted_events,
+                        );
+                    }
+                    continue;
+                }
+                // CRV emissions are bridged from the root gauge on mainnet to the child gauge.
+                if log.address == CRV_TOKEN_ADDRESS {
+                    if let Some(transfer) = erc20::events::Transfer::match_and_decode(&log) {
+                        handle_child_gauge_emission(
+                            &transfer,
+                            &child_gauge_factory,
+                            &trx,
+                            &blk,
+                            &log,
+                            &gauge_store,
+                            &mut child_gauge_emission_events,
+                        );
+                    }
+                    continue;
+                }
+            }
+
             let gauge_address = Hex::encode(&log.address);
             let gauge_opt = gauge_store.get_last(StoreKey::liquidity_gauge_key(&gauge_address));
 
@@ -64,9 +153,24 @@ pub fn map_gauge_events(
                         &mut liquidity_events,
                     );
                 }
+                // Gauge tokens are transferable from `LiquidityGaugeV2` onwards, and the `Transfer`
+                // event ABI is the same across these versions. Child gauges on sidechains share the
+                // `Deposit`, `Withdraw` and `Transfer` event ABIs, so they are handled here too.
+                if let Some(transfer) =
+                    gauges::liquidity_gauge_v6::events::Transfer::match_and_decode(&log)
+                {
+                    handle_transfer_event(
+                        &transfer,
+                        &trx,
+                        &gauge,
+                        &blk,
+                        &log,
+                        &mut transfer_events,
+                    );
+                }
             }
         }
-        // Handle AddReward function calls as these do not emit events and need to be captured by examining function calls.
+        // Handle AddReward, ClaimRewards, SetKilled, SetRewardDistributor and DepositRewardToken function calls as these do not emit events and need to be captured by examining function calls.
         for call_view in trx.calls().filter(|call| !call.call.state_reverted) {
             if let Some(add_reward_call) =
                 // Although there are multiple ABIs for proxies, the `add_reward` function remains the same.
@@ -81,6 +185,7 @@ pub fn map_gauge_events(
                     &add_reward_call.u_gauge,
                     &add_reward_call.u_reward_token,
                     &add_reward_call.u_distributor,
+                    call_view.call.begin_ordinal,
                     &mut seen_tx_hashes,
                     &mut unique_add_reward_events,
                     &gauge_store,
@@ -99,16 +204,80 @@ pub fn map_gauge_events(
                     &call_view.call.address,
                     &add_reward_call.u_reward_token,
                     &add_reward_call.u_distributor,
+                    call_view.call.begin_ordinal,
                     &mut seen_tx_hashes,
                     &mut unique_add_reward_events,
                     &gauge_store,
                 );
             }
+            if let Some(user) = decode_claim_rewards_user(&call_view.call) {
+                handle_reward_claim_call(
+                    trx,
+                    &blk,
+                    &call_view.call,
+                    &user,
+                    &gauge_store,
+                    &mut reward_claim_events,
+                );
+            }
+            if let Some(set_killed_call) =
+                // The `set_killed` function ABI is the same across gauge versions V2 to V6.
+                // V1 gauges cannot be killed.
+                gauges::liquidity_gauge_v6::functions::SetKilled::match_and_decode(
+                        &call_view.call,
+                    )
+            {
+                handle_set_killed_call(
+                    trx,
+                    &blk,
+                    &call_view.call,
+                    set_killed_call.u_is_killed,
+                    &gauge_store,
+                    &mut kill_status_events,
+                );
+            }
+            // Ownership proxies forward `set_reward_distributor` calls to the gauge itself, so matching
+            // the gauge function also catches the calls made through proxies. The ABI is the same
+            // across gauge versions V4 to V6.
+            if let Some(set_distributor_call) =
+                gauges::liquidity_gauge_v6::functions::SetRewardDistributor::match_and_decode(
+                    &call_view.call,
+                )
+            {
+                handle_set_reward_distributor_call(
+                    trx,
+                    &blk,
+                    &call_view.call,
+                    &set_distributor_call.u_reward_token,
+                    &set_distributor_call.u_distributor,
+                    &gauge_store,
+                    &mut set_reward_distributor_events,
+                );
+            }
+            if let Some((reward_token, amount)) = decode_deposit_reward_token(&call_view.call) {
+                handle_deposit_reward_token_call(
+                    trx,
+                    &blk,
+                    &call_view.call,
+                    &reward_token,
+                    &amount,
+                    &gauge_store,
+                    &mut deposit_reward_token_events,
+                );
+            }
         }
     }
 
     gauge_events.liquidity_events = liquidity_events;
     gauge_events.add_reward_events = unique_add_reward_events;
+    gauge_events.crv_minted_events = crv_minted_events;
+    gauge_events.transfer_events = transfer_events;
+    gauge_events.reward_claim_events = reward_claim_events;
+    gauge_events.kill_status_events = kill_status_events;
+    gauge_events.set_reward_distributor_events = set_reward_distributor_events;
+    gauge_events.deposit_reward_token_events = deposit_reward_token_events;
+    gauge_events.root_gauge_emission_events = root_gauge_emission_events;
+    gauge_events.child_gauge_emission_events = child_gauge_emission_events;
 
     Ok(gauge_events)
 }
@@ -138,6 +307,99 @@ fn handle_liquidity_event(
             log_ordinal: log.ordinal,
             timestamp: blk.timestamp_seconds(),
             block_number: blk.number,
+            staking_wrapper: utils::get_staking_wrapper(event_provider) as i32,
+        });
+    }
+}
+
+fn handle_transfer_event(
+    transfer: &gauges::liquidity_gauge_v6::events::Transfer,
+    trx: &TransactionTrace,
+    gauge: &LiquidityGauge,
+    blk: &eth::Block,
+    log: &Log,
+    transfer_events: &mut Vec<GaugeTransferEvent>,
+) {
+    // Mints and burns are emitted alongside `Deposit` and `Withdraw` events, which are handled above.
+    if transfer.u_from == NULL_ADDRESS || transfer.u_to == NULL_ADDRESS {
+        return;
+    }
+    transfer_events.push(GaugeTransferEvent {
+        gauge: gauge.gauge.clone(),
+        pool: gauge.pool.clone(),
+        from: Hex::encode(&transfer.u_from),
+        to: Hex::encode(&transfer.u_to),
+        value: transfer.u_value.to_string(),
+        transaction_hash: Hex::encode(&trx.hash),
+        tx_index: trx.index,
+        log_index: log.index,
+        log_ordinal: log.ordinal,
+        timestamp: blk.timestamp_seconds(),
+        block_number: blk.number,
+    });
+}
+
+// Both the Minter and the ChildGaugeFactory report the total CRV minted to a user from a gauge.
+fn handle_crv_minted_event(
+    recipient: &Vec<u8>,
+    gauge: &Vec<u8>,
+    minted: &BigInt,
+    trx: &TransactionTrace,
+    blk: &eth::Block,
+    log: &Log,
+    gauge_store: &StoreGetProto<LiquidityGauge>,
+    crv_minted_events: &mut Vec<CrvMintedEvent>,
+) {
+    let gauge_address = Hex::encode(gauge);
+    match gauge_store.get_last(StoreKey::liquidity_gauge_key(&gauge_address)) {
+        Some(gauge) => crv_minted_events.push(CrvMintedEvent {
+            gauge: gauge_address,
+            pool: gauge.pool,
+            recipient: Hex::encode(recipient),
+            minted: minted.to_string(),
+            transaction_hash: Hex::encode(&trx.hash),
+            tx_index: trx.index,
+            log_index: log.index,
+            log_ordinal: log.ordinal,
+            timestamp: blk.timestamp_seconds(),
+            block_number: blk.number,
+        }),
+        None => substreams::log::debug!(
+            "Skipping Minted event for untracked gauge {} in tx {}",
+            gauge_address,
+            Hex::encode(&trx.hash)
+        ),
+    }
+}
+
+fn handle_child_gauge_emission(
+    transfer: &erc20::events::Transfer,
+    child_gauge_factory: &[u8; 20],
+    trx: &TransactionTrace,
+    blk: &eth::Block,
+    log: &Log,
+    gauge_store: &StoreGetProto<LiquidityGauge>,
+    child_gauge_emission_events: &mut Vec<ChildGaugeEmissionEvent>,
+) {
+    // Only CRV bridged from mainnet or released by the factory funds emissions. Anyone else can
+    // transfer CRV into a gauge, e.g. when depositing it as a reward token.
+    let is_emission_sender = transfer.sender == child_gauge_factory
+        || CHILD_GAUGE_BRIDGER_ADDRESS.map_or(false, |bridger| transfer.sender == bridger);
+    if !is_emission_sender {
+        return;
+    }
+    let gauge_address = Hex::encode(&transfer.receiver);
+    if let Some(gauge) = gauge_store.get_last(StoreKey::liquidity_gauge_key(&gauge_address)) {
+        child_gauge_emission_events.push(ChildGaugeEmissionEvent {
+            gauge: gauge.gauge,
+            pool: gauge.pool,
+            amount: transfer.value.to_string(),
+            transaction_hash: Hex::encode(&trx.hash),
+            tx_index: trx.index,
+            log_index: log.index,
+            log_ordinal: log.ordinal,
+            timestamp: blk.timestamp_seconds(),
+            block_number: blk.number,
         });
     }
 }
@@ -148,21 +410,21 @@ fn handle_add_reward_event(
     gauge_address: &Vec<u8>,
     reward_token: &Vec<u8>,
     distributor: &Vec<u8>,
+    ordinal: u64,
     seen_tx_hashes: &mut HashSet<String>,
     unique_add_reward_events: &mut Vec<AddRewardEvent>,
     gauge_store: &StoreGetProto<LiquidityGauge>,
 ) {
-    // Shadowing as don't need the raw address after this call
-    let gauge_address = StoreKey::liquidity_gauge_key(&Hex::encode(gauge_address));
+    let gauge_key = StoreKey::liquidity_gauge_key(&Hex::encode(gauge_address));
 
     // Check if the gauge related to the add_reward call exists in the gauge store
-    if let Some(gauge) = gauge_store.get_last(&gauge_address) {
+    if let Some(gauge) = gauge_store.get_last(&gauge_key) {
         let tx_hash_str = Hex::encode(&trx.hash);
 
         if seen_tx_hashes.insert(tx_hash_str.clone()) {
             // If the transaction hash was successfully inserted (i.e., it's a new unique hash), add the event.
             unique_add_reward_events.push(AddRewardEvent {
-                gauge: Hex::encode(&gauge_address),
+                gauge: gauge.gauge,
                 pool: gauge.pool,
                 reward_token: Hex::encode(&reward_token),
                 distributor: Hex::encode(&distributor),
@@ -170,11 +432,178 @@ fn handle_add_reward_event(
                 tx_index: trx.index,
                 timestamp: blk.timestamp_seconds(),
                 block_number: blk.number,
+                ordinal,
             })
         }
     }
 }
 
+// Returns the user whose rewards are being claimed if the call is to one of the `claim_rewards` functions,
+// or to a `deposit`/`withdraw` function with `_claim_rewards` set.
+// Although there are multiple ABIs for different version of `LiquidityGauge`, these functions
+// remain the same from V2 onwards. Therefore we only need to match for one of these.
+fn decode_claim_rewards_user(call: &eth::Call) -> Option<Vec<u8>> {
+    if gauges::liquidity_gauge_v6::functions::ClaimRewards1::match_call(call) {
+        return Some(call.caller.clone());
+    }
+    if let Some(claim) =
+        gauges::liquidity_gauge_v6::functions::ClaimRewards2::match_and_decode(call)
+    {
+        return Some(claim.u_addr);
+    }
+    if let Some(claim) =
+        gauges::liquidity_gauge_v6::functions::ClaimRewards3::match_and_decode(call)
+    {
+        return Some(claim.u_addr);
+    }
+    if let Some(deposit) = gauges::liquidity_gauge_v6::functions::Deposit3::match_and_decode(call) {
+        return deposit.u_claim_rewards.then(|| deposit.u_addr);
+    }
+    gauges::liquidity_gauge_v6::functions::Withdraw2::match_and_decode(call)
+        .filter(|withdraw| withdraw.u_claim_rewards)
+        .map(|_| call.caller.clone())
+}
+
+fn handle_reward_claim_call(
+    trx: &TransactionTrace,
+    blk: &eth::Block,
+    call: &eth::Call,
+    user: &Vec<u8>,
+    gauge_store: &StoreGetProto<LiquidityGauge>,
+    reward_claim_events: &mut Vec<RewardClaimEvent>,
+) {
+    let gauge_address = Hex::encode(&call.address);
+    if let Some(gauge) = gauge_store.get_last(StoreKey::liquidity_gauge_key(&gauge_address)) {
+        // Withdrawals also transfer the staked LP tokens out of the gauge, which are not rewards.
+        let lp_token = if gauges::liquidity_gauge_v6::functions::Withdraw2::match_call(call) {
+            match &gauge.token {
+                Some(token) => Hex::decode(token).ok(),
+                None => rpc::gauge::get_gauge_lp_token(&call.address),
+            }
+        } else {
+            None
+        };
+        // Claimed rewards are transferred out of the gauge within the scope of the call. The token
+        // contract emitting each transfer identifies the reward token being claimed.
+        let claim_transfers = trx
+            .calls
+            .iter()
+            .filter(|nested_call| !nested_call.state_reverted)
+            .flat_map(|nested_call| nested_call.logs.iter())
+            .filter(|log| log.ordinal >= call.begin_ordinal && log.ordinal <= call.end_ordinal)
+            .filter(|log| lp_token.as_ref() != Some(&log.address))
+            .filter_map(|log| {
+                erc20::events::Transfer::match_and_decode(log)
+                    .filter(|transfer| transfer.sender == call.address)
+                    .map(|transfer| (log, transfer))
+            });
+
+        for (log, transfer) in claim_transfers {
+            reward_claim_events.push(RewardClaimEvent {
+                gauge: gauge_address.clone(),
+                pool: gauge.pool.clone(),
+                user: Hex::encode(user),
+                receiver: Hex::encode(&transfer.receiver),
+                reward_token: Hex::encode(&log.address),
+                amount: transfer.value.to_string(),
+                transaction_hash: Hex::encode(&trx.hash),
+                tx_index: trx.index,
+                log_index: log.index,
+                log_ordinal: log.ordinal,
+                timestamp: blk.timestamp_seconds(),
+                block_number: blk.number,
+            });
+        }
+    }
+}
+
+fn handle_set_killed_call(
+    trx: &TransactionTrace,
+    blk: &eth::Block,
+    call: &eth::Call,
+    is_killed: bool,
+    gauge_store: &StoreGetProto<LiquidityGauge>,
+    kill_status_events: &mut Vec<GaugeKillStatusEvent>,
+) {
+    let gauge_address = Hex::encode(&call.address);
+    if let Some(gauge) = gauge_store.get_last(StoreKey::liquidity_gauge_key(&gauge_address)) {
+        kill_status_events.push(GaugeKillStatusEvent {
+            gauge: gauge_address,
+            pool: gauge.pool,
+            is_killed,
+            transaction_hash: Hex::encode(&trx.hash),
+            tx_index: trx.index,
+            ordinal: call.end_ordinal,
+            timestamp: blk.timestamp_seconds(),
+            block_number: blk.number,
+        });
+    }
+}
+
+fn handle_set_reward_distributor_call(
+    trx: &TransactionTrace,
+    blk: &eth::Block,
+    call: &eth::Call,
+    reward_token: &Vec<u8>,
+    distributor: &Vec<u8>,
+    gauge_store: &StoreGetProto<LiquidityGauge>,
+    set_reward_distributor_events: &mut Vec<SetRewardDistributorEvent>,
+) {
+    let gauge_address = Hex::encode(&call.address);
+    if let Some(gauge) = gauge_store.get_last(StoreKey::liquidity_gauge_key(&gauge_address)) {
+        set_reward_distributor_events.push(SetRewardDistributorEvent {
+            gauge: gauge_address,
+            pool: gauge.pool,
+            reward_token: Hex::encode(reward_token),
+            distributor: Hex::encode(distributor),
+            transaction_hash: Hex::encode(&trx.hash),
+            tx_index: trx.index,
+            ordinal: call.begin_ordinal,
+            timestamp: blk.timestamp_seconds(),
+            block_number: blk.number,
+        });
+    }
+}
+
+// Returns the reward token and amount if the call is to one of the `deposit_reward_token` functions.
+// V4 and V5 gauges share the same ABI as the first V6 function, while the second V6 function also
+// takes the duration of the reward period.
+fn decode_deposit_reward_token(call: &eth::Call) -> Option<(Vec<u8>, BigInt)> {
+    if let Some(deposit) =
+        gauges::liquidity_gauge_v6::functions::DepositRewardToken1::match_and_decode(call)
+    {
+        return Some((deposit.u_reward_token, deposit.u_amount));
+    }
+    gauges::liquidity_gauge_v6::functions::DepositRewardToken2::match_and_decode(call)
+        .map(|deposit| (deposit.u_reward_token, deposit.u_amount))
+}
+
+fn handle_deposit_reward_token_call(
+    trx: &TransactionTrace,
+    blk: &eth::Block,
+    call: &eth::Call,
+    reward_token: &Vec<u8>,
+    amount: &BigInt,
+    gauge_store: &StoreGetProto<LiquidityGauge>,
+    deposit_reward_token_events: &mut Vec<DepositRewardTokenEvent>,
+) {
+    let gauge_address = Hex::encode(&call.address);
+    if let Some(gauge) = gauge_store.get_last(StoreKey::liquidity_gauge_key(&gauge_address)) {
+        deposit_reward_token_events.push(DepositRewardTokenEvent {
+            gauge: gauge_address,
+            pool: gauge.pool,
+            reward_token: Hex::encode(reward_token),
+            distributor: Hex::encode(&call.caller),
+            amount: amount.to_string(),
+            transaction_hash: Hex::encode(&trx.hash),
+            tx_index: trx.index,
+            ordinal: call.end_ordinal,
+            timestamp: blk.timestamp_seconds(),
+            block_number: blk.number,
+        });
+    }
+}
+
 pub fn extract_gauge_working_supply(trx: &TransactionTrace, gauge: &Vec<u8>) -> Option<BigInt> {
     trx.calls
         .iter()
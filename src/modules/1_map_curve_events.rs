@@ -1,5 +1,9 @@
 use anyhow::anyhow;
-use substreams::{errors::Error, Hex};
+use substreams::{
+    errors::Error,
+    store::{StoreGet, StoreGetString},
+    Hex,
+};
 use substreams_ethereum::{block_view, pb::eth::v2 as eth, NULL_ADDRESS};
 
 use crate::{
@@ -8,35 +12,52 @@ use crate::{
             crv_usd_pool_factory, crypto_pool_factory_v2, pool_registry_v1, stable_swap_factory_ng,
             tricrypto_factory_ng, twocrypto_factory,
         },
-        crv_token, gauge_controller,
+        crv_token, crvusd, gauge_controller,
+        gauges::{child_gauge_factory, root_gauge_factory},
+        lending,
     },
-    common::event_extraction,
+    common::{event_extraction, utils},
+    key_management::store_key_manager::StoreKey,
     network_config::{
-        PoolDetails, PoolType as PoolTypeConfig, CRV_TOKEN_ADDRESS, GAUGE_CONTROLLER_ADDRESS,
-        MISSING_OLD_POOLS_DATA, REGISTRIES,
+        PoolDetails, PoolType as PoolTypeConfig, CHILD_GAUGE_FACTORY_ADDRESS,
+        CRVUSD_CONTROLLER_FACTORY_ADDRESS, CRVUSD_TOKEN_ADDRESS, CRV_TOKEN_ADDRESS,
+        GAUGE_CONTROLLER_ADDRESS, MISSING_OLD_POOLS_DATA, ONE_WAY_LENDING_FACTORY_ADDRESS,
+        REGISTRIES, ROOT_GAUGE_FACTORY_ADDRESS,
     },
     pb::curve::types::v1::{
-        pool::PoolType, ControllerNewGauge, CryptoPool, CurveEvents, LendingPool, LiquidityGauge,
-        MetaPool, PlainPool, Pool, Token, TriCryptoPool, TwoCryptoPool,
-        UpdateMiningParametersEvent,
+        pool::PoolType, AssetType, ControllerNewGauge, CrvUsdMarket, CurveEvents, LendingMarket,
+        LendingPool, LiquidityGauge, MetaPool, PlainPool, Pool, RootGauge, Token, TwoCryptoPool,
+        UpdateMiningParametersEvent, WildcardPool,
     },
     rpc::{self, pool, token},
-    types::{event_traits::PlainPoolDeployedEvent, registry::RegistryDetails},
+    types::{
+        event_traits::PlainPoolDeployedEvent,
+        registry::{RegistryDetails, RegistryType},
+    },
 };
 
 #[substreams::handlers::map]
-pub fn map_curve_events(blk: eth::Block) -> Result<CurveEvents, Vec<Error>> {
+pub fn map_curve_events(
+    blk: eth::Block,
+    base_pools_store: StoreGetString,
+) -> Result<CurveEvents, Vec<Error>> {
     let mut curve_events = CurveEvents::default();
     let mut pools: Vec<Pool> = Vec::new();
     // Liquidity Gauges deployed via registry/factories
     let mut gauges: Vec<LiquidityGauge> = Vec::new();
     // Liquidity Gauges that have been previously deployed, and now added to the GaugeController contract
     let mut controller_gauges: Vec<ControllerNewGauge> = Vec::new();
+    // Root gauges deployed on mainnet, which bridge CRV emissions to child gauges on sidechains
+    let mut root_gauges: Vec<RootGauge> = Vec::new();
+    // crvUSD markets deployed from the ControllerFactory
+    let mut crvusd_markets: Vec<CrvUsdMarket> = Vec::new();
+    // LlamaLend markets deployed from the OneWayLendingFactory
+    let mut lending_markets: Vec<LendingMarket> = Vec::new();
 
     // Need to add pools that were deployed before any registry/factory contracts handled pool deployment
     for &(_pool_address, ref pool_details) in MISSING_OLD_POOLS_DATA.iter() {
         if pool_details.start_block == blk.number {
-            match add_missing_pool(&blk, &mut pools, pool_details) {
+            match add_missing_pool(&blk, &mut pools, pool_details, &base_pools_store) {
                 Ok(_) => {}
                 Err(e) => {
                     return Err(vec![e]);
@@ -65,7 +86,7 @@ pub fn map_curve_events(blk: eth::Block) -> Result<CurveEvents, Vec<Error>> {
                 map_plain_pool_deployed_events::<stable_swap_factory_ng::events::PlainPoolDeployed>(
                     &blk, &mut pools, registry,
                 ),
-                map_meta_pool_deployed_events(&blk, &mut pools, registry),
+                map_meta_pool_deployed_events(&blk, &mut pools, registry, &base_pools_store),
                 map_tricrypto_pool_deployed_events(&blk, &mut pools, registry),
                 map_twocrypto_pool_deployed_events(&blk, &mut pools, registry),
                 // Track liquidity gauges that have been deployed from registry/factory contracts
@@ -76,13 +97,32 @@ pub fn map_curve_events(blk: eth::Block) -> Result<CurveEvents, Vec<Error>> {
         .filter_map(Result::err)
         .collect();
 
-    // Extracts NewGauge events from the GaugeController contract
-    match map_controller_new_gauge_events(&blk, &mut controller_gauges, GAUGE_CONTROLLER_ADDRESS) {
-        // As nothing is returned with the `Ok` variant, we can just ignore it,
-        // and use the `Err` variant to collect any error that occurs.
-        Err(e) => errors.push(e),
-        _ => {}
-    };
+    // Extracts NewGauge events from the GaugeController contract, which only exists on mainnet
+    if let Some(address) = GAUGE_CONTROLLER_ADDRESS {
+        if let Err(e) = map_controller_new_gauge_events(&blk, &mut controller_gauges, address) {
+            errors.push(e);
+        }
+    }
+
+    // Sidechain gauges are deployed from a RootGaugeFactory on mainnet, and a ChildGaugeFactory on the
+    // sidechain itself. Child gauges behave like any other gauge, and are tracked alongside them.
+    if let Some(address) = ROOT_GAUGE_FACTORY_ADDRESS {
+        map_root_gauge_deployed_events(&blk, &mut root_gauges, address);
+    }
+    if let Some(address) = CHILD_GAUGE_FACTORY_ADDRESS {
+        map_child_gauge_deployed_events(&blk, &mut gauges, address);
+    }
+
+    if let Some(address) = CRVUSD_CONTROLLER_FACTORY_ADDRESS {
+        if let Err(e) = map_crvusd_market_added_events(&blk, &mut crvusd_markets, address) {
+            errors.push(e);
+        }
+    }
+    if let Some(address) = ONE_WAY_LENDING_FACTORY_ADDRESS {
+        if let Err(e) = map_lending_vault_deployed_events(&blk, &mut lending_markets, address) {
+            errors.push(e);
+        }
+    }
 
     let crv_mining_update_event = map_crv_mining_update_events(&blk, CRV_TOKEN_ADDRESS);
 
@@ -97,9 +137,35 @@ pub fn map_curve_events(blk: eth::Block) -> Result<CurveEvents, Vec<Error>> {
         _ => {}
     }
 
+    // StableSwap-NG pools can hold rate oracle, rebasing and ERC4626 tokens, which are valued differently.
+    for pool in pools.iter_mut() {
+        if let Some(registry) = REGISTRIES.iter().find(|registry| {
+            matches!(registry.registry_type, RegistryType::StableSwapFactoryNG)
+                && Hex::encode(registry.address) == pool.registry_address
+        }) {
+            set_pool_asset_types(pool, &registry.address.to_vec());
+        }
+    }
+
+    // Flag any pool tokens that are LP tokens of base pools registered via `BasePoolAdded` events.
+    for pool in pools.iter_mut() {
+        for token in pool
+            .output_token
+            .iter_mut()
+            .chain(pool.input_tokens.iter_mut())
+        {
+            if utils::is_registered_base_pool_lp_token(&token.address, &base_pools_store) {
+                token.is_base_pool_lp_token = true;
+            }
+        }
+    }
+
     curve_events.pools = pools;
     curve_events.gauges = gauges;
     curve_events.controller_gauges = controller_gauges;
+    curve_events.root_gauges = root_gauges;
+    curve_events.crvusd_markets = crvusd_markets;
+    curve_events.lending_markets = lending_markets;
 
     // Sort by log ordinal to maintain determinism when handling these messages downstream
     curve_events
@@ -111,6 +177,15 @@ pub fn map_curve_events(blk: eth::Block) -> Result<CurveEvents, Vec<Error>> {
     curve_events
         .controller_gauges
         .sort_by(|a, b| a.log_ordinal.cmp(&b.log_ordinal));
+    curve_events
+        .root_gauges
+        .sort_by(|a, b| a.log_ordinal.cmp(&b.log_ordinal));
+    curve_events
+        .crvusd_markets
+        .sort_by(|a, b| a.log_ordinal.cmp(&b.log_ordinal));
+    curve_events
+        .lending_markets
+        .sort_by(|a, b| a.log_ordinal.cmp(&b.log_ordinal));
 
     if errors.is_empty() {
         return Ok(curve_events);
@@ -118,10 +193,26 @@ pub fn map_curve_events(blk: eth::Block) -> Result<CurveEvents, Vec<Error>> {
     Err(errors)
 }
 
+fn set_pool_asset_types(pool: &mut Pool, factory_address: &Vec<u8>) {
+    let asset_types = rpc::registry::get_ng_pool_asset_types(&pool.address_vec(), factory_address);
+    for token in pool.input_tokens.iter_mut() {
+        if let Some(asset_type) = token
+            .index
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| asset_types.get(index))
+            .and_then(|asset_type| AssetType::from_i32(asset_type.to_i32()))
+        {
+            token.set_asset_type(asset_type);
+        }
+    }
+}
+
 fn add_missing_pool(
     blk: &eth::Block,
     pools: &mut Vec<Pool>,
     pool: &PoolDetails,
+    base_pools_store: &StoreGetString,
 ) -> Result<(), Error> {
     let pool_address = pool.address.to_vec();
     let lp_token = match token::create_token(
@@ -161,6 +252,7 @@ fn add_missing_pool(
             ));
         }
         PoolTypeConfig::Crypto => {
+            let crypto_pool = pool::get_crypto_pool(&pool_address);
             pools.push(create_missing_pool(
                 Hex::encode(pool_address),
                 Hex::encode(NULL_ADDRESS.to_vec()),
@@ -169,10 +261,12 @@ fn add_missing_pool(
                 input_tokens,
                 blk,
                 hash,
-                PoolType::PlainPool(PlainPool {}),
+                PoolType::CryptoPool(crypto_pool),
             ));
         }
         PoolTypeConfig::TriCrypto => {
+            let tricrypto_pool =
+                pool::get_tricrypto_pool(&pool_address, input_tokens_ordered.len());
             pools.push(create_missing_pool(
                 Hex::encode(pool_address),
                 Hex::encode(NULL_ADDRESS.to_vec()),
@@ -181,7 +275,19 @@ fn add_missing_pool(
                 input_tokens,
                 blk,
                 hash,
-                PoolType::PlainPool(PlainPool {}),
+                PoolType::TricryptoPool(tricrypto_pool),
+            ));
+        }
+        PoolTypeConfig::Wildcard => {
+            pools.push(create_missing_pool(
+                Hex::encode(pool_address),
+                Hex::encode(NULL_ADDRESS.to_vec()),
+                lp_token,
+                input_tokens_ordered,
+                input_tokens,
+                blk,
+                hash,
+                PoolType::WildcardPool(WildcardPool {}),
             ));
         }
         PoolTypeConfig::Lending => {
@@ -204,7 +310,10 @@ fn add_missing_pool(
             }
         }
         PoolTypeConfig::Meta => {
-            if let Some(base_pool) = pool::get_old_metapool_base_pool(&pool.address.to_vec()) {
+            let base_pool = pool::get_old_metapool_base_pool(&pool.address.to_vec())
+                .map(Hex::encode)
+                .or_else(|| get_registered_base_pool(&input_tokens_ordered, base_pools_store));
+            if let Some(base_pool) = base_pool {
                 if let Ok(underlying_coins) =
                     pool::get_old_metapool_underlying_coins(&pool.address.to_vec())
                 {
@@ -217,7 +326,7 @@ fn add_missing_pool(
                         blk,
                         hash,
                         PoolType::MetaPool(MetaPool {
-                            base_pool_address: Hex::encode(base_pool),
+                            base_pool_address: base_pool,
                             underlying_tokens: underlying_coins,
                             max_coin: 1,
                         }),
@@ -225,7 +334,12 @@ fn add_missing_pool(
                 }
             }
         }
-        _ => {}
+        PoolTypeConfig::Unknown => {
+            substreams::log::debug!(
+                "Skipping missing pool {} with an unknown pool type",
+                Hex::encode(&pool.address)
+            );
+        }
     }
     Ok(())
 }
@@ -273,6 +387,7 @@ fn map_crypto_pool_deployed_events(
                         }
                     };
                 substreams::log::debug!("Adding a CryptoPool");
+                let crypto_pool = pool::get_crypto_pool(&pool_address);
 
                 Some(create_pool(
                     Hex::encode(&pool_address),
@@ -282,7 +397,7 @@ fn map_crypto_pool_deployed_events(
                     input_tokens,
                     &log,
                     blk,
-                    PoolType::CryptoPool(CryptoPool {}),
+                    PoolType::CryptoPool(crypto_pool),
                 ))
             })
             .collect(),
@@ -361,6 +476,7 @@ fn map_meta_pool_deployed_events(
     blk: &eth::Block,
     pools: &mut Vec<Pool>,
     registry: &RegistryDetails,
+    base_pools_store: &StoreGetString,
 ) -> Result<(), Error> {
     pools.append(
         &mut blk
@@ -413,9 +529,21 @@ fn map_meta_pool_deployed_events(
                             return None;
                         }
                     };
-                if let Ok(underlying_coins) = rpc::pool::get_pool_coins(&event.base_pool) {
+                // Fall back to the base pool registered for the metapool's base LP token when the
+                // base pool from the event cannot be queried for its coins.
+                let base_pool = rpc::pool::get_pool_coins(&event.base_pool)
+                    .map(|coins| (Hex::encode(&event.base_pool), coins))
+                    .ok()
+                    .or_else(|| {
+                        let base_pool =
+                            get_registered_base_pool(&input_tokens_ordered, base_pools_store)?;
+                        let coins =
+                            rpc::pool::get_pool_coins(&Hex::decode(&base_pool).ok()?).ok()?;
+                        Some((base_pool, coins))
+                    });
+                if let Some((base_pool_address, underlying_coins)) = base_pool {
                     let pool_type = PoolType::MetaPool(MetaPool {
-                        base_pool_address: Hex::encode(event.base_pool),
+                        base_pool_address,
                         underlying_tokens: underlying_coins,
                         max_coin: 1,
                     });
@@ -473,6 +601,8 @@ fn map_tricrypto_pool_deployed_events(
                         }
                     };
                 substreams::log::debug!("Added TricryptoPool");
+                let tricrypto_pool =
+                    pool::get_tricrypto_pool(&event.pool, input_tokens_ordered.len());
 
                 Some(create_pool(
                     Hex::encode(&event.pool),
@@ -482,7 +612,7 @@ fn map_tricrypto_pool_deployed_events(
                     input_tokens,
                     &log,
                     blk,
-                    PoolType::TricryptoPool(TriCryptoPool {}),
+                    PoolType::TricryptoPool(tricrypto_pool),
                 ))
             })
             .collect(),
@@ -589,6 +719,121 @@ fn map_liquidity_gauge_deployed_with_token_events(
     Ok(())
 }
 
+fn map_root_gauge_deployed_events(
+    blk: &eth::Block,
+    root_gauges: &mut Vec<RootGauge>,
+    address: [u8; 20],
+) {
+    root_gauges.append(
+        &mut blk
+            .events::<root_gauge_factory::events::DeployedGauge>(&[&address])
+            .filter_map(|(event, log)| {
+                Some(RootGauge {
+                    gauge: Hex::encode(event.u_gauge),
+                    chain_id: event.u_chain_id.to_u64(),
+                    deployer: Hex::encode(event.u_deployer),
+                    created_at_timestamp: blk.timestamp_seconds(),
+                    created_at_block_number: blk.number,
+                    log_ordinal: log.ordinal(),
+                })
+            })
+            .collect(),
+    );
+}
+
+fn map_child_gauge_deployed_events(
+    blk: &eth::Block,
+    gauges: &mut Vec<LiquidityGauge>,
+    address: [u8; 20],
+) {
+    gauges.append(
+        &mut blk
+            .events::<child_gauge_factory::events::DeployedGauge>(&[&address])
+            .filter_map(|(event, log)| {
+                // The factory only emits the LP token. Older pools have a separate LP token that
+                // references its pool as the minter, whereas newer pools are their own LP token.
+                let pool = token::get_token_minter(&event.u_lp_token)
+                    .unwrap_or_else(|_| event.u_lp_token.clone());
+                Some(LiquidityGauge {
+                    gauge: Hex::encode(event.u_gauge),
+                    pool: Hex::encode(pool),
+                    token: Some(Hex::encode(event.u_lp_token)),
+                    created_at_timestamp: blk.timestamp_seconds(),
+                    created_at_block_number: blk.number,
+                    log_ordinal: log.ordinal(),
+                })
+            })
+            .collect(),
+    );
+}
+
+fn map_crvusd_market_added_events(
+    blk: &eth::Block,
+    crvusd_markets: &mut Vec<CrvUsdMarket>,
+    address: [u8; 20],
+) -> Result<(), Error> {
+    for (event, log) in blk.events::<crvusd::controller_factory::events::AddMarket>(&[&address]) {
+        let stablecoin = CRVUSD_TOKEN_ADDRESS.ok_or_else(|| {
+            anyhow!("`crvUsdTokenAddress` is missing from the network configuration")
+        })?;
+        let collateral_token =
+            match token::create_token("1".to_string(), &event.collateral, &event.amm, None) {
+                Ok(token) => token,
+                Err(e) => {
+                    return Err(anyhow!(
+                        "Error in `map_crvusd_market_added_events`: {:?}",
+                        e
+                    ))
+                }
+            };
+        crvusd_markets.push(CrvUsdMarket {
+            controller: Hex::encode(&event.controller),
+            amm: Hex::encode(&event.amm),
+            monetary_policy: Hex::encode(&event.monetary_policy),
+            // crvUSD is always coin 0 of the LLAMMA, and the collateral coin 1.
+            stablecoin: Hex::encode(stablecoin),
+            collateral_token: Some(collateral_token),
+            index: event.ix.to_u64(),
+            created_at_timestamp: blk.timestamp_seconds(),
+            created_at_block_number: blk.number,
+            log_ordinal: log.ordinal(),
+        });
+    }
+    Ok(())
+}
+
+fn map_lending_vault_deployed_events(
+    blk: &eth::Block,
+    lending_markets: &mut Vec<LendingMarket>,
+    address: [u8; 20],
+) -> Result<(), Error> {
+    for (event, log) in
+        blk.events::<lending::one_way_lending_factory::events::NewVault>(&[&address])
+    {
+        // The borrowed token is always coin 0 of the LLAMMA, and the collateral coin 1.
+        let borrowed_token =
+            token::create_token("0".to_string(), &event.borrowed_token, &event.amm, None)
+                .map_err(|e| anyhow!("Error in `map_lending_vault_deployed_events`: {:?}", e))?;
+        let collateral_token =
+            token::create_token("1".to_string(), &event.collateral_token, &event.amm, None)
+                .map_err(|e| anyhow!("Error in `map_lending_vault_deployed_events`: {:?}", e))?;
+        lending_markets.push(LendingMarket {
+            vault: Hex::encode(&event.vault),
+            controller: Hex::encode(&event.controller),
+            amm: Hex::encode(&event.amm),
+            price_oracle: Hex::encode(&event.price_oracle),
+            monetary_policy: Hex::encode(&event.monetary_policy),
+            borrowed_token: Some(borrowed_token),
+            collateral_token: Some(collateral_token),
+            index: event.id.to_u64(),
+            created_at_timestamp: blk.timestamp_seconds(),
+            created_at_block_number: blk.number,
+            log_ordinal: log.ordinal(),
+        });
+    }
+    Ok(())
+}
+
 fn map_controller_new_gauge_events(
     blk: &eth::Block,
     controller_gauges: &mut Vec<ControllerNewGauge>,
@@ -685,6 +930,16 @@ fn create_missing_pool(
 
 // This follows the logic from the original subgraph.
 // An array of token addresses, and a sorted array of token structs is required.
+// Returns the base pool registered for a metapool's base LP token, which is the last of its coins.
+fn get_registered_base_pool(
+    input_tokens_ordered: &Vec<String>,
+    base_pools_store: &StoreGetString,
+) -> Option<String> {
+    input_tokens_ordered
+        .last()
+        .and_then(|token| base_pools_store.get_last(StoreKey::base_pool_lp_token_key(token)))
+}
+
 fn get_and_sort_input_tokens(pool_address: &Vec<u8>) -> Result<(Vec<Token>, Vec<String>), Error> {
     let mut input_tokens = pool::get_pool_coins(&pool_address)?;
     let input_tokens_ordered = input_tokens
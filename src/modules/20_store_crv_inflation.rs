@@ -1,13 +1,36 @@
-use substreams::store::{StoreNew, StoreSet, StoreSetString};
+use substreams::{
+    pb::substreams::Clock,
+    store::{DeltaInt64, Deltas, StoreNew, StoreSet, StoreSetString},
+};
 
-use crate::{key_management::store_key_manager::StoreKey, pb::curve::types::v1::CurveEvents};
+use crate::{
+    common::crv_inflation, key_management::store_key_manager::StoreKey,
+    pb::curve::types::v1::CurveEvents, timeframe_management::utils::is_new_day,
+};
 
-// This module, is responsible for marking liquidity gauges that have been added to the GaugeController
-// contract. By setting a value in the store for each gauge added, it effectively acts as a boolean
-// indicator of whether a gauge is eligible for CRV rewards.
+// This module keeps track of the current CRV inflation rate. The rate is taken from the
+// `UpdateMiningParameters` events emitted by the CRV token. As these events are only emitted once a
+// year, the rate is also refreshed from the emission schedule at the start of each day, so it is
+// available when starting from a later block and stays correct if the event has not been emitted yet.
 #[substreams::handlers::store]
-pub fn store_crv_inflation(events: CurveEvents, store: StoreSetString) {
+pub fn store_crv_inflation(
+    events: CurveEvents,
+    current_time_deltas: Deltas<DeltaInt64>,
+    clock: Clock,
+    store: StoreSetString,
+) {
     if let Some(inflation_event) = events.update_mining_parameters_event {
-        store.set(0, StoreKey::crv_inflation_rate_key(), &inflation_event.rate);
+        store.set(
+            inflation_event.log_ordinal,
+            StoreKey::crv_inflation_rate_key(),
+            &inflation_event.rate,
+        );
+    } else if is_new_day(&current_time_deltas) {
+        let timestamp = clock.timestamp.unwrap().seconds;
+        store.set(
+            0,
+            StoreKey::crv_inflation_rate_key(),
+            &crv_inflation::get_crv_inflation_rate(timestamp).to_string(),
+        );
     }
 }
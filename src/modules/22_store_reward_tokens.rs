@@ -45,6 +45,7 @@ pub fn store_reward_tokens(
                                 total_supply: reward_token.total_supply.to_string(),
                                 is_base_pool_lp_token: reward_token.is_base_pool_lp_token,
                                 gauge: Some(gauge.gauge.to_string()),
+                                asset_type: reward_token.asset_type,
                             },
                         )
                     } else {
@@ -1,133 +1,290 @@
-use std::str::FromStr;
+use std::{collections::HashSet, str::FromStr};
 
 use substreams::{
     pb::substreams::Clock,
     scalar::{BigDecimal, BigInt},
     store::{
-        StoreGet, StoreGetBigDecimal, StoreGetInt64, StoreGetProto, StoreGetString, StoreNew,
-        StoreSet, StoreSetProto,
+        DeltaInt64, Deltas, StoreGet, StoreGetBigDecimal, StoreGetInt64, StoreGetProto,
+        StoreGetString, StoreNew, StoreSet, StoreSetProto,
     },
 };
 
 use crate::{
-    common::prices,
-    constants::{curve_token, default_decimals, SECONDS_PER_DAY},
+    common::{crv_inflation, prices},
+    constants::{
+        curve_token, default_decimals, DAYS_PER_YEAR, GAUGE_TOKENLESS_PRODUCTION, SECONDS_PER_DAY,
+    },
     key_management::store_key_manager::StoreKey,
     pb::{
-        curve::types::v1::{LiquidityGauge, LiquidityGaugeEvents, PoolRewards, Token},
+        curve::types::v1::{
+            CurveEvents, LiquidityGauge, LiquidityGaugeEvents, Pool, PoolRewards, Token,
+        },
         uniswap_pricing::v1::Erc20Price,
     },
     rpc,
+    timeframe_management::utils::is_new_day,
 };
 
 #[substreams::handlers::store]
 pub fn store_pool_rewards(
     gauge_events: LiquidityGaugeEvents,
+    curve_events: CurveEvents,
+    pools_store: StoreGetProto<Pool>,
     gauge_store: StoreGetProto<LiquidityGauge>,
     gauge_controller_store: StoreGetInt64,
     crv_inflation_store: StoreGetString,
     reward_token_count_store: StoreGetInt64,
     reward_tokens_store: StoreGetProto<Token>,
+    gauge_count_store: StoreGetInt64,
+    gauge_addresses_store: StoreGetString,
+    current_time_deltas: Deltas<DeltaInt64>,
     uniswap_prices: StoreGetProto<Erc20Price>,
     chainlink_prices: StoreGetBigDecimal,
     clock: Clock,
     output_store: StoreSetProto<PoolRewards>,
 ) {
-    for event in gauge_events.liquidity_events {
-        // String representations of the BigInt values for native emissions
-        let mut reward_token_emissions_native: Vec<String> = Vec::new();
-        // String representations of the BigDecimal values for USD emissions
-        let mut reward_token_emissions_usd: Vec<String> = Vec::new();
+    let timestamp = clock.timestamp.unwrap().seconds;
+    let reward_stores = RewardStores {
+        pools_store: &pools_store,
+        gauge_controller_store: &gauge_controller_store,
+        crv_inflation_store: &crv_inflation_store,
+        reward_token_count_store: &reward_token_count_store,
+        reward_tokens_store: &reward_tokens_store,
+        uniswap_prices: &uniswap_prices,
+        chainlink_prices: &chainlink_prices,
+    };
+
+    // Keep track of the pools updated by liquidity events, as their emissions are already up to date.
+    let mut updated_pools: HashSet<String> = HashSet::new();
 
+    for event in gauge_events.liquidity_events {
         if let Some(gauge) = gauge_store.get_last(StoreKey::liquidity_gauge_key(&event.gauge)) {
-            // Handle CRV Rewards - if it has been added to the `GaugeController`, it is eligible for CRV rewards
-            if let Some(_) =
-                gauge_controller_store.get_last(StoreKey::controller_gauge_added_key(&gauge.gauge))
-            {
-                if let Some(crv_inflation) =
-                    crv_inflation_store.get_last(StoreKey::crv_inflation_rate_key())
+            let working_supply =
+                BigInt::from_str(&event.working_supply).unwrap_or_else(|_| BigInt::zero());
+            let pool_rewards =
+                calculate_pool_rewards(&gauge, &reward_stores, &working_supply, timestamp);
+
+            if pool_rewards.reward_token_emissions_native.len() > 0 {
+                updated_pools.insert(event.pool.clone());
+                output_store.set(
+                    event.log_ordinal,
+                    StoreKey::pool_rewards_key(&event.pool),
+                    &pool_rewards,
+                )
+            }
+        }
+    }
+
+    // Emissions also change without any gauge activity, when a permissionless reward period ends or
+    // the CRV inflation rate is updated. In these cases, emissions are recalculated for all gauges.
+    if is_new_day(&current_time_deltas) || curve_events.update_mining_parameters_event.is_some() {
+        let gauge_count = gauge_count_store
+            .get_last(StoreKey::protocol_gauge_count_key())
+            .unwrap_or(0);
+
+        for index in 1..=gauge_count {
+            let gauge_opt = gauge_addresses_store
+                .get_last(StoreKey::gauge_address_key(&index))
+                .and_then(|gauge_address| {
+                    gauge_store.get_last(StoreKey::liquidity_gauge_key(&gauge_address))
+                });
+
+            if let Some(gauge) = gauge_opt {
+                // Gauges without any rewards are skipped before reading their working supply
+                if updated_pools.contains(&gauge.pool) || !has_reward_tokens(&gauge, &reward_stores)
                 {
-                    let gauge_rel_weight =
-                        rpc::gauge::get_gauge_relative_weight(&gauge.address_vec())
-                            .to_decimal(default_decimals());
-
-                    let crv_emissions_native = (BigDecimal::from_str(&crv_inflation)
-                        .unwrap_or_else(|_| BigDecimal::zero())
-                        * gauge_rel_weight
-                        * SECONDS_PER_DAY)
-                        .to_bigint();
-                    reward_token_emissions_native.push(crv_emissions_native.to_string());
-
-                    let (price_usd, _) = prices::get_token_usd_price(
-                        &curve_token(),
-                        &uniswap_prices,
-                        &chainlink_prices,
-                    );
-                    let crv_emissions_usd =
-                        crv_emissions_native.to_decimal(default_decimals()) * price_usd;
-                    reward_token_emissions_usd.push(crv_emissions_usd.to_string());
+                    continue;
                 }
+
+                let working_supply = rpc::gauge::get_gauge_working_supply(&gauge.address_vec());
+                let pool_rewards =
+                    calculate_pool_rewards(&gauge, &reward_stores, &working_supply, timestamp);
+
+                if pool_rewards.reward_token_emissions_native.len() > 0 {
+                    output_store.set(0, StoreKey::pool_rewards_key(&gauge.pool), &pool_rewards)
+                }
+            }
+        }
+    }
+}
+
+struct RewardStores<'a> {
+    pools_store: &'a StoreGetProto<Pool>,
+    gauge_controller_store: &'a StoreGetInt64,
+    crv_inflation_store: &'a StoreGetString,
+    reward_token_count_store: &'a StoreGetInt64,
+    reward_tokens_store: &'a StoreGetProto<Token>,
+    uniswap_prices: &'a StoreGetProto<Erc20Price>,
+    chainlink_prices: &'a StoreGetBigDecimal,
+}
+
+// A gauge has rewards once it is added to the GaugeController, or a permissionless reward token is
+// added to it.
+fn has_reward_tokens(gauge: &LiquidityGauge, stores: &RewardStores) -> bool {
+    stores
+        .gauge_controller_store
+        .get_last(StoreKey::controller_gauge_added_key(&gauge.gauge))
+        .is_some()
+        || stores
+            .reward_token_count_store
+            .get_last(StoreKey::liquidity_gauge_reward_token_count_key(
+                &gauge.gauge,
+            ))
+            .unwrap_or(0)
+            > 0
+}
+
+// Returns the rewards of a gauge: the daily emissions of each reward token, as native amounts and
+// USD values, along with the base and max boosted APR they yield to liquidity providers.
+fn calculate_pool_rewards(
+    gauge: &LiquidityGauge,
+    stores: &RewardStores,
+    working_supply: &BigInt,
+    timestamp: i64,
+) -> PoolRewards {
+    let mut reward_tokens: Vec<String> = Vec::new();
+    // String representations of the BigInt values for native emissions
+    let mut reward_token_emissions_native: Vec<String> = Vec::new();
+    // String representations of the BigDecimal values for USD emissions
+    let mut reward_token_emissions_usd: Vec<String> = Vec::new();
+    // String representations of the BigDecimal values for APRs
+    let mut reward_token_base_aprs: Vec<String> = Vec::new();
+    let mut reward_token_max_boosted_aprs: Vec<String> = Vec::new();
+
+    let RewardStores {
+        pools_store,
+        gauge_controller_store,
+        crv_inflation_store,
+        reward_token_count_store,
+        reward_tokens_store,
+        uniswap_prices,
+        chainlink_prices,
+    } = stores;
+
+    // The value of the gauge deposits is based on the price of the pool's LP token
+    let (lp_token_price, lp_token_decimals) =
+        match pools_store.get_last(StoreKey::pool_key(&gauge.pool)) {
+            Some(pool) => {
+                let (price_usd, _) = prices::get_token_usd_price(
+                    pool.output_token_ref(),
+                    uniswap_prices,
+                    chainlink_prices,
+                );
+                (price_usd, pool.output_token_ref().decimals)
             }
+            None => (BigDecimal::zero(), default_decimals()),
+        };
+
+    // Handle CRV Rewards - if it has been added to the `GaugeController`, it is eligible for CRV rewards
+    if let Some(_) =
+        gauge_controller_store.get_last(StoreKey::controller_gauge_added_key(&gauge.gauge))
+    {
+        // Fall back to the emission schedule until the inflation rate store has been populated
+        let crv_inflation = crv_inflation_store
+            .get_last(StoreKey::crv_inflation_rate_key())
+            .unwrap_or_else(|| crv_inflation::calculate_crv_inflation_rate(timestamp).to_string());
+
+        let gauge_rel_weight = rpc::gauge::get_gauge_relative_weight(&gauge.address_vec())
+            .to_decimal(default_decimals());
+
+        let crv_emissions_native = (BigDecimal::from_str(&crv_inflation)
+            .unwrap_or_else(|_| BigDecimal::zero())
+            * gauge_rel_weight
+            * SECONDS_PER_DAY)
+            .to_bigint();
+        reward_token_emissions_native.push(crv_emissions_native.to_string());
+
+        let (price_usd, _) =
+            prices::get_token_usd_price(&curve_token(), uniswap_prices, chainlink_prices);
+        let crv_emissions_usd = crv_emissions_native.to_decimal(default_decimals()) * price_usd;
+        reward_token_emissions_usd.push(crv_emissions_usd.to_string());
+
+        // CRV is distributed pro rata to working balances. A deposit without any boost only counts
+        // for `GAUGE_TOKENLESS_PRODUCTION` percent of its balance, while a max boosted deposit counts
+        // for its full balance.
+        let working_supply_usd =
+            working_supply.to_decimal(lp_token_decimals) * lp_token_price.clone();
+        let max_boosted_apr = calculate_apr(&crv_emissions_usd, &working_supply_usd);
+        let base_apr = max_boosted_apr.clone() * BigDecimal::from(GAUGE_TOKENLESS_PRODUCTION)
+            / BigDecimal::from(100);
+        reward_token_base_aprs.push(base_apr.to_string());
+        reward_token_max_boosted_aprs.push(max_boosted_apr.to_string());
+        reward_tokens.push(curve_token().address);
+    }
+
+    // Handle Permissionless Rewards
+    if let Some(count) = reward_token_count_store.get_last(
+        StoreKey::liquidity_gauge_reward_token_count_key(&gauge.gauge),
+    ) {
+        // Permissionless rewards are distributed pro rata to balances, so they are not boosted
+        let total_supply_usd = rpc::gauge::get_gauge_total_supply(&gauge.address_vec())
+            .to_decimal(lp_token_decimals)
+            * lp_token_price;
 
-            // Handle Permissionless Rewards
-            if let Some(count) = reward_token_count_store.get_last(
-                StoreKey::liquidity_gauge_reward_token_count_key(&gauge.gauge),
+        for index in 0..count {
+            if let Some(reward_token) = reward_tokens_store.get_last(
+                StoreKey::liquidity_gauge_reward_token_key(&gauge.gauge, &(index + 1)),
             ) {
-                for index in 0..count {
-                    if let Some(reward_token) = reward_tokens_store.get_last(
-                        StoreKey::liquidity_gauge_reward_token_key(&gauge.gauge, &(index + 1)),
-                    ) {
-                        match rpc::gauge::get_reward_token_data(
-                            &gauge.address_vec(),
-                            &reward_token.address_vec(),
-                        ) {
-                            Some(reward_data) => {
-                                if reward_data.period_finish.to_u64() as i64
-                                    > clock.clone().timestamp.unwrap().seconds
-                                {
-                                    // Calculate native token emissions
-                                    let token_emissions_native = reward_data.rate * SECONDS_PER_DAY;
-                                    reward_token_emissions_native
-                                        .push(token_emissions_native.to_string());
-
-                                    let (price_usd, _) = prices::get_token_usd_price(
-                                        &reward_token,
-                                        &uniswap_prices,
-                                        &chainlink_prices,
-                                    );
-
-                                    let token_emissions_usd = token_emissions_native
-                                        .to_decimal(default_decimals())
-                                        * price_usd;
-
-                                    // Calculate USD emissions
-                                    reward_token_emissions_usd
-                                        .push(token_emissions_usd.to_string());
-                                } else {
-                                    reward_token_emissions_native.push(BigInt::zero().to_string());
-                                    reward_token_emissions_usd.push(BigDecimal::zero().to_string());
-                                }
-                            }
-                            None => {
-                                reward_token_emissions_native.push(BigInt::zero().to_string());
-                                reward_token_emissions_usd.push(BigDecimal::zero().to_string());
-                            }
+                match rpc::gauge::get_reward_token_data(
+                    &gauge.address_vec(),
+                    &reward_token.address_vec(),
+                ) {
+                    Some(reward_data) => {
+                        if reward_data.period_finish.to_u64() as i64 > timestamp {
+                            // Calculate native token emissions
+                            let token_emissions_native = reward_data.rate * SECONDS_PER_DAY;
+                            reward_token_emissions_native.push(token_emissions_native.to_string());
+
+                            let (price_usd, _) = prices::get_token_usd_price(
+                                &reward_token,
+                                uniswap_prices,
+                                chainlink_prices,
+                            );
+
+                            let token_emissions_usd = token_emissions_native
+                                .to_decimal(reward_token.decimals)
+                                * price_usd;
+
+                            // Calculate USD emissions
+                            reward_token_emissions_usd.push(token_emissions_usd.to_string());
+
+                            let apr = calculate_apr(&token_emissions_usd, &total_supply_usd);
+                            reward_token_base_aprs.push(apr.to_string());
+                            reward_token_max_boosted_aprs.push(apr.to_string());
+                        } else {
+                            reward_token_emissions_native.push(BigInt::zero().to_string());
+                            reward_token_emissions_usd.push(BigDecimal::zero().to_string());
+                            reward_token_base_aprs.push(BigDecimal::zero().to_string());
+                            reward_token_max_boosted_aprs.push(BigDecimal::zero().to_string());
                         }
                     }
+                    None => {
+                        reward_token_emissions_native.push(BigInt::zero().to_string());
+                        reward_token_emissions_usd.push(BigDecimal::zero().to_string());
+                        reward_token_base_aprs.push(BigDecimal::zero().to_string());
+                        reward_token_max_boosted_aprs.push(BigDecimal::zero().to_string());
+                    }
                 }
+                reward_tokens.push(reward_token.address);
             }
         }
+    }
 
-        if reward_token_emissions_native.len() > 0 || reward_token_emissions_native.len() > 0 {
-            output_store.set(
-                event.log_ordinal,
-                StoreKey::pool_rewards_key(&event.pool),
-                &PoolRewards {
-                    staked_output_token_amount: event.working_supply,
-                    reward_token_emissions_native,
-                    reward_token_emissions_usd,
-                },
-            )
-        }
+    PoolRewards {
+        staked_output_token_amount: working_supply.to_string(),
+        reward_token_emissions_native,
+        reward_token_emissions_usd,
+        reward_tokens,
+        reward_token_base_aprs,
+        reward_token_max_boosted_aprs,
+    }
+}
+
+// Annualises daily emissions in USD over the USD value they are distributed to, as a percentage.
+fn calculate_apr(daily_emissions_usd: &BigDecimal, staked_value_usd: &BigDecimal) -> BigDecimal {
+    if *staked_value_usd == BigDecimal::zero() {
+        return BigDecimal::zero();
     }
+    daily_emissions_usd.clone() * BigDecimal::from(DAYS_PER_YEAR) * BigDecimal::from(100)
+        / staked_value_usd.clone()
 }
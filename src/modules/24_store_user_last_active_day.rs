@@ -0,0 +1,28 @@
+use substreams::{
+    pb::substreams::Clock,
+    store::{StoreNew, StoreSet, StoreSetInt64},
+};
+
+use crate::{
+    key_management::store_key_manager::StoreKey, pb::curve::types::v1::Events,
+    timeframe_management::utils::calculate_day_hour_id,
+};
+
+// Tracks the most recent day each user was active, both across the protocol and per pool.
+// The deltas of this store allow us to count the unique users within a rolling window,
+// as each user is only ever counted against the day they were last active.
+#[substreams::handlers::store]
+pub fn store_user_last_active_day(clock: Clock, events: Events, output_store: StoreSetInt64) {
+    let (day_id, _) = calculate_day_hour_id(clock.timestamp.unwrap().seconds);
+
+    for event in events.pool_events {
+        output_store.set_many(
+            event.log_ordinal,
+            &vec![
+                StoreKey::user_last_active_day_key(&event.from_address),
+                StoreKey::pool_user_last_active_day_key(&event.pool_address, &event.from_address),
+            ],
+            &day_id,
+        );
+    }
+}
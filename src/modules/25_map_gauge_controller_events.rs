@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use substreams::{errors::Error, Hex};
+use substreams_ethereum::pb::eth::v2 as eth;
+
+use crate::{
+    abi::curve::gauge_controller,
+    network_config::GAUGE_CONTROLLER_ADDRESS,
+    pb::curve::types::v1::{
+        GaugeControllerEvents, GaugeTypeWeightUpdate, GaugeVote, GaugeWeightUpdate,
+    },
+    rpc,
+};
+
+#[substreams::handlers::map]
+pub fn map_gauge_controller_events(blk: eth::Block) -> Result<GaugeControllerEvents, Error> {
+    let mut gauge_controller_events = GaugeControllerEvents::default();
+
+    // Gauge weights are voted on mainnet only, so sidechains have no GaugeController to index.
+    let controller = match GAUGE_CONTROLLER_ADDRESS {
+        Some(address) => address,
+        None => return Ok(gauge_controller_events),
+    };
+
+    gauge_controller_events.gauge_votes = map_vote_for_gauge_events(&blk, &controller);
+    gauge_controller_events.gauge_weight_updates =
+        map_gauge_weight_updates(&blk, &controller, &gauge_controller_events.gauge_votes);
+    gauge_controller_events.type_weight_updates = map_new_type_weight_events(&blk, &controller);
+
+    Ok(gauge_controller_events)
+}
+
+fn map_vote_for_gauge_events(blk: &eth::Block, controller: &[u8; 20]) -> Vec<GaugeVote> {
+    blk.events::<gauge_controller::events::VoteForGauge>(&[controller])
+        .map(|(event, log)| GaugeVote {
+            user: Hex::encode(&event.user),
+            gauge: Hex::encode(&event.gauge_addr),
+            weight: event.weight.to_string(),
+            slope: rpc::gauge::get_vote_user_slope(&event.user, &event.gauge_addr).to_string(),
+            time: event.time.to_string(),
+            transaction_hash: Hex::encode(&log.receipt.transaction.hash),
+            tx_index: log.receipt.transaction.index,
+            log_index: log.index(),
+            log_ordinal: log.ordinal(),
+            timestamp: blk.timestamp_seconds(),
+            block_number: blk.number,
+        })
+        .collect()
+}
+
+fn map_gauge_weight_updates(
+    blk: &eth::Block,
+    controller: &[u8; 20],
+    gauge_votes: &Vec<GaugeVote>,
+) -> Vec<GaugeWeightUpdate> {
+    let mut gauge_weight_updates: Vec<GaugeWeightUpdate> = blk
+        .events::<gauge_controller::events::NewGaugeWeight>(&[controller])
+        .map(|(event, log)| GaugeWeightUpdate {
+            gauge: Hex::encode(&event.gauge_address),
+            weight: event.weight.to_string(),
+            total_weight: event.total_weight.to_string(),
+            time: event.time.to_string(),
+            transaction_hash: Hex::encode(&log.receipt.transaction.hash),
+            log_ordinal: log.ordinal(),
+            timestamp: blk.timestamp_seconds(),
+            block_number: blk.number,
+        })
+        .collect();
+
+    if !gauge_votes.is_empty() {
+        // Only the last vote for each gauge is needed, as the weight is read at the end of the block.
+        let mut last_votes: HashMap<&str, &GaugeVote> = HashMap::new();
+        for vote in gauge_votes {
+            last_votes.insert(&vote.gauge, vote);
+        }
+
+        let total_weight = rpc::gauge::get_total_gauge_weight().to_string();
+        for (gauge, vote) in last_votes {
+            let gauge_weight = match Hex::decode(gauge) {
+                Ok(gauge_address) => rpc::gauge::get_gauge_weight(&gauge_address),
+                Err(_) => continue,
+            };
+            gauge_weight_updates.push(GaugeWeightUpdate {
+                gauge: gauge.to_string(),
+                weight: gauge_weight.to_string(),
+                total_weight: total_weight.clone(),
+                time: vote.time.clone(),
+                transaction_hash: vote.transaction_hash.clone(),
+                log_ordinal: vote.log_ordinal,
+                timestamp: vote.timestamp,
+                block_number: vote.block_number,
+            });
+        }
+    }
+
+    // Sort by log ordinal to maintain determinism when handling these messages downstream
+    gauge_weight_updates.sort_by(|a, b| a.log_ordinal.cmp(&b.log_ordinal));
+    gauge_weight_updates
+}
+
+fn map_new_type_weight_events(
+    blk: &eth::Block,
+    controller: &[u8; 20],
+) -> Vec<GaugeTypeWeightUpdate> {
+    blk.events::<gauge_controller::events::NewTypeWeight>(&[controller])
+        .map(|(event, log)| GaugeTypeWeightUpdate {
+            r#type: event.type_id.to_i32(),
+            weight: event.weight.to_string(),
+            total_weight: event.total_weight.to_string(),
+            time: event.time.to_string(),
+            transaction_hash: Hex::encode(&log.receipt.transaction.hash),
+            log_ordinal: log.ordinal(),
+            timestamp: blk.timestamp_seconds(),
+            block_number: blk.number,
+        })
+        .collect()
+}
@@ -0,0 +1,19 @@
+use substreams::store::{StoreNew, StoreSet, StoreSetProto};
+
+use crate::{
+    key_management::store_key_manager::StoreKey,
+    pb::curve::types::v1::{GaugeControllerEvents, GaugeVote},
+};
+
+// Keeps the current vote of each user for each gauge. A new vote replaces the previous one,
+// so the store deltas expose the weight change caused by every vote.
+#[substreams::handlers::store]
+pub fn store_gauge_votes(events: GaugeControllerEvents, output_store: StoreSetProto<GaugeVote>) {
+    for vote in events.gauge_votes {
+        output_store.set(
+            vote.log_ordinal,
+            StoreKey::gauge_vote_key(&vote.gauge, &vote.user),
+            &vote,
+        );
+    }
+}
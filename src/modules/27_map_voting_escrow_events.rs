@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use substreams::{errors::Error, scalar::BigInt, Hex};
+use substreams_ethereum::pb::eth::v2 as eth;
+
+use crate::{
+    abi::curve::voting_escrow,
+    network_config::VOTING_ESCROW_ADDRESS,
+    pb::curve::types::v1::{
+        VotingEscrowEvents, VotingEscrowLockEvent, VotingEscrowLockEventType,
+        VotingEscrowSupplyEvent,
+    },
+    rpc,
+};
+
+#[substreams::handlers::map]
+pub fn map_voting_escrow_events(blk: eth::Block) -> Result<VotingEscrowEvents, Error> {
+    let mut voting_escrow_events = VotingEscrowEvents::default();
+
+    // veCRV only exists on mainnet
+    let voting_escrow_address = match VOTING_ESCROW_ADDRESS {
+        Some(address) => address,
+        None => return Ok(voting_escrow_events),
+    };
+
+    voting_escrow_events.lock_events = map_lock_events(&blk, &voting_escrow_address);
+    voting_escrow_events.supply_events = map_supply_events(&blk, &voting_escrow_address);
+
+    Ok(voting_escrow_events)
+}
+
+fn map_lock_events(
+    blk: &eth::Block,
+    voting_escrow_address: &[u8; 20],
+) -> Vec<VotingEscrowLockEvent> {
+    let mut lock_events: Vec<VotingEscrowLockEvent> = blk
+        .events::<voting_escrow::events::Deposit>(&[voting_escrow_address])
+        .map(|(event, log)| {
+            let deposit_type = VotingEscrowLockEventType::from_i32(event.u_type.to_i32())
+                .unwrap_or(VotingEscrowLockEventType::DepositFor);
+            VotingEscrowLockEvent {
+                provider: Hex::encode(&event.provider),
+                r#type: deposit_type as i32,
+                value: event.value.to_string(),
+                locked_amount: String::new(),
+                unlock_time: event.locktime.to_string(),
+                transaction_hash: Hex::encode(&log.receipt.transaction.hash),
+                tx_index: log.receipt.transaction.index,
+                log_index: log.index(),
+                log_ordinal: log.ordinal(),
+                timestamp: blk.timestamp_seconds(),
+                block_number: blk.number,
+            }
+        })
+        .collect();
+
+    // Withdrawals are only possible once the lock has expired, and always withdraw the full amount.
+    lock_events.extend(
+        blk.events::<voting_escrow::events::Withdraw>(&[voting_escrow_address])
+            .map(|(event, log)| VotingEscrowLockEvent {
+                provider: Hex::encode(&event.provider),
+                r#type: VotingEscrowLockEventType::WithdrawLock as i32,
+                value: event.value.to_string(),
+                locked_amount: BigInt::zero().to_string(),
+                unlock_time: BigInt::zero().to_string(),
+                transaction_hash: Hex::encode(&log.receipt.transaction.hash),
+                tx_index: log.receipt.transaction.index,
+                log_index: log.index(),
+                log_ordinal: log.ordinal(),
+                timestamp: blk.timestamp_seconds(),
+                block_number: blk.number,
+            }),
+    );
+
+    // Sort by log ordinal to maintain determinism when handling these messages downstream
+    lock_events.sort_by(|a, b| a.log_ordinal.cmp(&b.log_ordinal));
+
+    // The events only include the deposited or withdrawn value, so the amount locked after each
+    // event is derived backwards from the amount locked at the end of the block.
+    let mut locked_amounts: HashMap<String, BigInt> = HashMap::new();
+    for lock_event in lock_events.iter_mut().rev() {
+        let locked_amount = locked_amounts
+            .entry(lock_event.provider.clone())
+            .or_insert_with(|| {
+                let provider = Hex::decode(&lock_event.provider).unwrap();
+                rpc::voting_escrow::get_locked(&provider).0
+            });
+        lock_event.locked_amount = locked_amount.to_string();
+        *locked_amount = if lock_event.r#type() == VotingEscrowLockEventType::WithdrawLock {
+            lock_event.parse_value()
+        } else {
+            locked_amount.clone() - lock_event.parse_value()
+        };
+    }
+    lock_events
+}
+
+fn map_supply_events(
+    blk: &eth::Block,
+    voting_escrow_address: &[u8; 20],
+) -> Vec<VotingEscrowSupplyEvent> {
+    let mut supply_events: Vec<VotingEscrowSupplyEvent> = blk
+        .events::<voting_escrow::events::Supply>(&[voting_escrow_address])
+        .map(|(event, log)| VotingEscrowSupplyEvent {
+            previous_supply: event.prev_supply.to_string(),
+            supply: event.supply.to_string(),
+            total_voting_power: String::new(),
+            transaction_hash: Hex::encode(&log.receipt.transaction.hash),
+            log_ordinal: log.ordinal(),
+            timestamp: blk.timestamp_seconds(),
+            block_number: blk.number,
+        })
+        .collect();
+
+    if !supply_events.is_empty() {
+        // The veCRV total supply is only read once, as it reflects the state at the end of the block.
+        let total_voting_power = rpc::voting_escrow::get_total_voting_power().to_string();
+        for supply_event in supply_events.iter_mut() {
+            supply_event.total_voting_power = total_voting_power.clone();
+        }
+    }
+
+    supply_events
+}
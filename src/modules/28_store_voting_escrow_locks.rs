@@ -0,0 +1,22 @@
+use substreams::store::{StoreNew, StoreSet, StoreSetProto};
+
+use crate::{
+    key_management::store_key_manager::StoreKey,
+    pb::curve::types::v1::{VotingEscrowEvents, VotingEscrowLock},
+};
+
+// Keeps the latest lock checkpoint of each provider. The voting power at any later time can be
+// derived from the stored slope and bias, as it decays linearly until the unlock time.
+#[substreams::handlers::store]
+pub fn store_voting_escrow_locks(
+    events: VotingEscrowEvents,
+    output_store: StoreSetProto<VotingEscrowLock>,
+) {
+    for lock_event in events.lock_events {
+        output_store.set(
+            lock_event.log_ordinal,
+            StoreKey::voting_escrow_lock_key(&lock_event.provider),
+            &VotingEscrowLock::from_lock_event(&lock_event),
+        );
+    }
+}
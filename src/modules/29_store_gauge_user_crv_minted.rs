@@ -0,0 +1,21 @@
+use substreams::store::{StoreNew, StoreSet, StoreSetBigInt};
+
+use crate::{
+    key_management::store_key_manager::StoreKey, pb::curve::types::v1::LiquidityGaugeEvents,
+};
+
+// The Minter reports the total CRV minted to a user from a gauge, so the latest value is kept as is.
+// The store deltas then expose the amount minted by each claim.
+#[substreams::handlers::store]
+pub fn store_gauge_user_crv_minted(
+    gauge_events: LiquidityGaugeEvents,
+    output_store: StoreSetBigInt,
+) {
+    for event in gauge_events.crv_minted_events {
+        output_store.set(
+            event.log_ordinal,
+            StoreKey::gauge_user_crv_minted_key(&event.gauge, &event.recipient),
+            &event.parse_minted(),
+        );
+    }
+}
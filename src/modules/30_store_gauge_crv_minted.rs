@@ -0,0 +1,46 @@
+use substreams::{
+    key,
+    pb::substreams::Clock,
+    store::{DeltaBigInt, DeltaInt64, Deltas, StoreAdd, StoreAddBigInt, StoreNew},
+};
+
+use crate::{
+    key_management::store_key_manager::StoreKey,
+    timeframe_management::{
+        pruning::{
+            pruners::gauge_crv_minted_pruner::GaugeCrvMintedPruneAction, setup_timeframe_pruning,
+        },
+        utils::calculate_day_hour_id,
+    },
+};
+
+// Aggregates the CRV minted from each gauge across all users, both cumulatively and per day.
+#[substreams::handlers::store]
+pub fn store_gauge_crv_minted(
+    clock: Clock,
+    gauge_user_crv_minted_deltas: Deltas<DeltaBigInt>,
+    current_time_deltas: Deltas<DeltaInt64>,
+    output_store: StoreAddBigInt,
+) {
+    let gauge_crv_minted_pruner = GaugeCrvMintedPruneAction {
+        store: &output_store,
+    };
+    setup_timeframe_pruning(&current_time_deltas, &[&gauge_crv_minted_pruner]);
+
+    let (day_id, _) = calculate_day_hour_id(clock.timestamp.unwrap().seconds);
+
+    for delta in gauge_user_crv_minted_deltas.deltas.iter() {
+        // Key format: GaugeUserCrvMinted:{gauge}:{user}
+        let gauge_address = key::segment_at(&delta.key, 1);
+        let minted = delta.new_value.clone() - delta.old_value.clone();
+
+        output_store.add_many(
+            delta.ordinal,
+            &vec![
+                StoreKey::gauge_crv_minted_key(gauge_address),
+                StoreKey::gauge_daily_crv_minted_key(&day_id, gauge_address),
+            ],
+            minted,
+        );
+    }
+}
@@ -0,0 +1,10 @@
+use substreams::store::{StoreAdd, StoreAddInt64, StoreNew};
+
+use crate::{key_management::store_key_manager::StoreKey, pb::curve::types::v1::CurveEvents};
+
+#[substreams::handlers::store]
+pub fn store_gauge_count(events: CurveEvents, store: StoreAddInt64) {
+    for gauge in events.gauges {
+        store.add(gauge.log_ordinal, StoreKey::protocol_gauge_count_key(), 1)
+    }
+}
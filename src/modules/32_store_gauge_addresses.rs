@@ -0,0 +1,22 @@
+use substreams::store::{
+    DeltaInt64, Deltas, StoreNew, StoreSetIfNotExists, StoreSetIfNotExistsString,
+};
+
+use crate::{key_management::store_key_manager::StoreKey, pb::curve::types::v1::CurveEvents};
+
+// Indexes gauges by their deployment count, so that all gauges can be iterated over when emissions
+// need to be recalculated without any gauge activity.
+#[substreams::handlers::store]
+pub fn store_gauge_addresses(
+    events: CurveEvents,
+    gauge_count_deltas: Deltas<DeltaInt64>,
+    store: StoreSetIfNotExistsString,
+) {
+    // Use `zip` to iterate over gauges and their corresponding count deltas simultaneously
+    for (gauge, count_delta) in events.gauges.iter().zip(gauge_count_deltas.deltas.iter()) {
+        let key = StoreKey::gauge_address_key(&count_delta.new_value);
+        let value = gauge.gauge.clone();
+
+        store.set_if_not_exists(0, &key, &value);
+    }
+}
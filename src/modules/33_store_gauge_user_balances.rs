@@ -0,0 +1,36 @@
+use substreams::store::{StoreAdd, StoreAddBigInt, StoreNew};
+
+use crate::{
+    key_management::store_key_manager::StoreKey,
+    pb::curve::types::v1::{GaugeLiquidityEventType, LiquidityGaugeEvents},
+};
+
+// Tracks the balance of gauge tokens staked by each user, from gauge deposits and withdrawals as
+// well as transfers of the gauge token between users.
+#[substreams::handlers::store]
+pub fn store_gauge_user_balances(gauge_events: LiquidityGaugeEvents, output_store: StoreAddBigInt) {
+    for event in gauge_events.liquidity_events {
+        let value = match event.r#type() {
+            GaugeLiquidityEventType::Deposit => event.parse_value(),
+            GaugeLiquidityEventType::Withdraw => event.parse_value().neg(),
+        };
+        output_store.add(
+            event.log_ordinal,
+            StoreKey::gauge_user_balance_key(&event.gauge, &event.provider),
+            value,
+        );
+    }
+
+    for event in gauge_events.transfer_events {
+        output_store.add(
+            event.log_ordinal,
+            StoreKey::gauge_user_balance_key(&event.gauge, &event.from),
+            event.parse_value().neg(),
+        );
+        output_store.add(
+            event.log_ordinal,
+            StoreKey::gauge_user_balance_key(&event.gauge, &event.to),
+            event.parse_value(),
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use substreams::{
+    key,
+    scalar::BigInt,
+    store::{DeltaBigInt, Deltas, StoreAdd, StoreAddInt64, StoreNew},
+};
+
+use crate::key_management::store_key_manager::StoreKey;
+
+// Counts the staking positions opened by each user in a gauge. A new position is opened whenever a
+// user's staked balance goes from zero to a positive amount, which gives each position a unique ID.
+#[substreams::handlers::store]
+pub fn store_gauge_position_count(
+    gauge_user_balances_deltas: Deltas<DeltaBigInt>,
+    output_store: StoreAddInt64,
+) {
+    for delta in gauge_user_balances_deltas.deltas {
+        if delta.old_value <= BigInt::zero() && delta.new_value > BigInt::zero() {
+            let gauge_address = key::segment_at(&delta.key, 1);
+            let user_address = key::segment_at(&delta.key, 2);
+            output_store.add(
+                delta.ordinal,
+                StoreKey::gauge_user_position_count_key(gauge_address, user_address),
+                1,
+            );
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use substreams::store::{StoreAdd, StoreAddBigInt, StoreNew};
+
+use crate::{
+    key_management::store_key_manager::StoreKey, pb::curve::types::v1::LiquidityGaugeEvents,
+};
+
+// Accumulates the amount of each reward token claimed from a gauge, in total and per user.
+#[substreams::handlers::store]
+pub fn store_reward_claimed(gauge_events: LiquidityGaugeEvents, output_store: StoreAddBigInt) {
+    for event in gauge_events.reward_claim_events {
+        output_store.add_many(
+            event.log_ordinal,
+            &vec![
+                StoreKey::gauge_reward_claimed_key(&event.gauge, &event.reward_token),
+                StoreKey::gauge_user_reward_claimed_key(
+                    &event.gauge,
+                    &event.user,
+                    &event.reward_token,
+                ),
+            ],
+            event.parse_amount(),
+        );
+    }
+}
@@ -0,0 +1,26 @@
+use substreams::store::{StoreNew, StoreSet, StoreSetString};
+
+use crate::{
+    key_management::store_key_manager::StoreKey, pb::curve::types::v1::LiquidityGaugeEvents,
+};
+
+// Keeps track of the current distributor of each reward token of a gauge. The distributor is first
+// set when the reward token is added, and can later be changed with `set_reward_distributor`.
+#[substreams::handlers::store]
+pub fn store_reward_distributors(gauge_events: LiquidityGaugeEvents, output_store: StoreSetString) {
+    for event in gauge_events.add_reward_events {
+        output_store.set(
+            event.ordinal,
+            StoreKey::reward_token_distributor_key(&event.gauge, &event.reward_token),
+            &event.distributor,
+        );
+    }
+
+    for event in gauge_events.set_reward_distributor_events {
+        output_store.set(
+            event.ordinal,
+            StoreKey::reward_token_distributor_key(&event.gauge, &event.reward_token),
+            &event.distributor,
+        );
+    }
+}
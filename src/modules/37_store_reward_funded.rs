@@ -0,0 +1,21 @@
+use substreams::store::{StoreAdd, StoreAddBigInt, StoreNew};
+
+use crate::{
+    key_management::store_key_manager::StoreKey, pb::curve::types::v1::LiquidityGaugeEvents,
+};
+
+// Accumulates the amount of each reward token deposited by distributors to fund gauge rewards, both
+// per gauge and across all gauges.
+#[substreams::handlers::store]
+pub fn store_reward_funded(gauge_events: LiquidityGaugeEvents, output_store: StoreAddBigInt) {
+    for event in gauge_events.deposit_reward_token_events {
+        output_store.add_many(
+            event.ordinal,
+            &vec![
+                StoreKey::reward_token_funded_key(&event.gauge, &event.reward_token),
+                StoreKey::reward_token_total_funded_key(&event.reward_token),
+            ],
+            event.parse_amount(),
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use substreams::store::{StoreNew, StoreSet, StoreSetProto};
+
+use crate::{
+    key_management::store_key_manager::StoreKey,
+    pb::curve::types::v1::{CurveEvents, RootGauge},
+};
+
+// Root gauges are kept separate from the `store_gauges` store, as they have no pool on this chain and
+// only ever receive CRV emissions from the Minter, which they bridge to their child gauge.
+#[substreams::handlers::store]
+pub fn store_root_gauges(events: CurveEvents, store: StoreSetProto<RootGauge>) {
+    for root_gauge in events.root_gauges {
+        store.set(
+            root_gauge.log_ordinal,
+            StoreKey::root_gauge_key(&root_gauge.gauge),
+            &root_gauge,
+        )
+    }
+}
@@ -0,0 +1,17 @@
+use substreams::store::{StoreAdd, StoreAddBigInt, StoreNew};
+
+use crate::{
+    key_management::store_key_manager::StoreKey, pb::curve::types::v1::LiquidityGaugeEvents,
+};
+
+// Accumulates the CRV bridged to each child gauge on a sidechain, which funds the gauge's emissions.
+#[substreams::handlers::store]
+pub fn store_gauge_crv_bridged(gauge_events: LiquidityGaugeEvents, output_store: StoreAddBigInt) {
+    for event in gauge_events.child_gauge_emission_events {
+        output_store.add(
+            event.log_ordinal,
+            StoreKey::gauge_crv_bridged_key(&event.gauge),
+            event.parse_amount(),
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use substreams::{errors::Error, Hex};
+use substreams_ethereum::{pb::eth::v2 as eth, Event, Function};
+
+use crate::{
+    abi::curve::child_registries::{base_pool_registry, stable_swap_factory_ng},
+    network_config::REGISTRIES,
+    pb::curve::types::v1::{BasePool, BasePools},
+    types::registry::RegistryType,
+};
+
+// Extracts base pools as they are registered, so that new base pools are recognised without having to
+// be added to the static `BASE_POOLS_LP_TOKEN` list in the network config.
+#[substreams::handlers::map]
+pub fn map_base_pool_events(blk: eth::Block) -> Result<BasePools, Error> {
+    let mut base_pools: Vec<BasePool> = Vec::new();
+
+    for trx in blk.transactions() {
+        for (log, call_view) in trx.logs_with_calls() {
+            let registry = match REGISTRIES
+                .iter()
+                .find(|registry| registry.address == log.address.as_slice())
+            {
+                Some(registry) => registry,
+                None => continue,
+            };
+            // `BasePoolAdded` events only include the base pool address, so the LP token is decoded
+            // from the `add_base_pool` call that emitted the event.
+            let base_pool = match registry.registry_type {
+                RegistryType::BasePoolRegistry => {
+                    base_pool_registry::events::BasePoolAdded::match_and_decode(log).and_then(
+                        |event| {
+                            base_pool_registry::functions::AddBasePool::match_and_decode(
+                                call_view.call,
+                            )
+                            .map(|call| (event.basepool, call.u_lp_token))
+                        },
+                    )
+                }
+                RegistryType::StableSwapFactoryNG => {
+                    stable_swap_factory_ng::events::BasePoolAdded::match_and_decode(log).and_then(
+                        |event| {
+                            stable_swap_factory_ng::functions::AddBasePool::match_and_decode(
+                                call_view.call,
+                            )
+                            .map(|call| (event.base_pool, call.u_base_lp_token))
+                        },
+                    )
+                }
+                _ => None,
+            };
+
+            if let Some((base_pool_address, lp_token_address)) = base_pool {
+                base_pools.push(BasePool {
+                    address: Hex::encode(base_pool_address),
+                    lp_token: Hex::encode(lp_token_address),
+                    registry: Hex::encode(registry.address),
+                    created_at_timestamp: blk.timestamp_seconds(),
+                    created_at_block_number: blk.number,
+                    log_ordinal: log.ordinal,
+                });
+            }
+        }
+    }
+
+    Ok(BasePools { base_pools })
+}
@@ -0,0 +1,16 @@
+use substreams::store::{StoreNew, StoreSet, StoreSetString};
+
+use crate::{key_management::store_key_manager::StoreKey, pb::curve::types::v1::BasePools};
+
+// Maps the LP token of each registered base pool to the base pool address. Base pools that predate the
+// registries are seeded from the static `BASE_POOLS_LP_TOKEN` list instead, see `common::utils`.
+#[substreams::handlers::store]
+pub fn store_base_pools(events: BasePools, store: StoreSetString) {
+    for base_pool in events.base_pools {
+        store.set(
+            base_pool.log_ordinal,
+            StoreKey::base_pool_lp_token_key(&base_pool.lp_token),
+            &base_pool.address,
+        )
+    }
+}
@@ -7,8 +7,8 @@ use substreams::{
     pb::substreams::{store_delta::Operation, Clock},
     scalar::{BigDecimal, BigInt},
     store::{
-        DeltaBigDecimal, DeltaInt64, DeltaProto, Deltas, StoreGet, StoreGetBigDecimal,
-        StoreGetBigInt, StoreGetInt64, StoreGetProto, StoreGetString,
+        DeltaBigDecimal, DeltaBigInt, DeltaInt64, DeltaProto, DeltaString, Deltas, StoreGet,
+        StoreGetBigDecimal, StoreGetBigInt, StoreGetInt64, StoreGetProto, StoreGetString,
     },
     Hex,
 };
@@ -32,12 +32,20 @@ use crate::{
                 pool_event::{DepositEvent, SwapEvent, TokenAmount, Type, WithdrawEvent},
                 PoolEvent,
             },
-            CurveEvents, Events, LiquidityGauge, LiquidityGaugeEvents, Pool, PoolFee, PoolFees,
-            PoolRewards, Token,
+            BasePools, CrvUsdEvents, CrvUsdMarket, CurveEvents, Events, GaugeControllerEvents,
+            GaugeTypeWeightUpdate, GaugeVote, GaugeWeightUpdate, LendingEvents, LendingMarket,
+            LiquidityGauge, LiquidityGaugeEvents, Pool, PoolFee, PoolFees, PoolRewards,
+            RewardClaimEvent, StakingWrapper, Token, VotingEscrowEvents, VotingEscrowLock,
+            VotingEscrowSupplyEvent,
         },
         uniswap_pricing::v1::Erc20Price,
     },
-    timeframe_management::snapshot::snapshot_utils::manage_timeframe_snapshots,
+    timeframe_management::{
+        rolling_window::update_rolling_window_aggregates,
+        snapshot::snapshot_utils::manage_timeframe_snapshots,
+        utils::{calculate_day_hour_id, calculate_week_month_id},
+    },
+    types::pool_fees::RevenueSide,
 };
 
 // TODO: If this module gets too bulky, consider following an approach similar to Uniswap V2 SPS:
@@ -72,6 +80,30 @@ pub fn graph_out(
     reward_tokens_store: StoreGetProto<Token>,
     pool_rewards_store: StoreGetProto<PoolRewards>,
     pool_rewards_deltas: Deltas<DeltaProto<PoolRewards>>,
+    gauge_controller_events: GaugeControllerEvents,
+    gauge_votes_deltas: Deltas<DeltaProto<GaugeVote>>,
+    voting_escrow_events: VotingEscrowEvents,
+    voting_escrow_locks_store: StoreGetProto<VotingEscrowLock>,
+    voting_escrow_locks_deltas: Deltas<DeltaProto<VotingEscrowLock>>,
+    gauge_crv_minted_store: StoreGetBigInt,
+    gauge_crv_minted_deltas: Deltas<DeltaBigInt>,
+    gauge_user_balances_deltas: Deltas<DeltaBigInt>,
+    gauge_position_count_store: StoreGetInt64,
+    reward_claimed_store: StoreGetBigInt,
+    reward_distributors_deltas: Deltas<DeltaString>,
+    reward_funded_deltas: Deltas<DeltaBigInt>,
+    gauge_crv_bridged_deltas: Deltas<DeltaBigInt>,
+    base_pool_events: BasePools,
+    pool_revenue_store: StoreGetBigDecimal,
+    pool_revenue_deltas: Deltas<DeltaBigDecimal>,
+    protocol_revenue_store: StoreGetBigDecimal,
+    protocol_revenue_deltas: Deltas<DeltaBigDecimal>,
+    crvusd_events: CrvUsdEvents,
+    crvusd_balances_deltas: Deltas<DeltaBigInt>,
+    lending_events: LendingEvents,
+    lending_balances_deltas: Deltas<DeltaBigInt>,
+    gauge_staked_supply_store: StoreGetBigInt,
+    gauge_staked_supply_deltas: Deltas<DeltaBigInt>,
     uniswap_prices: StoreGetProto<Erc20Price>,
     chainlink_prices: StoreGetBigDecimal,
 ) -> Result<EntityChanges, Error> {
@@ -89,12 +121,65 @@ pub fn graph_out(
         create_pool_token_entities(&mut tables, &pool, &tokens_store)?;
     }
 
+    // Base pools registered after their LP token has already been indexed need the Token updated.
+    for base_pool in base_pool_events.base_pools {
+        if tokens_store
+            .get_last(StoreKey::token_key(&base_pool.lp_token))
+            .is_some()
+        {
+            tables
+                .update_row("Token", EntityKey::token_key(&base_pool.lp_token))
+                .set("isBasePoolLpToken", true);
+        }
+    }
+
     for event in events.gauges {
+        create_liquidity_gauge_entity(&mut tables, &event);
         tables
             .update_row("LiquidityPool", EntityKey::liquidity_pool_key(&event.pool))
-            .set("_gaugeAddress", format_address_string(&event.gauge));
+            .set("_gaugeAddress", format_address_string(&event.gauge))
+            .set("_gauge", EntityKey::liquidity_gauge_key(&event.gauge));
+    }
+
+    // Root gauges bridge CRV emissions to a child gauge on another chain, so have no local pool.
+    for event in events.root_gauges {
+        tables
+            .create_row("RootGauge", EntityKey::root_gauge_key(&event.gauge))
+            .set("chainId", BigInt::from(event.chain_id))
+            .set("deployer", format_address_string(&event.deployer))
+            .set("totalCrvTransmitted", BigInt::zero())
+            .set("createdTimestamp", BigInt::from(event.created_at_timestamp))
+            .set(
+                "createdBlockNumber",
+                BigInt::from(event.created_at_block_number),
+            );
     }
 
+    for event in gauge_events.root_gauge_emission_events.iter() {
+        tables
+            .update_row("RootGauge", EntityKey::root_gauge_key(&event.gauge))
+            .set("totalCrvTransmitted", event.parse_total_transmitted());
+    }
+
+    for delta in gauge_crv_bridged_deltas.deltas.iter() {
+        // Key format: GaugeCrvBridged:{gauge}
+        let gauge_address = key::segment_at(&delta.key, 1);
+        tables
+            .update_row(
+                "LiquidityGauge",
+                EntityKey::liquidity_gauge_key(gauge_address),
+            )
+            .set("cumulativeCrvBridged", delta.new_value.clone());
+    }
+
+    // Create and update entities related to crvUSD markets, loans and PegKeepers
+    create_crvusd_market_entities(&mut tables, &events.crvusd_markets);
+    update_crvusd_entities(&clock, &mut tables, &crvusd_events, &crvusd_balances_deltas);
+
+    // Create and update entities related to LlamaLend vaults, loans and liquidations
+    create_lending_market_entities(&mut tables, &events.lending_markets);
+    update_lending_entities(&mut tables, &lending_events, &lending_balances_deltas);
+
     for event in gauge_events.add_reward_events {
         if let Some(count) = reward_token_count_store.get_last(
             StoreKey::liquidity_gauge_reward_token_count_key(&event.gauge),
@@ -145,7 +230,9 @@ pub fn graph_out(
                         .set(
                             "rewardToken",
                             EntityKey::reward_token_key(&event.reward_token),
-                        );
+                        )
+                        .set("baseAPR", BigDecimal::zero())
+                        .set("maxBoostedAPR", BigDecimal::zero());
                 }
             }
         }
@@ -167,14 +254,45 @@ pub fn graph_out(
                     EntityKey::pool_reward_token_key(&gauge.pool, &crv_address),
                 )
                 .set("pool", EntityKey::liquidity_pool_key(&gauge.pool))
-                .set("rewardToken", EntityKey::reward_token_key(&crv_address));
+                .set("rewardToken", EntityKey::reward_token_key(&crv_address))
+                .set("baseAPR", BigDecimal::zero())
+                .set("maxBoostedAPR", BigDecimal::zero());
 
             tables
                 .update_row("LiquidityPool", EntityKey::liquidity_pool_key(&gauge.pool))
-                .set("_gaugeAddress", format_address_string(&event.gauge));
+                .set("_gaugeAddress", format_address_string(&event.gauge))
+                .set("_gauge", EntityKey::liquidity_gauge_key(&event.gauge));
+
+            tables
+                .update_row(
+                    "LiquidityGauge",
+                    EntityKey::liquidity_gauge_key(&event.gauge),
+                )
+                .set("gaugeType", event.r#type().as_str_name())
+                .set(
+                    "weightAtAdd",
+                    BigInt::from_str(&event.weight).unwrap_or_default(),
+                )
+                .set(
+                    "addedToControllerTimestamp",
+                    BigInt::from(event.created_at_timestamp),
+                )
+                .set(
+                    "addedToControllerBlockNumber",
+                    BigInt::from(event.created_at_block_number),
+                );
         }
     }
 
+    for event in gauge_events.kill_status_events.iter() {
+        tables
+            .update_row(
+                "LiquidityGauge",
+                EntityKey::liquidity_gauge_key(&event.gauge),
+            )
+            .set("isKilled", event.is_killed);
+    }
+
     for delta in pool_rewards_deltas.deltas {
         let pool_address = key::last_segment(&delta.key);
         tables
@@ -193,9 +311,82 @@ pub fn graph_out(
             .set(
                 "rewardTokenEmissionsUSD",
                 delta.new_value.parse_reward_token_emissions_usd(),
+            )
+            .set("_baseRewardAPR", delta.new_value.base_reward_apr())
+            .set(
+                "_maxBoostedRewardAPR",
+                delta.new_value.max_boosted_reward_apr(),
             );
+
+        for ((reward_token, base_apr), max_boosted_apr) in delta
+            .new_value
+            .reward_tokens
+            .iter()
+            .zip(delta.new_value.parse_reward_token_base_aprs())
+            .zip(delta.new_value.parse_reward_token_max_boosted_aprs())
+        {
+            tables
+                .update_row(
+                    "PoolRewardToken",
+                    EntityKey::pool_reward_token_key(&pool_address, reward_token),
+                )
+                .set("baseAPR", base_apr)
+                .set("maxBoostedAPR", max_boosted_apr);
+        }
     }
 
+    // Create entities related to GaugeController weight voting
+    create_gauge_vote_entities(&mut tables, &gauge_votes_deltas, &voting_escrow_locks_store);
+    create_gauge_weight_snapshot_entities(
+        &mut tables,
+        &gauge_controller_events.gauge_weight_updates,
+    );
+    create_gauge_type_weight_entities(&mut tables, &gauge_controller_events.type_weight_updates);
+
+    // Create entities related to veCRV locks
+    create_voting_escrow_lock_entities(&mut tables, &voting_escrow_locks_deltas);
+    create_voting_escrow_daily_snapshot_entities(&mut tables, &voting_escrow_events.supply_events);
+
+    // Update entities related to CRV minted from gauges
+    update_gauge_crv_minted_entities(
+        &clock,
+        &mut tables,
+        &gauge_store,
+        &gauge_crv_minted_store,
+        &gauge_staked_supply_store,
+        &gauge_crv_minted_deltas,
+    );
+
+    // Update entities related to the gauge supply staked through Convex and Stake DAO
+    update_gauge_staked_supply_entities(
+        &clock,
+        &mut tables,
+        &gauge_store,
+        &gauge_crv_minted_store,
+        &gauge_staked_supply_store,
+        &gauge_staked_supply_deltas,
+    );
+
+    // Create and update entities related to users staking in gauges
+    update_gauge_position_entities(
+        &clock,
+        &mut tables,
+        &gauge_store,
+        &gauge_position_count_store,
+        &gauge_user_balances_deltas,
+    );
+
+    // Create entities related to gauge reward claims
+    create_reward_claim_entities(
+        &mut tables,
+        &gauge_events.reward_claim_events,
+        &reward_claimed_store,
+    );
+
+    // Update entities related to the funding of gauge reward tokens
+    update_reward_distributor_entities(&mut tables, &gauge_store, &reward_distributors_deltas);
+    update_reward_funded_entities(&mut tables, &gauge_store, &reward_funded_deltas);
+
     for delta in pool_count_deltas.deltas.iter().last() {
         tables
             .update_row("DexAmmProtocol", EntityKey::protocol_key())
@@ -242,6 +433,41 @@ pub fn graph_out(
         }
     }
 
+    for delta in pool_revenue_deltas.deltas.iter() {
+        let field = match key::first_segment(&delta.key) {
+            "PoolTotalRevenueUsd" => "cumulativeTotalRevenueUSD",
+            "PoolProtocolSideRevenueUsd" => "cumulativeProtocolSideRevenueUSD",
+            "PoolSupplySideRevenueUsd" => "cumulativeSupplySideRevenueUSD",
+            _ => continue,
+        };
+        if let Some(pool_address) = key::try_last_segment(&delta.key) {
+            tables
+                .update_row(
+                    "LiquidityPool",
+                    EntityKey::liquidity_pool_key(&pool_address),
+                )
+                .set(field, delta.new_value.clone());
+        }
+    }
+
+    if !protocol_revenue_deltas.deltas.is_empty() {
+        let protocol_row = tables.update_row("DexAmmProtocol", EntityKey::protocol_key());
+        for (side, field) in [
+            (RevenueSide::Total, "cumulativeTotalRevenueUSD"),
+            (
+                RevenueSide::ProtocolSide,
+                "cumulativeProtocolSideRevenueUSD",
+            ),
+            (RevenueSide::SupplySide, "cumulativeSupplySideRevenueUSD"),
+        ] {
+            if let Some(revenue) =
+                protocol_revenue_store.get_last(StoreKey::protocol_revenue_usd_key(&side))
+            {
+                protocol_row.set(field, revenue);
+            }
+        }
+    }
+
     // Start - Pool TVL weights updates
     if !pool_tvl_deltas.deltas.is_empty() {
         // Initialize a HashSet to store unique pool addresses
@@ -265,12 +491,20 @@ pub fn graph_out(
         }
     }
 
+    // Keep track of the pools with activity in this block, so their rolling aggregates can be updated
+    let active_pool_addresses: HashSet<String> = pool_events
+        .pool_events
+        .iter()
+        .map(|event| event.pool_address.clone())
+        .collect();
+
     // Create entities related to Pool events
     create_pool_events_entities(
         &clock,
         &mut tables,
         pool_events.pool_events,
         &pools_store,
+        &pool_fees_store,
         &output_token_supply_store,
         &input_token_balances_store,
         &pool_tvl_store,
@@ -279,6 +513,18 @@ pub fn graph_out(
         &chainlink_prices,
     );
 
+    update_rolling_window_aggregates(
+        &mut tables,
+        &clock,
+        &active_pool_addresses,
+        &current_time_deltas,
+        &pool_count_store,
+        &pool_addresses_store,
+        &pool_volume_usd_store,
+        &protocol_volume_store,
+        &usage_metrics_store,
+    );
+
     manage_timeframe_snapshots(
         &clock,
         &current_time_deltas,
@@ -292,6 +538,8 @@ pub fn graph_out(
         &pool_volume_native_store,
         &protocol_tvl_store,
         &protocol_volume_store,
+        &pool_revenue_store,
+        &protocol_revenue_store,
         &input_token_balances_store,
         &output_token_supply_store,
         &pool_rewards_store,
@@ -324,7 +572,745 @@ fn create_protocol_entity(tables: &mut Tables, clock: &Clock) {
             .set("cumulativeProtocolSideRevenueUSD", BigDecimal::zero())
             .set("cumulativeTotalRevenueUSD", BigDecimal::zero())
             .set("cumulativeUniqueUsers", 0 as i32)
-            .set("totalPoolCount", 0 as i32);
+            .set("totalPoolCount", 0 as i32)
+            .set("_volume7dUSD", BigDecimal::zero())
+            .set("_volume30dUSD", BigDecimal::zero())
+            .set("_uniqueUsers7d", 0 as i32);
+    }
+}
+
+// Votes are created from the store deltas, as these also provide the user's previous vote for the gauge.
+fn create_gauge_vote_entities(
+    tables: &mut Tables,
+    gauge_votes_deltas: &Deltas<DeltaProto<GaugeVote>>,
+    voting_escrow_locks_store: &StoreGetProto<VotingEscrowLock>,
+) {
+    for delta in gauge_votes_deltas.deltas.iter() {
+        let vote = &delta.new_value;
+        let voting_power = voting_escrow_locks_store
+            .get_at(
+                vote.log_ordinal,
+                StoreKey::voting_escrow_lock_key(&vote.user),
+            )
+            .map(|lock| lock.voting_power_at(vote.timestamp))
+            .unwrap_or_else(BigInt::zero);
+        tables
+            .create_row(
+                "GaugeVote",
+                EntityKey::gauge_vote_key(&vote.transaction_hash, &vote.log_index),
+            )
+            .set(
+                "hash",
+                format::format_address_string(&vote.transaction_hash),
+            )
+            .set("logIndex", vote.log_index as i32)
+            .set("gauge", format::format_address_string(&vote.gauge))
+            .set("user", format::format_address_string(&vote.user))
+            .set("weight", vote.parse_weight())
+            .set("previousWeight", delta.old_value.parse_weight())
+            .set("slope", vote.parse_slope())
+            .set("votingPower", voting_power)
+            .set("time", BigInt::from_str(&vote.time).unwrap_or_default())
+            .set("blockNumber", BigInt::from(vote.block_number))
+            .set("timestamp", BigInt::from(vote.timestamp));
+    }
+}
+
+// Gauge weights are snapshotted per week, matching the weekly epochs used by the GaugeController.
+// Each update within a week overwrites the snapshot, so it holds the latest weight for that week.
+fn create_gauge_weight_snapshot_entities(
+    tables: &mut Tables,
+    gauge_weight_updates: &Vec<GaugeWeightUpdate>,
+) {
+    for update in gauge_weight_updates {
+        let (week_id, _) = calculate_week_month_id(update.timestamp as i64);
+        tables
+            .create_row(
+                "GaugeWeightSnapshot",
+                EntityKey::gauge_weight_snapshot_key(&update.gauge, &week_id),
+            )
+            .set("gauge", format::format_address_string(&update.gauge))
+            .set("weight", update.parse_weight())
+            .set("totalWeight", update.parse_total_weight())
+            .set("relativeWeight", update.relative_weight())
+            .set("time", BigInt::from_str(&update.time).unwrap_or_default())
+            .set("blockNumber", BigInt::from(update.block_number))
+            .set("timestamp", BigInt::from(update.timestamp));
+    }
+}
+
+fn create_gauge_type_weight_entities(
+    tables: &mut Tables,
+    type_weight_updates: &Vec<GaugeTypeWeightUpdate>,
+) {
+    for update in type_weight_updates {
+        tables
+            .create_row(
+                "GaugeTypeWeight",
+                EntityKey::gauge_type_weight_key(&update.r#type),
+            )
+            .set("gaugeType", update.r#type().as_str_name())
+            .set(
+                "weight",
+                BigInt::from_str(&update.weight).unwrap_or_default(),
+            )
+            .set(
+                "totalWeight",
+                BigInt::from_str(&update.total_weight).unwrap_or_default(),
+            )
+            .set("time", BigInt::from_str(&update.time).unwrap_or_default())
+            .set("blockNumber", BigInt::from(update.block_number))
+            .set("timestamp", BigInt::from(update.timestamp));
+    }
+}
+
+fn create_voting_escrow_lock_entities(
+    tables: &mut Tables,
+    voting_escrow_locks_deltas: &Deltas<DeltaProto<VotingEscrowLock>>,
+) {
+    for delta in voting_escrow_locks_deltas.deltas.iter() {
+        let lock = &delta.new_value;
+        tables
+            .create_row(
+                "VotingEscrowLock",
+                EntityKey::voting_escrow_lock_key(&lock.provider),
+            )
+            .set("provider", format::format_address_string(&lock.provider))
+            .set("amount", lock.parse_amount())
+            .set("unlockTime", lock.parse_unlock_time())
+            .set("slope", lock.parse_slope())
+            .set("bias", lock.parse_bias())
+            .set(
+                "lastUpdateBlockNumber",
+                BigInt::from(lock.updated_at_block_number),
+            )
+            .set(
+                "lastUpdateTimestamp",
+                BigInt::from(lock.updated_at_timestamp),
+            );
+    }
+}
+
+// The veCRV supply is snapshotted per day. Each update within a day overwrites the snapshot,
+// so it holds the latest supply for that day.
+fn create_voting_escrow_daily_snapshot_entities(
+    tables: &mut Tables,
+    supply_events: &Vec<VotingEscrowSupplyEvent>,
+) {
+    for supply_event in supply_events {
+        let (day_id, _) = calculate_day_hour_id(supply_event.timestamp as i64);
+        tables
+            .create_row(
+                "VotingEscrowDailySnapshot",
+                EntityKey::voting_escrow_daily_snapshot_key(&day_id),
+            )
+            .set("day", convert_i64_to_i32(day_id))
+            .set("lockedSupply", supply_event.parse_supply())
+            .set("totalVotingPower", supply_event.parse_total_voting_power())
+            .set("blockNumber", BigInt::from(supply_event.block_number))
+            .set("timestamp", BigInt::from(supply_event.timestamp));
+    }
+}
+
+// Realised CRV distributions, based on the `Minted` events of the Minter. These can be compared with
+// the theoretical CRV emissions in `rewardTokenEmissionsAmount`.
+fn update_gauge_crv_minted_entities(
+    clock: &Clock,
+    tables: &mut Tables,
+    gauge_store: &StoreGetProto<LiquidityGauge>,
+    gauge_crv_minted_store: &StoreGetBigInt,
+    gauge_staked_supply_store: &StoreGetBigInt,
+    gauge_crv_minted_deltas: &Deltas<DeltaBigInt>,
+) {
+    for delta in gauge_crv_minted_deltas.deltas.iter() {
+        // Deletes are caused by pruning, and do not reflect any minting.
+        if delta.operation == Operation::Delete {
+            continue;
+        }
+        match key::first_segment(&delta.key) {
+            "GaugeCrvMinted" => {
+                let gauge_address = key::segment_at(&delta.key, 1);
+                if let Some(gauge) =
+                    gauge_store.get_last(StoreKey::liquidity_gauge_key(gauge_address))
+                {
+                    tables
+                        .update_row("LiquidityPool", EntityKey::liquidity_pool_key(&gauge.pool))
+                        .set("_cumulativeCrvMinted", delta.new_value.clone());
+                }
+            }
+            "GaugeDailyCrvMinted" => {
+                let day_id = key::segment_at(&delta.key, 1)
+                    .parse::<i64>()
+                    .unwrap_or_default();
+                let gauge_address = key::segment_at(&delta.key, 2);
+                create_gauge_daily_snapshot_entity(
+                    clock,
+                    tables,
+                    gauge_address,
+                    &day_id,
+                    gauge_store,
+                    gauge_crv_minted_store,
+                    gauge_staked_supply_store,
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+// Exposes how much of each gauge's supply is staked through the Convex and Stake DAO wrappers.
+fn update_gauge_staked_supply_entities(
+    clock: &Clock,
+    tables: &mut Tables,
+    gauge_store: &StoreGetProto<LiquidityGauge>,
+    gauge_crv_minted_store: &StoreGetBigInt,
+    gauge_staked_supply_store: &StoreGetBigInt,
+    gauge_staked_supply_deltas: &Deltas<DeltaBigInt>,
+) {
+    let (day_id, _) = calculate_day_hour_id(clock.timestamp.as_ref().unwrap().seconds);
+    let mut updated_gauges: HashSet<&str> = HashSet::new();
+
+    for delta in gauge_staked_supply_deltas.deltas.iter() {
+        // Key format: GaugeStakedSupply:{gauge} or GaugeWrapperStakedSupply:{gauge}:{wrapper}
+        let gauge_address = key::segment_at(&delta.key, 1);
+        if !updated_gauges.insert(gauge_address) {
+            continue;
+        }
+
+        if let Some(gauge) = gauge_store.get_last(StoreKey::liquidity_gauge_key(gauge_address)) {
+            let (total_staked, convex_staked, stake_dao_staked) =
+                get_gauge_staked_supply(gauge_address, gauge_staked_supply_store);
+            tables
+                .update_row("LiquidityPool", EntityKey::liquidity_pool_key(&gauge.pool))
+                .set("_gaugeStakedSupply", total_staked)
+                .set("_convexStakedSupply", convex_staked)
+                .set("_stakeDaoStakedSupply", stake_dao_staked);
+        }
+
+        create_gauge_daily_snapshot_entity(
+            clock,
+            tables,
+            gauge_address,
+            &day_id,
+            gauge_store,
+            gauge_crv_minted_store,
+            gauge_staked_supply_store,
+        );
+    }
+}
+
+fn create_gauge_daily_snapshot_entity(
+    clock: &Clock,
+    tables: &mut Tables,
+    gauge_address: &str,
+    day_id: &i64,
+    gauge_store: &StoreGetProto<LiquidityGauge>,
+    gauge_crv_minted_store: &StoreGetBigInt,
+    gauge_staked_supply_store: &StoreGetBigInt,
+) {
+    let pool_address = gauge_store
+        .get_last(StoreKey::liquidity_gauge_key(gauge_address))
+        .map(|gauge| format::format_address_string(&gauge.pool))
+        .unwrap_or_default();
+    let daily_crv_minted = gauge_crv_minted_store
+        .get_last(StoreKey::gauge_daily_crv_minted_key(day_id, gauge_address))
+        .unwrap_or_else(|| BigInt::zero());
+    let cumulative_crv_minted = gauge_crv_minted_store
+        .get_last(StoreKey::gauge_crv_minted_key(gauge_address))
+        .unwrap_or_else(|| BigInt::zero());
+    let (total_staked, convex_staked, stake_dao_staked) =
+        get_gauge_staked_supply(gauge_address, gauge_staked_supply_store);
+
+    tables
+        .create_row(
+            "GaugeDailySnapshot",
+            EntityKey::gauge_daily_snapshot_key(gauge_address, day_id),
+        )
+        .set("gauge", format::format_address_string(gauge_address))
+        .set("pool", pool_address)
+        .set("day", convert_i64_to_i32(*day_id))
+        .set("dailyCrvMinted", daily_crv_minted)
+        .set("cumulativeCrvMinted", cumulative_crv_minted)
+        .set("totalStakedSupply", total_staked)
+        .set("convexStakedSupply", convex_staked)
+        .set("stakeDaoStakedSupply", stake_dao_staked)
+        .set("blockNumber", BigInt::from(clock.number))
+        .set(
+            "timestamp",
+            BigInt::from(clock.timestamp.as_ref().unwrap().seconds),
+        );
+}
+
+// Returns the total supply staked in the gauge, and the amounts staked via Convex and Stake DAO.
+fn get_gauge_staked_supply(
+    gauge_address: &str,
+    gauge_staked_supply_store: &StoreGetBigInt,
+) -> (BigInt, BigInt, BigInt) {
+    let get_wrapper_staked = |staking_wrapper: StakingWrapper| {
+        gauge_staked_supply_store
+            .get_last(StoreKey::gauge_wrapper_staked_supply_key(
+                gauge_address,
+                staking_wrapper.as_str_name(),
+            ))
+            .unwrap_or_else(|| BigInt::zero())
+    };
+    let total_staked = gauge_staked_supply_store
+        .get_last(StoreKey::gauge_staked_supply_key(gauge_address))
+        .unwrap_or_else(|| BigInt::zero());
+    (
+        total_staked,
+        get_wrapper_staked(StakingWrapper::Convex),
+        get_wrapper_staked(StakingWrapper::StakeDao),
+    )
+}
+
+fn create_crvusd_market_entities(tables: &mut Tables, markets: &Vec<CrvUsdMarket>) {
+    for market in markets {
+        let collateral_token = market
+            .collateral_token
+            .as_ref()
+            .map(|token| format_address_string(&token.address))
+            .unwrap_or_default();
+        tables
+            .create_row(
+                "CrvUsdMarket",
+                EntityKey::crvusd_market_key(&market.controller),
+            )
+            .set("amm", format_address_string(&market.amm))
+            .set(
+                "monetaryPolicy",
+                format_address_string(&market.monetary_policy),
+            )
+            .set("collateralToken", collateral_token)
+            .set("index", market.index as i32)
+            .set("totalDebt", BigInt::zero())
+            .set("totalCollateral", BigInt::zero())
+            .set("totalStablecoin", BigInt::zero())
+            .set("cumulativeBorrowed", BigInt::zero())
+            .set("cumulativeRepaid", BigInt::zero())
+            .set("cumulativeLiquidatedDebt", BigInt::zero())
+            .set("cumulativeLiquidatedCollateral", BigInt::zero())
+            .set(
+                "createdTimestamp",
+                BigInt::from(market.created_at_timestamp),
+            )
+            .set(
+                "createdBlockNumber",
+                BigInt::from(market.created_at_block_number),
+            );
+    }
+}
+
+fn update_crvusd_entities(
+    clock: &Clock,
+    tables: &mut Tables,
+    crvusd_events: &CrvUsdEvents,
+    crvusd_balances_deltas: &Deltas<DeltaBigInt>,
+) {
+    for delta in crvusd_balances_deltas.deltas.iter() {
+        // Key format: {balance}:{controller or PegKeeper}
+        let address = key::segment_at(&delta.key, 1);
+        let field = match key::first_segment(&delta.key) {
+            "CrvUsdMarketCollateral" => "totalCollateral",
+            "CrvUsdMarketStablecoin" => "totalStablecoin",
+            "CrvUsdMarketBorrowed" => "cumulativeBorrowed",
+            "CrvUsdMarketRepaid" => "cumulativeRepaid",
+            "CrvUsdMarketLiquidatedDebt" => "cumulativeLiquidatedDebt",
+            "CrvUsdMarketLiquidatedCollateral" => "cumulativeLiquidatedCollateral",
+            "PegKeeperDebt" => {
+                tables
+                    .create_row("PegKeeper", EntityKey::peg_keeper_key(address))
+                    .set("debt", delta.new_value.clone())
+                    .set(
+                        "lastUpdatedTimestamp",
+                        BigInt::from(clock.timestamp.as_ref().unwrap().seconds),
+                    )
+                    .set("lastUpdatedBlockNumber", BigInt::from(clock.number));
+                continue;
+            }
+            _ => continue,
+        };
+        tables
+            .update_row("CrvUsdMarket", EntityKey::crvusd_market_key(address))
+            .set(field, delta.new_value.clone());
+    }
+
+    // Loans are read from the Controller after each event, so positions are overwritten as a whole.
+    for event in crvusd_events.controller_loan_events.iter() {
+        tables
+            .create_row(
+                "CrvUsdPosition",
+                EntityKey::crvusd_position_key(&event.controller, &event.user),
+            )
+            .set("market", EntityKey::crvusd_market_key(&event.controller))
+            .set("user", format_address_string(&event.user))
+            .set("collateral", event.parse_user_collateral())
+            .set("stablecoin", event.parse_user_stablecoin())
+            .set("debt", event.parse_user_debt())
+            .set("lastUpdatedTimestamp", BigInt::from(event.timestamp))
+            .set("lastUpdatedBlockNumber", BigInt::from(event.block_number));
+        tables
+            .update_row(
+                "CrvUsdMarket",
+                EntityKey::crvusd_market_key(&event.controller),
+            )
+            .set("totalDebt", event.parse_total_debt());
+    }
+
+    for event in crvusd_events.controller_liquidation_events.iter() {
+        tables
+            .create_row(
+                "CrvUsdLiquidation",
+                EntityKey::crvusd_liquidation_key(&event.transaction_hash, &event.log_index),
+            )
+            .set("hash", format_address_string(&event.transaction_hash))
+            .set("logIndex", event.log_index as i32)
+            .set("market", EntityKey::crvusd_market_key(&event.controller))
+            .set("liquidator", format_address_string(&event.liquidator))
+            .set("user", format_address_string(&event.user))
+            .set("collateralReceived", event.parse_collateral_received())
+            .set("stablecoinReceived", event.parse_stablecoin_received())
+            .set("debt", event.parse_debt())
+            .set("blockNumber", BigInt::from(event.block_number))
+            .set("timestamp", BigInt::from(event.timestamp));
+        tables
+            .update_row(
+                "CrvUsdMarket",
+                EntityKey::crvusd_market_key(&event.controller),
+            )
+            .set("totalDebt", event.parse_total_debt());
+    }
+
+    for event in crvusd_events.llamma_exchange_events.iter() {
+        tables
+            .create_row(
+                "LlammaExchange",
+                EntityKey::llamma_exchange_key(&event.transaction_hash, &event.log_index),
+            )
+            .set("hash", format_address_string(&event.transaction_hash))
+            .set("logIndex", event.log_index as i32)
+            .set("market", EntityKey::crvusd_market_key(&event.controller))
+            .set("buyer", format_address_string(&event.buyer))
+            .set("tokenSold", format_address_string(&event.sold_token))
+            .set("amountSold", event.parse_tokens_sold())
+            .set("tokenBought", format_address_string(&event.bought_token))
+            .set("amountBought", event.parse_tokens_bought())
+            .set("blockNumber", BigInt::from(event.block_number))
+            .set("timestamp", BigInt::from(event.timestamp));
+    }
+}
+
+fn create_lending_market_entities(tables: &mut Tables, markets: &Vec<LendingMarket>) {
+    for market in markets {
+        let borrowed_token = market
+            .borrowed_token
+            .as_ref()
+            .map(|token| format_address_string(&token.address))
+            .unwrap_or_default();
+        let collateral_token = market
+            .collateral_token
+            .as_ref()
+            .map(|token| format_address_string(&token.address))
+            .unwrap_or_default();
+        tables
+            .create_row(
+                "LendingMarket",
+                EntityKey::lending_market_key(&market.controller),
+            )
+            .set("vault", format_address_string(&market.vault))
+            .set("amm", format_address_string(&market.amm))
+            .set("priceOracle", format_address_string(&market.price_oracle))
+            .set(
+                "monetaryPolicy",
+                format_address_string(&market.monetary_policy),
+            )
+            .set("borrowedToken", borrowed_token)
+            .set("collateralToken", collateral_token)
+            .set("index", market.index as i32)
+            .set("totalSupplied", BigInt::zero())
+            .set("totalShares", BigInt::zero())
+            .set("totalBorrowed", BigInt::zero())
+            .set("utilization", BigDecimal::zero())
+            .set("borrowAPR", BigDecimal::zero())
+            .set("lendAPR", BigDecimal::zero())
+            .set("cumulativeDeposited", BigInt::zero())
+            .set("cumulativeWithdrawn", BigInt::zero())
+            .set("cumulativeBorrowed", BigInt::zero())
+            .set("cumulativeRepaid", BigInt::zero())
+            .set("cumulativeLiquidatedDebt", BigInt::zero())
+            .set(
+                "createdTimestamp",
+                BigInt::from(market.created_at_timestamp),
+            )
+            .set(
+                "createdBlockNumber",
+                BigInt::from(market.created_at_block_number),
+            )
+            .set(
+                "lastUpdatedTimestamp",
+                BigInt::from(market.created_at_timestamp),
+            )
+            .set(
+                "lastUpdatedBlockNumber",
+                BigInt::from(market.created_at_block_number),
+            );
+    }
+}
+
+fn update_lending_entities(
+    tables: &mut Tables,
+    lending_events: &LendingEvents,
+    lending_balances_deltas: &Deltas<DeltaBigInt>,
+) {
+    for delta in lending_balances_deltas.deltas.iter() {
+        // Key format: {balance}:{controller}
+        let controller_address = key::segment_at(&delta.key, 1);
+        let field = match key::first_segment(&delta.key) {
+            "LendingMarketDeposited" => "cumulativeDeposited",
+            "LendingMarketWithdrawn" => "cumulativeWithdrawn",
+            "LendingMarketBorrowed" => "cumulativeBorrowed",
+            "LendingMarketRepaid" => "cumulativeRepaid",
+            "LendingMarketLiquidatedDebt" => "cumulativeLiquidatedDebt",
+            _ => continue,
+        };
+        tables
+            .update_row(
+                "LendingMarket",
+                EntityKey::lending_market_key(controller_address),
+            )
+            .set(field, delta.new_value.clone());
+    }
+
+    for state in lending_events.market_states.iter() {
+        tables
+            .update_row(
+                "LendingMarket",
+                EntityKey::lending_market_key(&state.controller),
+            )
+            .set("totalSupplied", state.parse_total_assets())
+            .set("totalShares", state.parse_total_shares())
+            .set("totalBorrowed", state.parse_total_debt())
+            .set("utilization", state.utilization())
+            .set("borrowAPR", state.borrow_apr())
+            .set("lendAPR", state.lend_apr())
+            .set("lastUpdatedTimestamp", BigInt::from(state.timestamp))
+            .set("lastUpdatedBlockNumber", BigInt::from(state.block_number));
+    }
+
+    // Loans are read from the Controller after each event, so positions are overwritten as a whole.
+    for event in lending_events.controller_loan_events.iter() {
+        tables
+            .create_row(
+                "LendingPosition",
+                EntityKey::lending_position_key(&event.controller, &event.user),
+            )
+            .set("market", EntityKey::lending_market_key(&event.controller))
+            .set("user", format_address_string(&event.user))
+            .set("collateral", event.parse_user_collateral())
+            .set("borrowedTokenBalance", event.parse_user_stablecoin())
+            .set("debt", event.parse_user_debt())
+            .set("lastUpdatedTimestamp", BigInt::from(event.timestamp))
+            .set("lastUpdatedBlockNumber", BigInt::from(event.block_number));
+    }
+
+    for event in lending_events.controller_liquidation_events.iter() {
+        tables
+            .create_row(
+                "LendingLiquidation",
+                EntityKey::lending_liquidation_key(&event.transaction_hash, &event.log_index),
+            )
+            .set("hash", format_address_string(&event.transaction_hash))
+            .set("logIndex", event.log_index as i32)
+            .set("market", EntityKey::lending_market_key(&event.controller))
+            .set("liquidator", format_address_string(&event.liquidator))
+            .set("user", format_address_string(&event.user))
+            .set("collateralReceived", event.parse_collateral_received())
+            .set("borrowedTokenReceived", event.parse_stablecoin_received())
+            .set("debt", event.parse_debt())
+            .set("blockNumber", BigInt::from(event.block_number))
+            .set("timestamp", BigInt::from(event.timestamp));
+    }
+}
+
+fn create_liquidity_gauge_entity(tables: &mut Tables, gauge: &LiquidityGauge) {
+    let row = tables.create_row(
+        "LiquidityGauge",
+        EntityKey::liquidity_gauge_key(&gauge.gauge),
+    );
+    // Only gauges deployed from crypto pool factories and the ChildGaugeFactory report their LP token
+    if let Some(token) = &gauge.token {
+        row.set("lpToken", format_address_string(token));
+    }
+    row.set("pool", EntityKey::liquidity_pool_key(&gauge.pool))
+        .set("isKilled", false)
+        .set("createdTimestamp", BigInt::from(gauge.created_at_timestamp))
+        .set(
+            "createdBlockNumber",
+            BigInt::from(gauge.created_at_block_number),
+        );
+}
+
+fn update_reward_distributor_entities(
+    tables: &mut Tables,
+    gauge_store: &StoreGetProto<LiquidityGauge>,
+    reward_distributors_deltas: &Deltas<DeltaString>,
+) {
+    for delta in reward_distributors_deltas.deltas.iter() {
+        // Key format: RewardTokenDistributor:{gauge}:{reward_token}
+        let gauge_address = key::segment_at(&delta.key, 1);
+        let reward_token_address = key::segment_at(&delta.key, 2);
+        if let Some(gauge) = gauge_store.get_last(StoreKey::liquidity_gauge_key(gauge_address)) {
+            tables
+                .update_row(
+                    "PoolRewardToken",
+                    EntityKey::pool_reward_token_key(&gauge.pool, reward_token_address),
+                )
+                .set("distributor", format_address_string(&delta.new_value));
+        }
+    }
+}
+
+fn update_reward_funded_entities(
+    tables: &mut Tables,
+    gauge_store: &StoreGetProto<LiquidityGauge>,
+    reward_funded_deltas: &Deltas<DeltaBigInt>,
+) {
+    for delta in reward_funded_deltas.deltas.iter() {
+        match key::first_segment(&delta.key) {
+            "RewardTokenFunded" => {
+                let gauge_address = key::segment_at(&delta.key, 1);
+                let reward_token_address = key::segment_at(&delta.key, 2);
+                if let Some(gauge) =
+                    gauge_store.get_last(StoreKey::liquidity_gauge_key(gauge_address))
+                {
+                    tables
+                        .update_row(
+                            "PoolRewardToken",
+                            EntityKey::pool_reward_token_key(&gauge.pool, reward_token_address),
+                        )
+                        .set("fundedAmount", delta.new_value.clone());
+                }
+            }
+            "RewardTokenTotalFunded" => {
+                let reward_token_address = key::segment_at(&delta.key, 1);
+                tables
+                    .update_row(
+                        "RewardToken",
+                        EntityKey::reward_token_key(reward_token_address),
+                    )
+                    .set("_totalFundedAmount", delta.new_value.clone());
+            }
+            _ => {}
+        }
+    }
+}
+
+fn create_reward_claim_entities(
+    tables: &mut Tables,
+    reward_claim_events: &Vec<RewardClaimEvent>,
+    reward_claimed_store: &StoreGetBigInt,
+) {
+    for event in reward_claim_events {
+        // Cumulative amounts are read as of the claim, as a user can claim several times per block.
+        let cumulative_gauge_claimed = reward_claimed_store
+            .get_at(
+                event.log_ordinal,
+                StoreKey::gauge_reward_claimed_key(&event.gauge, &event.reward_token),
+            )
+            .unwrap_or_else(|| BigInt::zero());
+        let cumulative_user_claimed = reward_claimed_store
+            .get_at(
+                event.log_ordinal,
+                StoreKey::gauge_user_reward_claimed_key(
+                    &event.gauge,
+                    &event.user,
+                    &event.reward_token,
+                ),
+            )
+            .unwrap_or_else(|| BigInt::zero());
+
+        tables
+            .create_row(
+                "RewardClaim",
+                EntityKey::reward_claim_key(&event.transaction_hash, &event.log_index),
+            )
+            .set(
+                "hash",
+                format::format_address_string(&event.transaction_hash),
+            )
+            .set("logIndex", event.log_index as i32)
+            .set("gauge", format::format_address_string(&event.gauge))
+            .set("pool", format::format_address_string(&event.pool))
+            .set("user", format::format_address_string(&event.user))
+            .set("receiver", format::format_address_string(&event.receiver))
+            .set(
+                "rewardToken",
+                format::format_address_string(&event.reward_token),
+            )
+            .set("amount", event.parse_amount())
+            .set("cumulativeUserClaimed", cumulative_user_claimed)
+            .set("cumulativeGaugeClaimed", cumulative_gauge_claimed)
+            .set("blockNumber", BigInt::from(event.block_number))
+            .set("timestamp", BigInt::from(event.timestamp));
+    }
+}
+
+// A gauge position is opened when a user's staked balance goes from zero to a positive amount, and
+// closed when it goes back to zero. Each new position of a user in a gauge gets its own entity.
+fn update_gauge_position_entities(
+    clock: &Clock,
+    tables: &mut Tables,
+    gauge_store: &StoreGetProto<LiquidityGauge>,
+    gauge_position_count_store: &StoreGetInt64,
+    gauge_user_balances_deltas: &Deltas<DeltaBigInt>,
+) {
+    let timestamp = BigInt::from(clock.timestamp.as_ref().unwrap().seconds);
+    let block_number = BigInt::from(clock.number);
+
+    for delta in gauge_user_balances_deltas.deltas.iter() {
+        let gauge_address = key::segment_at(&delta.key, 1);
+        let user_address = key::segment_at(&delta.key, 2);
+        let position_id = gauge_position_count_store
+            .get_at(
+                delta.ordinal,
+                StoreKey::gauge_user_position_count_key(gauge_address, user_address),
+            )
+            .unwrap_or_default();
+
+        // Balances that have never been positive do not belong to any position.
+        if position_id == 0 {
+            continue;
+        }
+
+        let position_key = EntityKey::gauge_position_key(gauge_address, user_address, &position_id);
+        let is_opened = delta.old_value <= BigInt::zero() && delta.new_value > BigInt::zero();
+        let is_closed = delta.old_value > BigInt::zero() && delta.new_value <= BigInt::zero();
+
+        if is_opened {
+            let pool_address = gauge_store
+                .get_last(StoreKey::liquidity_gauge_key(gauge_address))
+                .map(|gauge| format::format_address_string(&gauge.pool))
+                .unwrap_or_default();
+
+            tables
+                .create_row("GaugePosition", position_key)
+                .set("gauge", format::format_address_string(gauge_address))
+                .set("pool", pool_address)
+                .set("user", format::format_address_string(user_address))
+                .set("balance", delta.new_value.clone())
+                .set("timestampOpened", timestamp.clone())
+                .set("blockNumberOpened", block_number.clone());
+        } else if is_closed {
+            tables
+                .update_row("GaugePosition", position_key)
+                .set("balance", BigInt::zero())
+                .set("timestampClosed", timestamp.clone())
+                .set("blockNumberClosed", block_number.clone());
+        } else if delta.new_value > BigInt::zero() {
+            tables
+                .update_row("GaugePosition", position_key)
+                .set("balance", delta.new_value.clone());
+        }
     }
 }
 
@@ -339,6 +1325,11 @@ fn create_pool_entity(tables: &mut Tables, pool: &Pool, pool_fees: &PoolFees) {
         .iter()
         .map(|t| format_address_string(&t))
         .collect();
+    let input_token_asset_types: Vec<String> = pool
+        .input_tokens
+        .iter()
+        .map(|t| t.asset_type().as_str_name().to_string())
+        .collect();
 
     // There is no liquidity when a Pool is first deployed, so we set the balances and weights to zero.
     let input_token_balances = vec![BigInt::zero(); input_token_addresses.len()];
@@ -354,6 +1345,7 @@ fn create_pool_entity(tables: &mut Tables, pool: &Pool, pool_fees: &PoolFees) {
         .set("symbol", &pool.symbol)
         .set("inputTokens", input_token_addresses)
         .set("_inputTokensOrdered", input_tokens_ordered)
+        .set("_inputTokenAssetTypes", input_token_asset_types)
         .set(
             "outputToken",
             format::format_address_string(&pool.output_token_ref().address),
@@ -375,6 +1367,12 @@ fn create_pool_entity(tables: &mut Tables, pool: &Pool, pool_fees: &PoolFees) {
         .set("outputTokenSupply", BigInt::zero())
         .set("outputTokenPriceUSD", BigDecimal::zero())
         .set("stakedOutputTokenAmount", BigInt::zero())
+        .set("_volume7dUSD", BigDecimal::zero())
+        .set("_volume30dUSD", BigDecimal::zero())
+        .set("_uniqueUsers7d", 0 as i32)
+        .set("_cumulativeCrvMinted", BigInt::zero())
+        .set("_baseRewardAPR", BigDecimal::zero())
+        .set("_maxBoostedRewardAPR", BigDecimal::zero())
         .set(
             "_registryAddress",
             format::format_address_string(&pool.registry_address),
@@ -383,6 +1381,7 @@ fn create_pool_entity(tables: &mut Tables, pool: &Pool, pool_fees: &PoolFees) {
             "_gaugeAddress",
             format::format_address_vec(&NULL_ADDRESS.to_vec()),
         )
+        .set("_poolType", pool.pool_type_name())
         .set("_isMetapool", pool_utils::is_metapool(&pool));
 }
 
@@ -452,6 +1451,7 @@ fn create_pool_events_entities(
     tables: &mut Tables,
     pool_events: Vec<PoolEvent>,
     pools_store: &StoreGetProto<Pool>,
+    pool_fees_store: &StoreGetProto<PoolFees>,
     output_token_supply_store: &StoreGetBigInt,
     input_token_balances_store: &StoreGetBigInt,
     pool_tvl_store: &StoreGetBigDecimal,
@@ -498,7 +1498,18 @@ fn create_pool_events_entities(
                     if let Some(pool) =
                         pools_store.get_last(StoreKey::pool_key(&event.pool_address))
                     {
-                        create_swap_entity(tables, &event, &swap);
+                        let fee_percentage = pool_fees_store
+                            .get_last(StoreKey::pool_fees_key(&pool.address))
+                            .map(|pool_fees| {
+                                pool_utils::get_swap_effective_fee_percentage(
+                                    &pool,
+                                    &pool_fees,
+                                    &swap,
+                                    event.log_ordinal,
+                                    input_token_balances_store,
+                                )
+                            });
+                        create_swap_entity(tables, &event, &swap, fee_percentage);
                         update_input_token_balances(
                             tables,
                             &event,
@@ -738,8 +1749,13 @@ fn create_withdraw_entity(
         .set("pool", format::format_address_string(&event.pool_address));
 }
 
-fn create_swap_entity(tables: &mut Tables, event: &PoolEvent, swap: &SwapEvent) {
-    tables
+fn create_swap_entity(
+    tables: &mut Tables,
+    event: &PoolEvent,
+    swap: &SwapEvent,
+    fee_percentage: Option<BigDecimal>,
+) {
+    let row = tables
         .create_row(
             "Swap",
             EntityKey::swap_key(&event.transaction_hash, &event.log_index),
@@ -767,6 +1783,10 @@ fn create_swap_entity(tables: &mut Tables, event: &PoolEvent, swap: &SwapEvent)
         .set("amountOut", swap.token_out_ref().amount_big())
         .set("amountOutUSD", swap.token_out_ref().amount_usd_decimal())
         .set("pool", format::format_address_string(&event.pool_address));
+    // Effective fee charged on the swap, including any off-peg fee multiplier
+    if let Some(fee_percentage) = fee_percentage {
+        row.set("_feePercentage", fee_percentage);
+    }
 }
 
 fn create_swap_underlying_entity(
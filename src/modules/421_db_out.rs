@@ -0,0 +1,447 @@
+use std::{collections::HashSet, str::FromStr};
+
+use anyhow::anyhow;
+use substreams::{
+    errors::Error,
+    key,
+    pb::substreams::{store_delta::Operation, Clock},
+    scalar::{BigDecimal, BigInt},
+    store::{
+        DeltaProto, Deltas, StoreGet, StoreGetBigDecimal, StoreGetBigInt, StoreGetInt64,
+        StoreGetProto,
+    },
+};
+use substreams_database_change::{pb::database::DatabaseChanges, tables::Tables};
+
+use crate::{
+    common::{format::format_address_string, pool_utils::get_input_token_balances},
+    key_management::{entity_key_manager::EntityKey, store_key_manager::StoreKey},
+    pb::curve::types::v1::{
+        events::{
+            pool_event::{TokenAmount, Type},
+            PoolEvent,
+        },
+        CurveEvents, Events, GaugeType, LiquidityGauge, LiquidityGaugeEvents, Pool, PoolFees,
+        Token,
+    },
+    timeframe_management::utils::calculate_day_hour_id,
+};
+
+// Emits the same pool, token, event, fee and gauge data as `graph_out`, as rows for the SQL sink.
+// Row IDs match the subgraph entity IDs, so both outputs can be joined on them.
+// Rows are upserted with every column set, as the ClickHouse engines of `schema.clickhouse.sql`
+// replace whole rows rather than merging columns.
+#[substreams::handlers::map]
+pub fn db_out(
+    clock: Clock,
+    events: CurveEvents,
+    pool_events: Events,
+    gauge_events: LiquidityGaugeEvents,
+    pools_store: StoreGetProto<Pool>,
+    pool_fees_deltas: Deltas<DeltaProto<PoolFees>>,
+    tokens_store: StoreGetInt64,
+    output_token_supply_store: StoreGetBigInt,
+    input_token_balances_store: StoreGetBigInt,
+    pool_volume_usd_store: StoreGetBigDecimal,
+    pool_tvl_store: StoreGetBigDecimal,
+    gauge_store: StoreGetProto<LiquidityGauge>,
+    gauge_status_store: StoreGetInt64,
+    gauge_staked_supply_store: StoreGetBigInt,
+) -> Result<DatabaseChanges, Error> {
+    let mut tables = Tables::new();
+
+    // Create rows for the tokens of newly deployed pools
+    for pool in events.pools.iter() {
+        create_token_rows(&mut tables, pool, &tokens_store)?;
+    }
+
+    // Covers both the fees fetched at pool creation and later fee changes.
+    for delta in pool_fees_deltas.iter() {
+        if delta.operation == Operation::Delete {
+            continue;
+        }
+        // Key format: PoolFees:{pool}
+        let pool_address = key::last_segment(&delta.key);
+        upsert_pool_fee_rows(&mut tables, pool_address, &delta.new_value);
+    }
+
+    let mut gauge_addresses: HashSet<String> = HashSet::new();
+    gauge_addresses.extend(events.gauges.iter().map(|gauge| gauge.gauge.clone()));
+    gauge_addresses.extend(
+        events
+            .controller_gauges
+            .iter()
+            .map(|event| event.gauge.clone()),
+    );
+    gauge_addresses.extend(
+        gauge_events
+            .kill_status_events
+            .iter()
+            .map(|event| event.gauge.clone()),
+    );
+    gauge_addresses.extend(
+        gauge_events
+            .liquidity_events
+            .iter()
+            .map(|event| event.gauge.clone()),
+    );
+
+    // Only gauges indexed by `store_gauges` have a row
+    for gauge_address in gauge_addresses.iter() {
+        if let Some(gauge) = gauge_store.get_last(StoreKey::liquidity_gauge_key(gauge_address)) {
+            upsert_gauge_row(
+                &mut tables,
+                &gauge,
+                &gauge_status_store,
+                &gauge_staked_supply_store,
+            );
+        }
+    }
+
+    let active_pool_addresses: HashSet<String> = pool_events
+        .pool_events
+        .iter()
+        .map(|event| event.pool_address.clone())
+        .collect();
+
+    for event in pool_events.pool_events {
+        create_pool_event_rows(&mut tables, &event, &pools_store);
+    }
+
+    let mut pool_addresses: HashSet<String> = events
+        .pools
+        .iter()
+        .map(|pool| pool.address.clone())
+        .collect();
+    pool_addresses.extend(active_pool_addresses.iter().cloned());
+
+    // Pool state is only refreshed for new pools and pools with activity in this block
+    let (day_id, _) = calculate_day_hour_id(clock.timestamp.as_ref().unwrap().seconds);
+    for pool_address in pool_addresses.iter() {
+        if let Some(pool) = pools_store.get_last(StoreKey::pool_key(pool_address)) {
+            upsert_pool_rows(
+                &mut tables,
+                &clock,
+                &pool,
+                day_id,
+                active_pool_addresses.contains(pool_address),
+                &output_token_supply_store,
+                &input_token_balances_store,
+                &pool_volume_usd_store,
+                &pool_tvl_store,
+            );
+        }
+    }
+
+    Ok(tables.to_database_changes())
+}
+
+fn upsert_pool_fee_rows(tables: &mut Tables, pool_address: &str, pool_fees: &PoolFees) {
+    for fee in [
+        pool_fees.trading_fee(),
+        pool_fees.protocol_fee(),
+        pool_fees.lp_fee(),
+    ] {
+        tables
+            .upsert_row("pool_fees", &fee.id)
+            .set("pool", format_address_string(pool_address))
+            .set("fee_type", fee.fee_type().as_str_name())
+            .set("fee_percentage", fee.fee_percentage_big_decimal());
+    }
+}
+
+fn upsert_gauge_row(
+    tables: &mut Tables,
+    gauge: &LiquidityGauge,
+    gauge_status_store: &StoreGetInt64,
+    gauge_staked_supply_store: &StoreGetBigInt,
+) {
+    let is_killed = gauge_status_store
+        .get_last(StoreKey::gauge_kill_status_key(&gauge.gauge))
+        .map_or(false, |is_killed| is_killed == 1);
+    let staked_supply = gauge_staked_supply_store
+        .get_last(StoreKey::gauge_staked_supply_key(&gauge.gauge))
+        .unwrap_or(BigInt::zero());
+
+    let row = tables
+        .upsert_row("gauges", EntityKey::liquidity_gauge_key(&gauge.gauge))
+        .set("pool", format_address_string(&gauge.pool))
+        .set("is_killed", is_killed)
+        .set("staked_supply", staked_supply)
+        .set("created_timestamp", gauge.created_at_timestamp)
+        .set("created_block_number", gauge.created_at_block_number);
+
+    // The type is only known once the gauge is added to the GaugeController
+    if let Some(gauge_type) = gauge_status_store
+        .get_last(StoreKey::gauge_type_key(&gauge.gauge))
+        .and_then(|gauge_type| GaugeType::from_i32(gauge_type as i32))
+    {
+        row.set("gauge_type", gauge_type.as_str_name());
+    }
+}
+
+fn create_token_rows(
+    tables: &mut Tables,
+    pool: &Pool,
+    tokens_store: &StoreGetInt64,
+) -> Result<(), Error> {
+    for token in pool.get_all_tokens() {
+        match tokens_store.get_at(pool.log_ordinal, StoreKey::token_key(&token.address)) {
+            // A count of one means this is the first pool the token has been seen in.
+            Some(count) => {
+                if count == 1 {
+                    upsert_token_row(tables, &token, BigDecimal::zero(), 0);
+                }
+            }
+            None => {
+                return Err(anyhow!(
+                    "Pool contains token with address {} that does not exist in the store",
+                    token.address
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn upsert_token_row(
+    tables: &mut Tables,
+    token: &Token,
+    last_price_usd: BigDecimal,
+    last_price_block_number: u64,
+) {
+    tables
+        .upsert_row("tokens", EntityKey::token_key(&token.address))
+        .set("name", &token.name)
+        .set("symbol", &token.symbol)
+        .set("decimals", token.decimals as i32)
+        .set("is_base_pool_lp_token", token.is_base_pool_lp_token)
+        .set("last_price_usd", last_price_usd)
+        .set("last_price_block_number", last_price_block_number);
+}
+
+fn create_pool_event_rows(
+    tables: &mut Tables,
+    event: &PoolEvent,
+    pools_store: &StoreGetProto<Pool>,
+) {
+    match &event.r#type {
+        Some(Type::DepositEvent(deposit)) => {
+            let output_token_amount = deposit
+                .output_token
+                .as_ref()
+                .map(|token| BigInt::from_str(&token.amount).unwrap_or_default())
+                .unwrap_or_default();
+            create_liquidity_event_row(
+                tables,
+                "deposits",
+                EntityKey::deposit_key(&event.transaction_hash, &event.log_index),
+                event,
+                &deposit.input_tokens,
+                output_token_amount,
+            );
+        }
+        Some(Type::WithdrawEvent(withdraw)) => {
+            let output_token_amount = withdraw
+                .output_token
+                .as_ref()
+                .map(|token| BigInt::from_str(&token.amount).unwrap_or_default())
+                .unwrap_or_default();
+            create_liquidity_event_row(
+                tables,
+                "withdraws",
+                EntityKey::withdraw_key(&event.transaction_hash, &event.log_index),
+                event,
+                &withdraw.input_tokens,
+                output_token_amount,
+            );
+        }
+        Some(Type::SwapEvent(swap)) => {
+            create_swap_row(tables, event, swap.token_in_ref(), swap.token_out_ref());
+            update_token_price_row(tables, event, swap.token_in_ref(), pools_store);
+            update_token_price_row(tables, event, swap.token_out_ref(), pools_store);
+        }
+        Some(Type::SwapUnderlyingMetaEvent(swap_underlying)) => {
+            create_swap_row(
+                tables,
+                event,
+                swap_underlying.token_in_ref(),
+                swap_underlying.token_out_ref(),
+            );
+        }
+        Some(Type::SwapUnderlyingLendingEvent(swap_underlying)) => {
+            create_swap_row(
+                tables,
+                event,
+                swap_underlying.token_in_ref(),
+                swap_underlying.token_out_ref(),
+            );
+        }
+        None => {}
+    }
+}
+
+// Deposits and withdraws touch a variable number of tokens, so their addresses and amounts are
+// stored as comma separated lists in pool token order.
+fn create_liquidity_event_row(
+    tables: &mut Tables,
+    table: &str,
+    id: String,
+    event: &PoolEvent,
+    input_tokens: &Vec<TokenAmount>,
+    output_token_amount: BigInt,
+) {
+    let mut total_amount_usd = BigDecimal::zero();
+    for token in input_tokens.iter() {
+        total_amount_usd = total_amount_usd + token.amount_usd_decimal();
+    }
+    let token_addresses: Vec<String> = input_tokens
+        .iter()
+        .map(|token| format_address_string(&token.token_address))
+        .collect();
+    let token_amounts: Vec<String> = input_tokens
+        .iter()
+        .map(|token| token.amount_big().to_string())
+        .collect();
+
+    tables
+        .create_row(table, id)
+        .set("pool", format_address_string(&event.pool_address))
+        .set("provider", format_address_string(&event.from_address))
+        .set("input_tokens", token_addresses.join(","))
+        .set("input_token_amounts", token_amounts.join(","))
+        .set("output_token_amount", output_token_amount)
+        .set("amount_usd", total_amount_usd)
+        .set(
+            "transaction_hash",
+            format_address_string(&event.transaction_hash),
+        )
+        .set("log_index", event.log_index)
+        .set("block_number", event.block_number)
+        .set("timestamp", event.timestamp);
+}
+
+fn create_swap_row(
+    tables: &mut Tables,
+    event: &PoolEvent,
+    token_in: &TokenAmount,
+    token_out: &TokenAmount,
+) {
+    tables
+        .create_row(
+            "swaps",
+            EntityKey::swap_key(&event.transaction_hash, &event.log_index),
+        )
+        .set("pool", format_address_string(&event.pool_address))
+        .set("trader", format_address_string(&event.from_address))
+        .set("token_in", format_address_string(&token_in.token_address))
+        .set("amount_in", token_in.amount_big())
+        .set("amount_in_usd", token_in.amount_usd_decimal())
+        .set("token_out", format_address_string(&token_out.token_address))
+        .set("amount_out", token_out.amount_big())
+        .set("amount_out_usd", token_out.amount_usd_decimal())
+        .set(
+            "transaction_hash",
+            format_address_string(&event.transaction_hash),
+        )
+        .set("log_index", event.log_index)
+        .set("block_number", event.block_number)
+        .set("timestamp", event.timestamp);
+}
+
+fn update_token_price_row(
+    tables: &mut Tables,
+    event: &PoolEvent,
+    token_amount: &TokenAmount,
+    pools_store: &StoreGetProto<Pool>,
+) {
+    let token = pools_store
+        .get_last(StoreKey::pool_key(&event.pool_address))
+        .and_then(|pool| pool.find_token(&token_amount.token_address).cloned());
+    if let Some(token) = token {
+        let price = BigDecimal::from_str(&token_amount.token_price).unwrap_or(BigDecimal::zero());
+        upsert_token_row(tables, &token, price, event.block_number);
+    }
+}
+
+// Pool rows are written for new and active pools, and the daily snapshot for active pools only.
+fn upsert_pool_rows(
+    tables: &mut Tables,
+    clock: &Clock,
+    pool: &Pool,
+    day_id: i64,
+    is_active: bool,
+    output_token_supply_store: &StoreGetBigInt,
+    input_token_balances_store: &StoreGetBigInt,
+    pool_volume_usd_store: &StoreGetBigDecimal,
+    pool_tvl_store: &StoreGetBigDecimal,
+) {
+    let output_token_supply = output_token_supply_store
+        .get_last(StoreKey::output_token_supply_key(&pool.address))
+        .unwrap_or(BigInt::zero());
+    let tvl = pool_tvl_store
+        .get_last(StoreKey::pool_tvl_key(&pool.address))
+        .unwrap_or(BigDecimal::zero());
+    let cumulative_volume = pool_volume_usd_store
+        .get_last(StoreKey::pool_volume_usd_key(&pool.address))
+        .unwrap_or(BigDecimal::zero());
+
+    tables
+        .upsert_row("pools", EntityKey::liquidity_pool_key(&pool.address))
+        .set("name", &pool.name)
+        .set("symbol", &pool.symbol)
+        .set("pool_type", pool.pool_type_name())
+        .set(
+            "output_token",
+            format_address_string(&pool.output_token_ref().address),
+        )
+        .set("registry", format_address_string(&pool.registry_address))
+        .set("total_value_locked_usd", tvl.clone())
+        .set("cumulative_volume_usd", cumulative_volume.clone())
+        .set("output_token_supply", output_token_supply.clone())
+        .set("created_timestamp", pool.created_at_timestamp)
+        .set("created_block_number", pool.created_at_block_number);
+
+    let input_token_balances = get_input_token_balances(
+        &pool.address,
+        &pool.input_tokens,
+        input_token_balances_store,
+    );
+    for (token, balance) in pool.input_tokens.iter().zip(input_token_balances) {
+        tables
+            .upsert_row(
+                "pool_tokens",
+                EntityKey::pool_token_key(&pool.address, &token.address),
+            )
+            .set("pool", format_address_string(&pool.address))
+            .set("token", format_address_string(&token.address))
+            .set(
+                "token_index",
+                token.index.parse::<i32>().unwrap_or_default(),
+            )
+            .set("balance", balance);
+    }
+
+    if !is_active {
+        return;
+    }
+
+    let daily_volume = pool_volume_usd_store
+        .get_last(StoreKey::pool_volume_usd_daily_key(&day_id, &pool.address))
+        .unwrap_or(BigDecimal::zero());
+
+    // The snapshot is overwritten by each block with activity, so it holds the end of day state.
+    tables
+        .upsert_row(
+            "pool_daily_snapshots",
+            EntityKey::pool_daily_snapshot_key(&pool.address, &day_id),
+        )
+        .set("pool", format_address_string(&pool.address))
+        .set("day", day_id)
+        .set("total_value_locked_usd", tvl)
+        .set("daily_volume_usd", daily_volume)
+        .set("cumulative_volume_usd", cumulative_volume)
+        .set("output_token_supply", output_token_supply)
+        .set("block_number", clock.number)
+        .set("timestamp", clock.timestamp.as_ref().unwrap().seconds);
+}
@@ -0,0 +1,100 @@
+use substreams::{
+    pb::substreams::Clock,
+    scalar::BigDecimal,
+    store::{
+        DeltaInt64, Deltas, StoreAdd, StoreAddBigDecimal, StoreGet, StoreGetBigInt, StoreGetProto,
+        StoreNew,
+    },
+};
+
+use crate::{
+    common::pool_utils::get_swap_effective_fee_percentage,
+    key_management::store_key_manager::StoreKey,
+    pb::curve::types::v1::{events::pool_event::Type, Events, Pool, PoolFees},
+    timeframe_management::{
+        pruning::{
+            pruners::pool_revenue_usd_pruner::PoolRevenueUsdPruner, setup_timeframe_pruning,
+        },
+        utils::{calculate_day_hour_id, calculate_week_month_id},
+    },
+    types::pool_fees::RevenueSide,
+};
+
+// Tracks the USD revenue generated by swap fees for each pool, split between the protocol (admin fee)
+// and liquidity providers. Revenue is kept both cumulatively and per timeframe for pool snapshots.
+#[substreams::handlers::store]
+pub fn store_pool_revenue(
+    clock: Clock,
+    events: Events,
+    pools_store: StoreGetProto<Pool>,
+    pool_fees_store: StoreGetProto<PoolFees>,
+    input_token_balances_store: StoreGetBigInt,
+    current_time_deltas: Deltas<DeltaInt64>,
+    output_store: StoreAddBigDecimal,
+) {
+    let pool_revenue_usd_pruner = PoolRevenueUsdPruner {
+        store: &output_store,
+    };
+    setup_timeframe_pruning(&current_time_deltas, &[&pool_revenue_usd_pruner]);
+
+    let timestamp = clock.timestamp.unwrap().seconds;
+    let (day_id, hour_id) = calculate_day_hour_id(timestamp);
+    let (week_id, month_id) = calculate_week_month_id(timestamp);
+
+    for event in events.pool_events {
+        let swap = match &event.r#type {
+            Some(Type::SwapEvent(swap)) => swap,
+            _ => continue,
+        };
+        let pool = pools_store.must_get_last(StoreKey::pool_key(&event.pool_address));
+        let pool_fees = match pool_fees_store.get_last(StoreKey::pool_fees_key(&pool.address)) {
+            Some(pool_fees) => pool_fees,
+            None => continue,
+        };
+
+        let fee = get_swap_effective_fee_percentage(
+            &pool,
+            &pool_fees,
+            swap,
+            event.log_ordinal,
+            &input_token_balances_store,
+        ) / BigDecimal::from(100);
+        if fee == BigDecimal::zero() || fee >= BigDecimal::one() {
+            continue;
+        }
+
+        // Fees are deducted from the output amount, which is reported net of fees.
+        let total_revenue =
+            swap.token_out_ref().amount_usd_decimal() * fee.clone() / (BigDecimal::one() - fee);
+
+        // The admin fee is the share of the fee taken by the protocol, and is unaffected by the
+        // off-peg multiplier.
+        let trading_fee = pool_fees.trading_fee().fee_percentage_big_decimal();
+        let protocol_revenue = if trading_fee == BigDecimal::zero() {
+            BigDecimal::zero()
+        } else {
+            total_revenue.clone() * pool_fees.protocol_fee().fee_percentage_big_decimal()
+                / trading_fee
+        };
+
+        let supply_revenue = total_revenue.clone() - protocol_revenue.clone();
+
+        for (side, revenue) in [
+            (RevenueSide::Total, total_revenue),
+            (RevenueSide::ProtocolSide, protocol_revenue),
+            (RevenueSide::SupplySide, supply_revenue),
+        ] {
+            output_store.add_many(
+                event.log_ordinal,
+                &vec![
+                    StoreKey::pool_revenue_usd_key(&side, &event.pool_address),
+                    StoreKey::pool_revenue_usd_daily_key(&day_id, &side, &event.pool_address),
+                    StoreKey::pool_revenue_usd_hourly_key(&hour_id, &side, &event.pool_address),
+                    StoreKey::pool_revenue_usd_weekly_key(&week_id, &side, &event.pool_address),
+                    StoreKey::pool_revenue_usd_monthly_key(&month_id, &side, &event.pool_address),
+                ],
+                &revenue,
+            );
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use substreams::store::{StoreNew, StoreSet, StoreSetProto};
+
+use crate::{
+    key_management::store_key_manager::StoreKey,
+    pb::curve::types::v1::{CrvUsdMarket, CurveEvents},
+};
+
+// Markets are stored under both their Controller and LLAMMA addresses, as each emits its own events.
+#[substreams::handlers::store]
+pub fn store_crvusd_markets(events: CurveEvents, store: StoreSetProto<CrvUsdMarket>) {
+    for market in events.crvusd_markets {
+        store.set(
+            market.log_ordinal,
+            StoreKey::crvusd_market_key(&market.controller),
+            &market,
+        );
+        store.set(
+            market.log_ordinal,
+            StoreKey::crvusd_market_amm_key(&market.amm),
+            &market,
+        );
+    }
+}
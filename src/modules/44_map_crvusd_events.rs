@@ -0,0 +1,194 @@
+use substreams::{
+    errors::Error,
+    scalar::BigInt,
+    store::{StoreGet, StoreGetProto},
+    Hex,
+};
+use substreams_ethereum::{
+    pb::eth::v2::{self as eth, Log, TransactionTrace},
+    Event,
+};
+
+use crate::{
+    abi::curve::crvusd::{llamma, peg_keeper},
+    common::controller_events,
+    key_management::store_key_manager::StoreKey,
+    network_config::CRVUSD_PEG_KEEPERS,
+    pb::curve::types::v1::{
+        llamma_liquidity_event::LiquidityEventType, peg_keeper_event::PegKeeperEventType,
+        CrvUsdEvents, CrvUsdMarket, LlammaExchangeEvent, LlammaLiquidityEvent, PegKeeperEvent,
+    },
+};
+
+#[substreams::handlers::map]
+pub fn map_crvusd_events(
+    blk: eth::Block,
+    crvusd_markets_store: StoreGetProto<CrvUsdMarket>,
+) -> Result<CrvUsdEvents, Error> {
+    let mut crvusd_events = CrvUsdEvents::default();
+
+    for trx in blk.transactions() {
+        for (log, _) in trx.logs_with_calls() {
+            if CRVUSD_PEG_KEEPERS
+                .iter()
+                .any(|peg_keeper| log.address == *peg_keeper)
+            {
+                if let Some(provide) = peg_keeper::events::Provide::match_and_decode(&log) {
+                    crvusd_events
+                        .peg_keeper_events
+                        .push(create_peg_keeper_event(
+                            PegKeeperEventType::Provide,
+                            provide.amount.to_string(),
+                            &trx,
+                            &blk,
+                            &log,
+                        ));
+                }
+                if let Some(withdraw) = peg_keeper::events::Withdraw::match_and_decode(&log) {
+                    crvusd_events
+                        .peg_keeper_events
+                        .push(create_peg_keeper_event(
+                            PegKeeperEventType::Withdraw,
+                            withdraw.amount.to_string(),
+                            &trx,
+                            &blk,
+                            &log,
+                        ));
+                }
+                continue;
+            }
+
+            let address = Hex::encode(&log.address);
+
+            if let Some(market) =
+                crvusd_markets_store.get_last(StoreKey::crvusd_market_key(&address))
+            {
+                controller_events::extract_controller_events(
+                    &market.controller,
+                    &trx,
+                    &blk,
+                    &log,
+                    &mut crvusd_events.controller_loan_events,
+                    &mut crvusd_events.controller_liquidation_events,
+                );
+                continue;
+            }
+
+            if let Some(market) =
+                crvusd_markets_store.get_last(StoreKey::crvusd_market_amm_key(&address))
+            {
+                handle_llamma_events(&market, &trx, &blk, &log, &mut crvusd_events);
+            }
+        }
+    }
+
+    Ok(crvusd_events)
+}
+
+fn handle_llamma_events(
+    market: &CrvUsdMarket,
+    trx: &TransactionTrace,
+    blk: &eth::Block,
+    log: &Log,
+    crvusd_events: &mut CrvUsdEvents,
+) {
+    if let Some(exchange) = llamma::events::TokenExchange::match_and_decode(log) {
+        let sold_id = exchange.sold_id.to_u64() as u32;
+        let bought_id = exchange.bought_id.to_u64() as u32;
+        crvusd_events
+            .llamma_exchange_events
+            .push(LlammaExchangeEvent {
+                amm: market.amm.clone(),
+                controller: market.controller.clone(),
+                buyer: Hex::encode(&exchange.buyer),
+                sold_id,
+                sold_token: get_llamma_coin(market, sold_id),
+                tokens_sold: exchange.tokens_sold.to_string(),
+                bought_id,
+                bought_token: get_llamma_coin(market, bought_id),
+                tokens_bought: exchange.tokens_bought.to_string(),
+                transaction_hash: Hex::encode(&trx.hash),
+                tx_index: trx.index,
+                log_index: log.index,
+                log_ordinal: log.ordinal,
+                timestamp: blk.timestamp_seconds(),
+                block_number: blk.number,
+            });
+        return;
+    }
+
+    let liquidity_event = if let Some(deposit) = llamma::events::Deposit::match_and_decode(log) {
+        Some((
+            deposit.provider,
+            LiquidityEventType::Deposit,
+            deposit.amount,
+            BigInt::zero(),
+            Some((deposit.n1.to_i32(), deposit.n2.to_i32())),
+        ))
+    } else if let Some(withdraw) = llamma::events::Withdraw::match_and_decode(log) {
+        Some((
+            withdraw.provider,
+            LiquidityEventType::Withdraw,
+            withdraw.amount_collateral,
+            withdraw.amount_borrowed,
+            None,
+        ))
+    } else {
+        None
+    };
+
+    if let Some((provider, liquidity_event_type, amount_collateral, amount_borrowed, bands)) =
+        liquidity_event
+    {
+        crvusd_events
+            .llamma_liquidity_events
+            .push(LlammaLiquidityEvent {
+                amm: market.amm.clone(),
+                controller: market.controller.clone(),
+                provider: Hex::encode(&provider),
+                r#type: liquidity_event_type as i32,
+                amount_collateral: amount_collateral.to_string(),
+                amount_borrowed: amount_borrowed.to_string(),
+                n1: bands.map(|(n1, _)| n1),
+                n2: bands.map(|(_, n2)| n2),
+                transaction_hash: Hex::encode(&trx.hash),
+                tx_index: trx.index,
+                log_index: log.index,
+                log_ordinal: log.ordinal,
+                timestamp: blk.timestamp_seconds(),
+                block_number: blk.number,
+            });
+    }
+}
+
+fn create_peg_keeper_event(
+    peg_keeper_event_type: PegKeeperEventType,
+    amount: String,
+    trx: &TransactionTrace,
+    blk: &eth::Block,
+    log: &Log,
+) -> PegKeeperEvent {
+    PegKeeperEvent {
+        peg_keeper: Hex::encode(&log.address),
+        r#type: peg_keeper_event_type as i32,
+        amount,
+        transaction_hash: Hex::encode(&trx.hash),
+        tx_index: trx.index,
+        log_index: log.index,
+        log_ordinal: log.ordinal,
+        timestamp: blk.timestamp_seconds(),
+        block_number: blk.number,
+    }
+}
+
+// crvUSD is always coin 0 of the LLAMMA, and the market's collateral coin 1.
+fn get_llamma_coin(market: &CrvUsdMarket, coin_id: u32) -> String {
+    match coin_id {
+        0 => market.stablecoin.clone(),
+        _ => market
+            .collateral_token
+            .as_ref()
+            .map(|token| token.address.clone())
+            .unwrap_or_default(),
+    }
+}
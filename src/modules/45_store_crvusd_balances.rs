@@ -0,0 +1,100 @@
+use substreams::store::{StoreAdd, StoreAddBigInt, StoreNew};
+
+use crate::{
+    key_management::store_key_manager::StoreKey,
+    pb::curve::types::v1::{
+        controller_loan_event::LoanEventType, llamma_liquidity_event::LiquidityEventType,
+        peg_keeper_event::PegKeeperEventType, CrvUsdEvents,
+    },
+};
+
+// Tracks the collateral and crvUSD held by each market's LLAMMA, along with the cumulative amounts
+// borrowed, repaid and liquidated through its Controller, and the debt of each PegKeeper.
+#[substreams::handlers::store]
+pub fn store_crvusd_balances(events: CrvUsdEvents, store: StoreAddBigInt) {
+    for event in events.llamma_liquidity_events {
+        match event.r#type() {
+            LiquidityEventType::Deposit => {
+                store.add(
+                    event.log_ordinal,
+                    StoreKey::crvusd_market_collateral_key(&event.controller),
+                    event.parse_amount_collateral(),
+                );
+            }
+            LiquidityEventType::Withdraw => {
+                store.add(
+                    event.log_ordinal,
+                    StoreKey::crvusd_market_collateral_key(&event.controller),
+                    event.parse_amount_collateral().neg(),
+                );
+                store.add(
+                    event.log_ordinal,
+                    StoreKey::crvusd_market_stablecoin_key(&event.controller),
+                    event.parse_amount_borrowed().neg(),
+                );
+            }
+        }
+    }
+
+    // Soft-liquidation trades move the LLAMMA's holdings between crvUSD (coin 0) and collateral.
+    for event in events.llamma_exchange_events {
+        let (sold_key, bought_key) = if event.sold_id == 0 {
+            (
+                StoreKey::crvusd_market_stablecoin_key(&event.controller),
+                StoreKey::crvusd_market_collateral_key(&event.controller),
+            )
+        } else {
+            (
+                StoreKey::crvusd_market_collateral_key(&event.controller),
+                StoreKey::crvusd_market_stablecoin_key(&event.controller),
+            )
+        };
+        store.add(event.log_ordinal, sold_key, event.parse_tokens_sold());
+        store.add(
+            event.log_ordinal,
+            bought_key,
+            event.parse_tokens_bought().neg(),
+        );
+    }
+
+    for event in events.controller_loan_events {
+        match event.r#type() {
+            LoanEventType::Borrow => store.add(
+                event.log_ordinal,
+                StoreKey::crvusd_market_borrowed_key(&event.controller),
+                event.parse_debt_amount(),
+            ),
+            LoanEventType::Repay => store.add(
+                event.log_ordinal,
+                StoreKey::crvusd_market_repaid_key(&event.controller),
+                event.parse_debt_amount(),
+            ),
+            LoanEventType::RemoveCollateral => {}
+        }
+    }
+
+    for event in events.controller_liquidation_events {
+        store.add(
+            event.log_ordinal,
+            StoreKey::crvusd_market_liquidated_debt_key(&event.controller),
+            event.parse_debt(),
+        );
+        store.add(
+            event.log_ordinal,
+            StoreKey::crvusd_market_liquidated_collateral_key(&event.controller),
+            event.parse_collateral_received(),
+        );
+    }
+
+    for event in events.peg_keeper_events {
+        let amount = match event.r#type() {
+            PegKeeperEventType::Provide => event.parse_amount(),
+            PegKeeperEventType::Withdraw => event.parse_amount().neg(),
+        };
+        store.add(
+            event.log_ordinal,
+            StoreKey::peg_keeper_debt_key(&event.peg_keeper),
+            amount,
+        );
+    }
+}
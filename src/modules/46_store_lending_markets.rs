@@ -0,0 +1,23 @@
+use substreams::store::{StoreNew, StoreSet, StoreSetProto};
+
+use crate::{
+    key_management::store_key_manager::StoreKey,
+    pb::curve::types::v1::{CurveEvents, LendingMarket},
+};
+
+// Markets are stored under both their Controller and vault addresses, as each emits its own events.
+#[substreams::handlers::store]
+pub fn store_lending_markets(events: CurveEvents, store: StoreSetProto<LendingMarket>) {
+    for market in events.lending_markets {
+        store.set(
+            market.log_ordinal,
+            StoreKey::lending_market_key(&market.controller),
+            &market,
+        );
+        store.set(
+            market.log_ordinal,
+            StoreKey::lending_market_vault_key(&market.vault),
+            &market,
+        );
+    }
+}
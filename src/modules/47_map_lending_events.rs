@@ -0,0 +1,118 @@
+use std::collections::HashSet;
+
+use substreams::{
+    errors::Error,
+    store::{StoreGet, StoreGetProto},
+    Hex,
+};
+use substreams_ethereum::{
+    pb::eth::v2::{self as eth, Log, TransactionTrace},
+    Event,
+};
+
+use crate::{
+    abi::curve::lending::vault,
+    common::controller_events,
+    key_management::store_key_manager::StoreKey,
+    pb::curve::types::v1::{vault_event::VaultEventType, LendingEvents, LendingMarket, VaultEvent},
+    rpc,
+};
+
+#[substreams::handlers::map]
+pub fn map_lending_events(
+    blk: eth::Block,
+    lending_markets_store: StoreGetProto<LendingMarket>,
+) -> Result<LendingEvents, Error> {
+    let mut lending_events = LendingEvents::default();
+    // Markets are collected in the order they are first touched, to keep the output deterministic.
+    let mut active_markets: Vec<LendingMarket> = Vec::new();
+    let mut seen_markets: HashSet<String> = HashSet::new();
+
+    for trx in blk.transactions() {
+        for (log, _) in trx.logs_with_calls() {
+            let address = Hex::encode(&log.address);
+
+            let market = if let Some(market) =
+                lending_markets_store.get_last(StoreKey::lending_market_vault_key(&address))
+            {
+                handle_vault_events(&market, &trx, &blk, &log, &mut lending_events);
+                market
+            } else if let Some(market) =
+                lending_markets_store.get_last(StoreKey::lending_market_key(&address))
+            {
+                controller_events::extract_controller_events(
+                    &market.controller,
+                    &trx,
+                    &blk,
+                    &log,
+                    &mut lending_events.controller_loan_events,
+                    &mut lending_events.controller_liquidation_events,
+                );
+                market
+            } else {
+                continue;
+            };
+
+            if seen_markets.insert(market.controller.clone()) {
+                active_markets.push(market);
+            }
+        }
+    }
+
+    // Reads reflect the state at the end of the block, so each active market is only read once.
+    lending_events.market_states = active_markets
+        .iter()
+        .map(|market| rpc::lending::get_market_state(market, blk.timestamp_seconds(), blk.number))
+        .collect();
+
+    Ok(lending_events)
+}
+
+fn handle_vault_events(
+    market: &LendingMarket,
+    trx: &TransactionTrace,
+    blk: &eth::Block,
+    log: &Log,
+    lending_events: &mut LendingEvents,
+) {
+    let vault_event = if let Some(deposit) = vault::events::Deposit::match_and_decode(log) {
+        Some((
+            VaultEventType::Deposit,
+            deposit.sender,
+            deposit.owner,
+            None,
+            deposit.assets,
+            deposit.shares,
+        ))
+    } else if let Some(withdraw) = vault::events::Withdraw::match_and_decode(log) {
+        Some((
+            VaultEventType::Withdraw,
+            withdraw.sender,
+            withdraw.owner,
+            Some(withdraw.receiver),
+            withdraw.assets,
+            withdraw.shares,
+        ))
+    } else {
+        None
+    };
+
+    if let Some((vault_event_type, sender, owner, receiver, assets, shares)) = vault_event {
+        lending_events.vault_events.push(VaultEvent {
+            vault: market.vault.clone(),
+            controller: market.controller.clone(),
+            r#type: vault_event_type as i32,
+            sender: Hex::encode(&sender),
+            owner: Hex::encode(&owner),
+            receiver: receiver.map(Hex::encode).unwrap_or_default(),
+            assets: assets.to_string(),
+            shares: shares.to_string(),
+            transaction_hash: Hex::encode(&trx.hash),
+            tx_index: trx.index,
+            log_index: log.index,
+            log_ordinal: log.ordinal,
+            timestamp: blk.timestamp_seconds(),
+            block_number: blk.number,
+        });
+    }
+}
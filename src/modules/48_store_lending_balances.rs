@@ -0,0 +1,45 @@
+use substreams::store::{StoreAdd, StoreAddBigInt, StoreNew};
+
+use crate::{
+    key_management::store_key_manager::StoreKey,
+    pb::curve::types::v1::{
+        controller_loan_event::LoanEventType, vault_event::VaultEventType, LendingEvents,
+    },
+};
+
+// Tracks the cumulative amounts of the borrowed token deposited into and withdrawn from each vault,
+// along with the cumulative amounts borrowed, repaid and liquidated through its Controller.
+#[substreams::handlers::store]
+pub fn store_lending_balances(events: LendingEvents, store: StoreAddBigInt) {
+    for event in events.vault_events {
+        let key = match event.r#type() {
+            VaultEventType::Deposit => StoreKey::lending_market_deposited_key(&event.controller),
+            VaultEventType::Withdraw => StoreKey::lending_market_withdrawn_key(&event.controller),
+        };
+        store.add(event.log_ordinal, key, event.parse_assets());
+    }
+
+    for event in events.controller_loan_events {
+        match event.r#type() {
+            LoanEventType::Borrow => store.add(
+                event.log_ordinal,
+                StoreKey::lending_market_borrowed_key(&event.controller),
+                event.parse_debt_amount(),
+            ),
+            LoanEventType::Repay => store.add(
+                event.log_ordinal,
+                StoreKey::lending_market_repaid_key(&event.controller),
+                event.parse_debt_amount(),
+            ),
+            LoanEventType::RemoveCollateral => {}
+        }
+    }
+
+    for event in events.controller_liquidation_events {
+        store.add(
+            event.log_ordinal,
+            StoreKey::lending_market_liquidated_debt_key(&event.controller),
+            event.parse_debt(),
+        );
+    }
+}
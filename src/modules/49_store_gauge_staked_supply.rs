@@ -0,0 +1,58 @@
+use substreams::{
+    store::{StoreAdd, StoreAddBigInt, StoreNew},
+    Hex,
+};
+
+use crate::{
+    common::utils,
+    key_management::store_key_manager::StoreKey,
+    pb::curve::types::v1::{GaugeLiquidityEventType, LiquidityGaugeEvents, StakingWrapper},
+};
+
+// Tracks the total supply staked in each gauge, along with the share of it staked through the
+// Convex and Stake DAO wrapper contracts on behalf of their own depositors.
+#[substreams::handlers::store]
+pub fn store_gauge_staked_supply(gauge_events: LiquidityGaugeEvents, output_store: StoreAddBigInt) {
+    for event in gauge_events.liquidity_events {
+        let value = match event.r#type() {
+            GaugeLiquidityEventType::Deposit => event.parse_value(),
+            GaugeLiquidityEventType::Withdraw => event.parse_value().neg(),
+        };
+        output_store.add(
+            event.log_ordinal,
+            StoreKey::gauge_staked_supply_key(&event.gauge),
+            value.clone(),
+        );
+        if event.staking_wrapper() != StakingWrapper::Direct {
+            output_store.add(
+                event.log_ordinal,
+                StoreKey::gauge_wrapper_staked_supply_key(
+                    &event.gauge,
+                    event.staking_wrapper().as_str_name(),
+                ),
+                value,
+            );
+        }
+    }
+
+    // Transfers do not change the gauge supply, but may move tokens in or out of a wrapper.
+    for event in gauge_events.transfer_events {
+        let from_wrapper =
+            utils::get_staking_wrapper(&Hex::decode(&event.from).unwrap_or_default());
+        if from_wrapper != StakingWrapper::Direct {
+            output_store.add(
+                event.log_ordinal,
+                StoreKey::gauge_wrapper_staked_supply_key(&event.gauge, from_wrapper.as_str_name()),
+                event.parse_value().neg(),
+            );
+        }
+        let to_wrapper = utils::get_staking_wrapper(&Hex::decode(&event.to).unwrap_or_default());
+        if to_wrapper != StakingWrapper::Direct {
+            output_store.add(
+                event.log_ordinal,
+                StoreKey::gauge_wrapper_staked_supply_key(&event.gauge, to_wrapper.as_str_name()),
+                event.parse_value(),
+            );
+        }
+    }
+}
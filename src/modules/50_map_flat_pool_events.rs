@@ -0,0 +1,116 @@
+use substreams::{
+    errors::Error,
+    store::{StoreGet, StoreGetProto},
+};
+
+use crate::{
+    key_management::store_key_manager::StoreKey,
+    pb::curve::types::v1::{
+        events::{
+            pool_event::{TokenAmount, Type},
+            PoolEvent,
+        },
+        flat_pool_event::{Direction, EventType},
+        Events, FlatPoolEvent, FlatPoolEvents, Pool,
+    },
+};
+
+#[substreams::handlers::map]
+pub fn map_flat_pool_events(
+    events: Events,
+    pools_store: StoreGetProto<Pool>,
+) -> Result<FlatPoolEvents, Error> {
+    let mut flat_pool_events: Vec<FlatPoolEvent> = Vec::new();
+
+    for event in events.pool_events {
+        let pool = match pools_store.get_last(StoreKey::pool_key(&event.pool_address)) {
+            Some(pool) => pool,
+            None => continue,
+        };
+
+        let mut legs: Vec<(&TokenAmount, Direction)> = Vec::new();
+        let event_type = match &event.r#type {
+            Some(Type::SwapEvent(swap)) => {
+                legs.push((swap.token_in_ref(), Direction::In));
+                legs.push((swap.token_out_ref(), Direction::Out));
+                EventType::Swap
+            }
+            Some(Type::SwapUnderlyingMetaEvent(swap_underlying)) => {
+                legs.push((swap_underlying.token_in_ref(), Direction::In));
+                legs.push((swap_underlying.token_out_ref(), Direction::Out));
+                EventType::SwapUnderlyingMeta
+            }
+            Some(Type::SwapUnderlyingLendingEvent(swap_underlying)) => {
+                legs.push((swap_underlying.token_in_ref(), Direction::In));
+                legs.push((swap_underlying.token_out_ref(), Direction::Out));
+                EventType::SwapUnderlyingLending
+            }
+            Some(Type::DepositEvent(deposit)) => {
+                legs.extend(deposit.input_tokens.iter().map(|t| (t, Direction::In)));
+                // LP tokens are minted to the provider
+                if let Some(output_token) = &deposit.output_token {
+                    legs.push((output_token, Direction::Out));
+                }
+                EventType::Deposit
+            }
+            Some(Type::WithdrawEvent(withdraw)) => {
+                legs.extend(withdraw.input_tokens.iter().map(|t| (t, Direction::Out)));
+                // LP tokens are burned from the provider
+                if let Some(output_token) = &withdraw.output_token {
+                    legs.push((output_token, Direction::In));
+                }
+                EventType::Withdraw
+            }
+            None => continue,
+        };
+
+        for (token_amount, direction) in legs {
+            if let Some(flat_pool_event) =
+                create_flat_pool_event(&event, &pool, event_type, direction, token_amount)
+            {
+                flat_pool_events.push(flat_pool_event);
+            }
+        }
+    }
+
+    Ok(FlatPoolEvents { flat_pool_events })
+}
+
+fn create_flat_pool_event(
+    event: &PoolEvent,
+    pool: &Pool,
+    event_type: EventType,
+    direction: Direction,
+    token_amount: &TokenAmount,
+) -> Option<FlatPoolEvent> {
+    // Without the token's decimals the leg's amount can't be interpreted, so it is skipped.
+    let decimals = match pool.find_token(&token_amount.token_address) {
+        Some(token) => token.decimals,
+        None => {
+            substreams::log::debug!(
+                "Skipping leg of {} in pool {}: token not found in pool",
+                token_amount.token_address,
+                pool.address
+            );
+            return None;
+        }
+    };
+
+    Some(FlatPoolEvent {
+        pool_address: event.pool_address.clone(),
+        event_type: event_type as i32,
+        direction: direction as i32,
+        token_address: token_amount.token_address.clone(),
+        amount: token_amount.amount.clone(),
+        decimals,
+        amount_usd: token_amount.amount_usd.clone(),
+        token_price: token_amount.token_price.clone(),
+        price_source: token_amount.price_source,
+        user: event.from_address.clone(),
+        transaction_hash: event.transaction_hash.clone(),
+        log_index: event.log_index,
+        log_ordinal: event.log_ordinal,
+        timestamp: event.timestamp,
+        block_number: event.block_number,
+    })
+}
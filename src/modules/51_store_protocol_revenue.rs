@@ -0,0 +1,58 @@
+use std::ops::Sub;
+
+use substreams::{
+    key,
+    pb::substreams::Clock,
+    store::{DeltaBigDecimal, DeltaInt64, Deltas, StoreAdd, StoreAddBigDecimal, StoreNew},
+};
+
+use crate::{
+    key_management::store_key_manager::StoreKey,
+    timeframe_management::{
+        pruning::{
+            pruners::protocol_revenue_usd_pruner::ProtocolRevenueUsdPruner, setup_timeframe_pruning,
+        },
+        utils::{calculate_day_hour_id, calculate_week_month_id},
+    },
+    types::pool_fees::RevenueSide,
+};
+
+// Aggregates the cumulative revenue of each pool into protocol wide totals, per revenue side.
+#[substreams::handlers::store]
+pub fn store_protocol_revenue(
+    clock: Clock,
+    pool_revenue_deltas: Deltas<DeltaBigDecimal>,
+    current_time_deltas: Deltas<DeltaInt64>,
+    output_store: StoreAddBigDecimal,
+) {
+    let protocol_revenue_usd_pruner = ProtocolRevenueUsdPruner {
+        store: &output_store,
+    };
+    setup_timeframe_pruning(&current_time_deltas, &[&protocol_revenue_usd_pruner]);
+
+    let timestamp = clock.timestamp.unwrap().seconds;
+    let (day_id, hour_id) = calculate_day_hour_id(timestamp);
+    let (week_id, month_id) = calculate_week_month_id(timestamp);
+
+    for delta in pool_revenue_deltas.iter() {
+        // Only the cumulative pool revenue is aggregated, the timeframe keys hold the same amounts.
+        let side = match key::first_segment(&delta.key) {
+            "PoolTotalRevenueUsd" => RevenueSide::Total,
+            "PoolProtocolSideRevenueUsd" => RevenueSide::ProtocolSide,
+            "PoolSupplySideRevenueUsd" => RevenueSide::SupplySide,
+            _ => continue,
+        };
+        let revenue_diff = delta.new_value.clone().sub(delta.old_value.clone());
+        output_store.add_many(
+            delta.ordinal,
+            &vec![
+                StoreKey::protocol_revenue_usd_key(&side),
+                StoreKey::protocol_daily_revenue_usd_key(&day_id, &side),
+                StoreKey::protocol_hourly_revenue_usd_key(&hour_id, &side),
+                StoreKey::protocol_weekly_revenue_usd_key(&week_id, &side),
+                StoreKey::protocol_monthly_revenue_usd_key(&month_id, &side),
+            ],
+            revenue_diff,
+        );
+    }
+}
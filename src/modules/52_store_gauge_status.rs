@@ -0,0 +1,31 @@
+use substreams::store::{StoreNew, StoreSet, StoreSetInt64};
+
+use crate::{
+    key_management::store_key_manager::StoreKey,
+    pb::curve::types::v1::{CurveEvents, LiquidityGaugeEvents},
+};
+
+// Tracks the GaugeController type and the kill status of each gauge, so `db_out` can emit
+// complete gauge rows. Kill status is stored as 1 for killed and 0 for active gauges.
+#[substreams::handlers::store]
+pub fn store_gauge_status(
+    events: CurveEvents,
+    gauge_events: LiquidityGaugeEvents,
+    output_store: StoreSetInt64,
+) {
+    for new_gauge in events.controller_gauges {
+        output_store.set(
+            new_gauge.log_ordinal,
+            StoreKey::gauge_type_key(&new_gauge.gauge),
+            &(new_gauge.r#type as i64),
+        );
+    }
+
+    for event in gauge_events.kill_status_events {
+        output_store.set(
+            event.ordinal,
+            StoreKey::gauge_kill_status_key(&event.gauge),
+            &(event.is_killed as i64),
+        );
+    }
+}
@@ -22,7 +22,11 @@ use crate::{
         RemoveLiquidityOne4, RemoveLiquidityOne5, TokenExchange1, TokenExchange2, TokenExchange3,
         TokenExchangeUnderlying,
     },
-    common::{event_extraction, pool_utils::is_metapool, prices::get_token_usd_price},
+    common::{
+        event_extraction,
+        pool_utils::{is_crypto_pool, is_metapool},
+        prices::{get_pool_token_usd_price, get_token_usd_price},
+    },
     constants::ETH_ADDRESS,
     key_management::store_key_manager::StoreKey,
     pb::{
@@ -389,43 +393,34 @@ pub fn map_extract_pool_events(
                         &chainlink_prices,
                     );
                 } else if let Some(withdraw) = RemoveLiquidityOne2::match_and_decode(&log) {
-                    // TODO: This is a hacky work around for the issue caused by the similarity between `RemoveLiquidityOne2` and `RemoveLiquidityOne5`.
-                    //       If a `RemoveLiquidityOne5` event is emitted, it matches against `RemoveLiquidityOne2` due to them having the same event parameter types.
-                    //       I have messaged SF regarding this, and will be looking into it. If the issue is not fixed we will have to look at removing the reliance on
-                    //       a merged ABI for the Pools. This workaround should suffice for now. In essence, for `RemoveLiquidityOne5` events, the `token_supply` is being
-                    //       used as the `coin_amount` for the `RemoveLiquidityOne5` event.
-
-                    // If the `coin_amount` is less than 10, we can assume it is `RemoveLiquidityOne5` event, as this parameter slot is used for the `coin_index`.
-                    // It is incredibly unlikely that a `coin_amount` less than 10 will part of a withdrawal as this would be an incredibly low token amount.
-                    if &withdraw.coin_amount < &BigInt::from(10) {
-                        extract_withdraw_one_event(
-                            &mut pool_events,
-                            &blk,
-                            trx,
-                            log,
-                            &pool,
-                            withdraw.provider,
-                            withdraw.token_amount,
-                            // Using `token_supply` as `coin_amount` for `RemoveLiquidityOne5`
-                            withdraw.token_supply,
-                            &uniswap_prices,
-                            &chainlink_prices,
-                        );
+                    // CryptoSwap pools emit `RemoveLiquidityOne5`, which shares its topic and
+                    // parameter types with `RemoveLiquidityOne2` and is therefore always decoded as
+                    // the latter. For these pools the `coin_amount` slot holds the `coin_index`, and
+                    // the withdrawn amount is found in the `token_supply` slot.
+                    let coin_amount = if is_crypto_pool(&pool) {
+                        withdraw.token_supply
+                    } else if matches!(pool.pool_type, Some(PoolType::WildcardPool(_)) | None)
+                        && withdraw.coin_amount < BigInt::from(10)
+                    {
+                        // Wildcard pools may implement either layout. A `coin_amount` below 10
+                        // is assumed to be a `coin_index`, as withdrawals of so few base units
+                        // are not expected.
+                        withdraw.token_supply
                     } else {
-                        // Extracting using the normal `RemoveLiquidityOne2` event params
-                        extract_withdraw_one_event(
-                            &mut pool_events,
-                            &blk,
-                            trx,
-                            log,
-                            &pool,
-                            withdraw.provider,
-                            withdraw.token_amount,
-                            withdraw.coin_amount,
-                            &uniswap_prices,
-                            &chainlink_prices,
-                        );
-                    }
+                        withdraw.coin_amount
+                    };
+                    extract_withdraw_one_event(
+                        &mut pool_events,
+                        &blk,
+                        trx,
+                        log,
+                        &pool,
+                        withdraw.provider,
+                        withdraw.token_amount,
+                        coin_amount,
+                        &uniswap_prices,
+                        &chainlink_prices,
+                    );
                 } else if let Some(withdraw) = RemoveLiquidityOne3::match_and_decode(&log) {
                     extract_withdraw_one_event(
                         &mut pool_events,
@@ -476,6 +471,7 @@ pub fn map_extract_pool_events(
                         fee: fee_change.fee.to_string(),
                         admin_fee: None,
                         pool_address: pool.address.clone(),
+                        offpeg_fee_multiplier: None,
                     });
                 } else if let Some(fee_change) = ApplyNewFee2::match_and_decode(&log) {
                     fee_change_events.push(FeeChangeEvent {
@@ -488,6 +484,7 @@ pub fn map_extract_pool_events(
                         fee: fee_change.fee.to_string(),
                         admin_fee: None,
                         pool_address: pool.address.clone(),
+                        offpeg_fee_multiplier: Some(fee_change.offpeg_fee_multiplier.to_string()),
                     });
                 } else if let Some(fee_change) = NewFee1::match_and_decode(&log) {
                     fee_change_events.push(FeeChangeEvent {
@@ -500,6 +497,7 @@ pub fn map_extract_pool_events(
                         fee: fee_change.fee.to_string(),
                         admin_fee: Some(fee_change.admin_fee.to_string()),
                         pool_address: pool.address.clone(),
+                        offpeg_fee_multiplier: None,
                     });
                 } else if let Some(fee_change) = NewFee2::match_and_decode(&log) {
                     fee_change_events.push(FeeChangeEvent {
@@ -512,6 +510,7 @@ pub fn map_extract_pool_events(
                         fee: fee_change.fee.to_string(),
                         admin_fee: Some(fee_change.admin_fee.to_string()),
                         pool_address: pool.address.clone(),
+                        offpeg_fee_multiplier: Some(fee_change.offpeg_fee_multiplier.to_string()),
                     });
                 } else if let Some(fee_change) = NewParameters1::match_and_decode(&log) {
                     fee_change_events.push(FeeChangeEvent {
@@ -524,6 +523,7 @@ pub fn map_extract_pool_events(
                         fee: fee_change.fee.to_string(),
                         admin_fee: Some(fee_change.admin_fee.to_string()),
                         pool_address: pool.address.clone(),
+                        offpeg_fee_multiplier: None,
                     });
                 } else if let Some(_fee_change) = NewParameters2::match_and_decode(&log) {
                     let (total_fee, admin_fee) = get_pool_fee_and_admin_fee(&pool.address_vec())?;
@@ -538,6 +538,7 @@ pub fn map_extract_pool_events(
                         fee: total_fee.to_string(),
                         admin_fee: Some(admin_fee.to_string()),
                         pool_address: pool.address.clone(),
+                        offpeg_fee_multiplier: None,
                     });
                 } else if let Some(_fee_change) = NewParameters3::match_and_decode(&log) {
                     let (total_fee, admin_fee) = get_pool_fee_and_admin_fee(&pool.address_vec())?;
@@ -552,6 +553,7 @@ pub fn map_extract_pool_events(
                         fee: total_fee.to_string(),
                         admin_fee: Some(admin_fee.to_string()),
                         pool_address: pool.address.clone(),
+                        offpeg_fee_multiplier: None,
                     });
                 } else if let Some(_fee_change) = NewParameters4::match_and_decode(&log) {
                     let (total_fee, admin_fee) = get_pool_fee_and_admin_fee(&pool.address_vec())?;
@@ -566,6 +568,7 @@ pub fn map_extract_pool_events(
                         fee: total_fee.to_string(),
                         admin_fee: Some(admin_fee.to_string()),
                         pool_address: pool.address.clone(),
+                        offpeg_fee_multiplier: None,
                     });
                 }
             }
@@ -614,9 +617,9 @@ fn extract_swap_event(
         .unwrap();
 
     let (token_in_price, token_in_price_source) =
-        get_token_usd_price(token_in, &uniswap_prices, &chainlink_prices);
+        get_pool_token_usd_price(pool, token_in, &uniswap_prices, &chainlink_prices);
     let (token_out_price, token_out_price_source) =
-        get_token_usd_price(token_out, &uniswap_prices, &chainlink_prices);
+        get_pool_token_usd_price(pool, token_out, &uniswap_prices, &chainlink_prices);
 
     let token_amount_in = TokenAmount {
         token_address: pool.input_tokens_ordered[in_address_index].clone(),
@@ -1106,7 +1109,7 @@ fn extract_deposit_event(
                 .and_then(|index| token_amounts.get(index))
                 .map(|amount| {
                     let (token_price, price_source) =
-                        get_token_usd_price(token, &uniswap_prices, &chainlink_prices);
+                        get_pool_token_usd_price(pool, token, &uniswap_prices, &chainlink_prices);
                     TokenAmount {
                         token_address: token.address.clone(),
                         amount: amount.into(),
@@ -1214,7 +1217,7 @@ fn extract_withdraw_event(
                 .and_then(|index| token_amounts.get(index))
                 .map(|amount| {
                     let (token_price, price_source) =
-                        get_token_usd_price(token, &uniswap_prices, &chainlink_prices);
+                        get_pool_token_usd_price(pool, token, &uniswap_prices, &chainlink_prices);
                     TokenAmount {
                         token_address: token.address.clone(),
                         amount: amount.into(),
@@ -1337,7 +1340,7 @@ fn extract_withdraw_one_event(
             if is_eth_transfer && address == &Hex::encode(&ETH_ADDRESS) {
                 if let Some(token) = pool.input_tokens.iter().find(|t| &t.address == address) {
                     let (token_price, price_source) =
-                        get_token_usd_price(token, &uniswap_prices, &chainlink_prices);
+                        get_pool_token_usd_price(pool, token, &uniswap_prices, &chainlink_prices);
                     return TokenAmount {
                         token_address: address.clone(),
                         amount: coin_amount.clone().into(),
@@ -1351,8 +1354,12 @@ fn extract_withdraw_one_event(
             } else if let Ok(transfer) = &token_transfer {
                 if &Hex::encode(&transfer.token_address) == address {
                     if let Some(token) = pool.input_tokens.iter().find(|t| &t.address == address) {
-                        let (token_price, price_source) =
-                            get_token_usd_price(token, &uniswap_prices, &chainlink_prices);
+                        let (token_price, price_source) = get_pool_token_usd_price(
+                            pool,
+                            token,
+                            &uniswap_prices,
+                            &chainlink_prices,
+                        );
 
                         return TokenAmount {
                             token_address: address.clone(),
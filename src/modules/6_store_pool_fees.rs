@@ -3,7 +3,7 @@ use substreams::store::{StoreNew, StoreSet, StoreSetProto};
 use crate::{
     key_management::store_key_manager::StoreKey,
     pb::curve::types::v1::{Events, Pool, PoolFees, CurveEvents},
-    rpc::pool::{calculate_pool_fees, get_pool_fee_and_admin_fee},
+    rpc::pool::{calculate_pool_fees, get_pool_fee_and_admin_fee, get_pool_offpeg_fee_multiplier},
 };
 
 #[substreams::handlers::store]
@@ -21,6 +21,7 @@ pub fn store_pool_fees(
         let pool_fees = calculate_pool_fees(
             event.fee_big(),
             event.admin_fee_big(),
+            event.offpeg_fee_multiplier_big(),
             &event.pool_address_vec(),
         );
         store.set(
@@ -41,7 +42,9 @@ fn process_pool_fee(pool: &Pool, store: &StoreSetProto<PoolFees>) {
     }
 
     if let Ok((total_fee, admin_fee)) = fee_res {
-        let pool_fees = calculate_pool_fees(total_fee, admin_fee, &pool_address);
+        let offpeg_fee_multiplier = get_pool_offpeg_fee_multiplier(&pool_address);
+        let pool_fees =
+            calculate_pool_fees(total_fee, admin_fee, offpeg_fee_multiplier, &pool_address);
         store.set(
             pool.log_ordinal,
             StoreKey::pool_fees_key(&pool.address),
@@ -15,7 +15,7 @@ use crate::{
         pruning::{
             pruners::token_volume_native_pruner::TokenVolumeNativePruner, setup_timeframe_pruning,
         },
-        utils::calculate_day_hour_id,
+        utils::{calculate_day_hour_id, calculate_week_month_id},
     },
 };
 
@@ -35,7 +35,9 @@ pub fn store_pool_volume_native(
     };
     setup_timeframe_pruning(&current_time_deltas, &[&token_volume_native_pruner]);
 
-    let (day_id, hour_id) = calculate_day_hour_id(clock.timestamp.unwrap().seconds);
+    let timestamp = clock.timestamp.unwrap().seconds;
+    let (day_id, hour_id) = calculate_day_hour_id(timestamp);
+    let (week_id, month_id) = calculate_week_month_id(timestamp);
 
     for event in events.pool_events {
         // Ensure there is a pool for this event
@@ -50,6 +52,8 @@ pub fn store_pool_volume_native(
                         swap.token_in_amount_big(),
                         &day_id,
                         &hour_id,
+                        &week_id,
+                        &month_id,
                     );
                     update_pool_volume(
                         &output_store,
@@ -58,6 +62,8 @@ pub fn store_pool_volume_native(
                         swap.token_out_amount_big(),
                         &day_id,
                         &hour_id,
+                        &week_id,
+                        &month_id,
                     );
                 }
                 Type::SwapUnderlyingMetaEvent(swap_underlying) => {
@@ -70,6 +76,8 @@ pub fn store_pool_volume_native(
                             swap_underlying.token_in_amount_big(),
                             &day_id,
                             &hour_id,
+                            &week_id,
+                            &month_id,
                         );
                     } else if is_base_to_meta_exchange(swap_underlying) {
                         // We only need to track the volume for the Metapools asset.
@@ -80,6 +88,8 @@ pub fn store_pool_volume_native(
                             swap_underlying.token_out_amount_big(),
                             &day_id,
                             &hour_id,
+                            &week_id,
+                            &month_id,
                         );
                     }
                     // If the exchange is a Base pool asset for another Base pool asset, the exchange
@@ -98,6 +108,8 @@ fn update_pool_volume(
     amount: BigInt,
     day_id: &i64,
     hour_id: &i64,
+    week_id: &i64,
+    month_id: &i64,
 ) {
     output_store.add_many(
         event.log_ordinal,
@@ -112,6 +124,16 @@ fn update_pool_volume(
                 &event.pool_address,
                 &token_address,
             ),
+            StoreKey::pool_token_volume_native_weekly_key(
+                &week_id,
+                &event.pool_address,
+                &token_address,
+            ),
+            StoreKey::pool_token_volume_native_monthly_key(
+                &month_id,
+                &event.pool_address,
+                &token_address,
+            ),
         ],
         amount,
     );
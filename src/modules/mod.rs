@@ -70,32 +70,152 @@ mod store_reward_tokens;
 #[path = "23_store_pool_rewards.rs"]
 mod store_pool_rewards;
 
+#[path = "24_store_user_last_active_day.rs"]
+mod store_user_last_active_day;
+
+#[path = "25_map_gauge_controller_events.rs"]
+mod map_gauge_controller_events;
+
+#[path = "26_store_gauge_votes.rs"]
+mod store_gauge_votes;
+
+#[path = "27_map_voting_escrow_events.rs"]
+mod map_voting_escrow_events;
+
+#[path = "28_store_voting_escrow_locks.rs"]
+mod store_voting_escrow_locks;
+
+#[path = "29_store_gauge_user_crv_minted.rs"]
+mod store_gauge_user_crv_minted;
+
+#[path = "30_store_gauge_crv_minted.rs"]
+mod store_gauge_crv_minted;
+
+#[path = "31_store_gauge_count.rs"]
+mod store_gauge_count;
+
+#[path = "32_store_gauge_addresses.rs"]
+mod store_gauge_addresses;
+
+#[path = "33_store_gauge_user_balances.rs"]
+mod store_gauge_user_balances;
+
+#[path = "34_store_gauge_position_count.rs"]
+mod store_gauge_position_count;
+
+#[path = "35_store_reward_claimed.rs"]
+mod store_reward_claimed;
+
+#[path = "36_store_reward_distributors.rs"]
+mod store_reward_distributors;
+
+#[path = "37_store_reward_funded.rs"]
+mod store_reward_funded;
+
+#[path = "38_store_root_gauges.rs"]
+mod store_root_gauges;
+
+#[path = "39_store_gauge_crv_bridged.rs"]
+mod store_gauge_crv_bridged;
+
+#[path = "40_map_base_pool_events.rs"]
+mod map_base_pool_events;
+
+#[path = "41_store_base_pools.rs"]
+mod store_base_pools;
+
+#[path = "42_store_pool_revenue.rs"]
+mod store_pool_revenue;
+
+#[path = "43_store_crvusd_markets.rs"]
+mod store_crvusd_markets;
+
+#[path = "44_map_crvusd_events.rs"]
+mod map_crvusd_events;
+
+#[path = "45_store_crvusd_balances.rs"]
+mod store_crvusd_balances;
+
+#[path = "46_store_lending_markets.rs"]
+mod store_lending_markets;
+
+#[path = "47_map_lending_events.rs"]
+mod map_lending_events;
+
+#[path = "48_store_lending_balances.rs"]
+mod store_lending_balances;
+
+#[path = "49_store_gauge_staked_supply.rs"]
+mod store_gauge_staked_supply;
+
+#[path = "50_map_flat_pool_events.rs"]
+mod map_flat_pool_events;
+
+#[path = "51_store_protocol_revenue.rs"]
+mod store_protocol_revenue;
+
+#[path = "52_store_gauge_status.rs"]
+mod store_gauge_status;
+
 // TODO: Will decrement once we have added and finalised all the other modules.
 #[path = "420_graph_out.rs"]
 mod graph_out;
 
+#[path = "421_db_out.rs"]
+mod db_out;
+
+pub use db_out::db_out;
 pub use graph_out::graph_out;
+pub use map_base_pool_events::map_base_pool_events;
+pub use map_crvusd_events::map_crvusd_events;
 pub use map_curve_events::map_curve_events;
 pub use map_extract_pool_events::map_extract_pool_events;
+pub use map_flat_pool_events::map_flat_pool_events;
+pub use map_gauge_controller_events::map_gauge_controller_events;
 pub use map_gauge_events::map_gauge_events;
+pub use map_lending_events::map_lending_events;
+pub use map_voting_escrow_events::map_voting_escrow_events;
 pub use store_active_users::store_active_users;
+pub use store_base_pools::store_base_pools;
 pub use store_controller_gauges::store_controller_gauges;
 pub use store_crv_inflation::store_crv_inflation;
+pub use store_crvusd_balances::store_crvusd_balances;
+pub use store_crvusd_markets::store_crvusd_markets;
 pub use store_current_time::store_current_time;
+pub use store_gauge_addresses::store_gauge_addresses;
+pub use store_gauge_count::store_gauge_count;
+pub use store_gauge_crv_bridged::store_gauge_crv_bridged;
+pub use store_gauge_crv_minted::store_gauge_crv_minted;
+pub use store_gauge_position_count::store_gauge_position_count;
+pub use store_gauge_staked_supply::store_gauge_staked_supply;
+pub use store_gauge_status::store_gauge_status;
+pub use store_gauge_user_balances::store_gauge_user_balances;
+pub use store_gauge_user_crv_minted::store_gauge_user_crv_minted;
+pub use store_gauge_votes::store_gauge_votes;
 pub use store_gauges::store_gauges;
 pub use store_input_token_balances::store_input_token_balances;
+pub use store_lending_balances::store_lending_balances;
+pub use store_lending_markets::store_lending_markets;
 pub use store_output_token_supply::store_output_token_supply;
 pub use store_pool_addresses::store_pool_addresses;
 pub use store_pool_count::store_pool_count;
 pub use store_pool_fees::store_pool_fees;
+pub use store_pool_revenue::store_pool_revenue;
 pub use store_pool_rewards::store_pool_rewards;
 pub use store_pool_tvl::store_pool_tvl;
 pub use store_pool_volume_native::store_pool_volume_native;
 pub use store_pool_volume_usd::store_pool_volume_usd;
 pub use store_pools_created::store_pools_created;
+pub use store_protocol_revenue::store_protocol_revenue;
 pub use store_protocol_tvl::store_protocol_tvl;
 pub use store_protocol_volume_usd::store_protocol_volume_usd;
+pub use store_reward_claimed::store_reward_claimed;
+pub use store_reward_distributors::store_reward_distributors;
+pub use store_reward_funded::store_reward_funded;
 pub use store_reward_token_count::store_reward_token_count;
 pub use store_reward_tokens::store_reward_tokens;
+pub use store_root_gauges::store_root_gauges;
 pub use store_tokens::store_tokens;
 pub use store_usage_metrics::store_usage_metrics;
+pub use store_user_last_active_day::store_user_last_active_day;
+pub use store_voting_escrow_locks::store_voting_escrow_locks;
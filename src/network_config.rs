@@ -1,8 +1,11 @@
 use hex_literal::hex;
 use crate::{
-        pb::curve::types::v1::lending_pool::{
-            AaveLending, CompoundLending, CompoundTetherLending, IronBankLending, LendingPoolType,
-            PaxLending, YiEarnLending,
+        pb::curve::types::v1::{
+            lending_pool::{
+                AaveLending, CompoundLending, CompoundTetherLending, IronBankLending,
+                LendingPoolType, PaxLending, YiEarnLending,
+            },
+            StakingWrapper,
         },
         types::registry::{RegistryDetails, RegistryType},
     };
@@ -13,7 +16,29 @@ pub const PRICE_CACHING: u64 = 6000;
 pub const POOL_INFO_CONTRACT: [u8; 20] = hex!("e64608E223433E8a03a1DaaeFD8Cb638C14B552C");
 pub const PROTOCOL_ADDRESS: [u8; 20] = hex!("0000000022D53366457F9d5E68Ec105046FC4383");
 pub const CRV_TOKEN_ADDRESS: [u8; 20] = hex!("d533a949740bb3306d119cc777fa900ba034cd52");
-pub const GAUGE_CONTROLLER_ADDRESS: [u8; 20] = hex!("2f50d538606fa9edd2b11e2446beb18c9d5846bb");
+pub const GAUGE_CONTROLLER_ADDRESS: Option<[u8; 20]> = Some(hex!("2f50d538606fa9edd2b11e2446beb18c9d5846bb"));
+pub const MINTER_ADDRESS: Option<[u8; 20]> = Some(hex!("d061D61a4d941c39E5453435B6345Dc261C2fcE0"));
+pub const VOTING_ESCROW_ADDRESS: Option<[u8; 20]> = Some(hex!("5f3b5DfEb7B28CDbD7FAba78963EE202a494e2A2"));
+pub const ROOT_GAUGE_FACTORY_ADDRESS: Option<[u8; 20]> = Some(hex!("306A45a1478A000dC701A6e1f7a569afb8D9DCD6"));
+pub const CHILD_GAUGE_FACTORY_ADDRESS: Option<[u8; 20]> = None;
+pub const CHILD_GAUGE_BRIDGER_ADDRESS: Option<[u8; 20]> = None;
+pub const CRVUSD_CONTROLLER_FACTORY_ADDRESS: Option<[u8; 20]> = Some(hex!("C9332fdCB1C491Dcc683bAe86Fe3cb70360738BC"));
+pub const CRVUSD_TOKEN_ADDRESS: Option<[u8; 20]> = Some(hex!("f939E0A03FB07F59A73314E73794Be0E57ac1b4E"));
+pub static CRVUSD_PEG_KEEPERS: [[u8; 20]; 8] = [
+hex!("aA346781dDD7009caa644A4980f044C50cD2ae22"), // USDC PegKeeper
+hex!("E7cd2b4EB1d98CD6a4A48B6071D46401Ac7DC5C8"), // USDT PegKeeper
+hex!("6B765d07cf966c745B340AdCa67749fE75B5c345"), // USDP PegKeeper
+hex!("1ef89Ed0eDd93D1EC09E4c07373f69C49f4dcCae"), // TUSD PegKeeper
+hex!("5B49b9adD1ecfe53E19cc2cFc8a33127cD6bA4C6"), // USDC PegKeeper V2
+hex!("FF78468340EE322ed63C432BF74D817742b392Bf"), // USDT PegKeeper V2
+hex!("68e31e1eDD641B13cAEAb1Ac1BE661B19CC021ca"), // pyUSD PegKeeper V2
+hex!("0B502e48E950095d93E8b739aD146C72b4f6C820"), // TUSD PegKeeper V2
+];
+pub const ONE_WAY_LENDING_FACTORY_ADDRESS: Option<[u8; 20]> = Some(hex!("eA6876DDE9e3467564acBeE1Ed5bac88783205E0"));
+pub static STAKING_WRAPPERS: [([u8; 20], StakingWrapper); 2] = [
+(hex!("989AEb4d175e16225E39E87d0D97A3360524AD80"), StakingWrapper::Convex), // Convex VoterProxy
+(hex!("52f541764E6e90eeBc5c21Ff570De0e2D63766B6"), StakingWrapper::StakeDao), // Stake DAO Locker
+];
 pub const REGISTRIES: &[RegistryDetails] = &[
     RegistryDetails { address: hex!("DE3eAD9B2145bBA2EB74007e58ED07308716B725"), registry_type: RegistryType::BasePoolRegistry },
     RegistryDetails { address: hex!("4F8846Ae9380B90d2E71D5e3D042dff3E7ebb40d"), registry_type: RegistryType::CrvUSDPoolFactory },
@@ -169,13 +194,13 @@ hex!("056fd409e1d7a124bd7017459dfea2f387b6d5cd"), // gUSD
 hex!("5f98805a4e8be255a32880fdec7f6728c6568ba0"), // lUSD
 ];
 
-pub static CURVE_CALCULATIONS: [u8; 20] = hex!("25bf7b72815476dd515044f9650bf79bad0df655");
+pub static CURVE_CALCULATIONS: Option<[u8; 20]> = Some(hex!("25bf7b72815476dd515044f9650bf79bad0df655"));
 
-pub static INCH_ORACLE: [u8; 20] = hex!("07d91f5fb9bf7798734c3f606db065549f6893bb");
+pub static INCH_ORACLE: Option<[u8; 20]> = Some(hex!("07d91f5fb9bf7798734c3f606db065549f6893bb"));
 
-pub static YEARN_LENS: [u8; 20] = hex!("83d95e0d5f402511db06817aff3f9ea88224b030");
+pub static YEARN_LENS: Option<[u8; 20]> = Some(hex!("83d95e0d5f402511db06817aff3f9ea88224b030"));
 
-pub static SUSHISWAP: [u8; 20] = hex!("5ea7e501c9a23f4a76dc7d33a11d995b13a1dd25");
+pub static SUSHISWAP: Option<[u8; 20]> = Some(hex!("5ea7e501c9a23f4a76dc7d33a11d995b13a1dd25"));
 
 pub static CURVE_CALCULATIONS_BLACKLIST: [[u8; 20]; 2] = [
 hex!("ca3d75ac011bf5ad07a98d02f18225f9bd9a6bdf"), // crvTriCrypto
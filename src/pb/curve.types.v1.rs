@@ -26,6 +26,9 @@ pub struct Token {
     /// Optional field to track the gauge for reward tokens
     #[prost(string, optional, tag="8")]
     pub gauge: ::core::option::Option<::prost::alloc::string::String>,
+    /// StableSwap-NG asset type, always STANDARD for other pools
+    #[prost(enumeration="AssetType", tag="9")]
+    pub asset_type: i32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -85,18 +88,60 @@ pub struct PoolRewards {
     pub reward_token_emissions_native: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     #[prost(string, repeated, tag="3")]
     pub reward_token_emissions_usd: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Reward token addresses, in the same order as the emissions and APRs
+    #[prost(string, repeated, tag="4")]
+    pub reward_tokens: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Reward APR (in percentage) of each reward token for a position without any boost
+    #[prost(string, repeated, tag="5")]
+    pub reward_token_base_aprs: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Reward APR (in percentage) of each reward token for a position with the maximum boost
+    #[prost(string, repeated, tag="6")]
+    pub reward_token_max_boosted_aprs: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlainPool {
 }
+/// Curve parameters of a CryptoSwap pool, read when the pool is first indexed. All values are raw
+/// on-chain integers; a field is empty if the pool's `eth_call` failed.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CryptoPool {
+    /// Amplification coefficient, multiplied by the pool's `A_MULTIPLIER`
+    #[prost(string, tag="1")]
+    pub a: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub gamma: ::prost::alloc::string::String,
+    /// Fee charged when the pool is balanced, with 10 decimals
+    #[prost(string, tag="3")]
+    pub mid_fee: ::prost::alloc::string::String,
+    /// Fee charged when the pool is imbalanced, with 10 decimals
+    #[prost(string, tag="4")]
+    pub out_fee: ::prost::alloc::string::String,
+    /// Controls how fast the fee moves from `mid_fee` to `out_fee`
+    #[prost(string, tag="5")]
+    pub fee_gamma: ::prost::alloc::string::String,
+    /// Price of the second coin in terms of the first, with 18 decimals
+    #[prost(string, tag="6")]
+    pub price_scale: ::prost::alloc::string::String,
 }
+/// Curve parameters of a Tricrypto pool, read when the pool is first indexed. See `CryptoPool`.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TriCryptoPool {
+    #[prost(string, tag="1")]
+    pub a: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub gamma: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mid_fee: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub out_fee: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub fee_gamma: ::prost::alloc::string::String,
+    /// Price of each coin after the first in terms of the first, with 18 decimals
+    #[prost(string, repeated, tag="6")]
+    pub price_scale: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -168,6 +213,30 @@ pub mod lending_pool {
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WildcardPool {
 }
+/// Base pools registered with the BasePoolRegistry or StableSwapFactoryNG, which metapools can be
+/// deployed against.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BasePool {
+    #[prost(string, tag="1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub lp_token: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub registry: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub created_at_timestamp: u64,
+    #[prost(uint64, tag="5")]
+    pub created_at_block_number: u64,
+    #[prost(uint64, tag="6")]
+    pub log_ordinal: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BasePools {
+    #[prost(message, repeated, tag="1")]
+    pub base_pools: ::prost::alloc::vec::Vec<BasePool>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LiquidityGauge {
@@ -186,114 +255,968 @@ pub struct LiquidityGauge {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct LiquidityGauges {
-    #[prost(message, repeated, tag="1")]
-    pub liquidity_gauges: ::prost::alloc::vec::Vec<LiquidityGauge>,
+pub struct LiquidityGauges {
+    #[prost(message, repeated, tag="1")]
+    pub liquidity_gauges: ::prost::alloc::vec::Vec<LiquidityGauge>,
+}
+/// Root gauges are deployed on mainnet via the RootGaugeFactory, and bridge their CRV emissions to a
+/// child gauge with the same address on the target chain.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RootGauge {
+    #[prost(string, tag="1")]
+    pub gauge: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub chain_id: u64,
+    #[prost(string, tag="3")]
+    pub deployer: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub created_at_timestamp: u64,
+    #[prost(uint64, tag="5")]
+    pub created_at_block_number: u64,
+    #[prost(uint64, tag="6")]
+    pub log_ordinal: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityGaugeEvents {
+    #[prost(message, repeated, tag="1")]
+    pub liquidity_events: ::prost::alloc::vec::Vec<LiquidityEvent>,
+    #[prost(message, repeated, tag="2")]
+    pub add_reward_events: ::prost::alloc::vec::Vec<AddRewardEvent>,
+    #[prost(message, repeated, tag="3")]
+    pub crv_minted_events: ::prost::alloc::vec::Vec<CrvMintedEvent>,
+    #[prost(message, repeated, tag="4")]
+    pub transfer_events: ::prost::alloc::vec::Vec<GaugeTransferEvent>,
+    #[prost(message, repeated, tag="5")]
+    pub reward_claim_events: ::prost::alloc::vec::Vec<RewardClaimEvent>,
+    #[prost(message, repeated, tag="6")]
+    pub kill_status_events: ::prost::alloc::vec::Vec<GaugeKillStatusEvent>,
+    #[prost(message, repeated, tag="7")]
+    pub set_reward_distributor_events: ::prost::alloc::vec::Vec<SetRewardDistributorEvent>,
+    #[prost(message, repeated, tag="8")]
+    pub deposit_reward_token_events: ::prost::alloc::vec::Vec<DepositRewardTokenEvent>,
+    #[prost(message, repeated, tag="9")]
+    pub root_gauge_emission_events: ::prost::alloc::vec::Vec<RootGaugeEmissionEvent>,
+    #[prost(message, repeated, tag="10")]
+    pub child_gauge_emission_events: ::prost::alloc::vec::Vec<ChildGaugeEmissionEvent>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityEvent {
+    #[prost(string, tag="1")]
+    pub gauge: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub provider: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(enumeration="GaugeLiquidityEventType", tag="5")]
+    pub r#type: i32,
+    #[prost(string, tag="6")]
+    pub working_supply: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="8")]
+    pub tx_index: u32,
+    #[prost(uint32, tag="9")]
+    pub log_index: u32,
+    #[prost(uint64, tag="10")]
+    pub log_ordinal: u64,
+    #[prost(uint64, tag="11")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="12")]
+    pub block_number: u64,
+    /// Set when the provider is a known staking wrapper contract
+    #[prost(enumeration="StakingWrapper", tag="13")]
+    pub staking_wrapper: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddRewardEvent {
+    #[prost(string, tag="1")]
+    pub gauge: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub reward_token: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub distributor: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="6")]
+    pub tx_index: u32,
+    #[prost(uint64, tag="7")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="8")]
+    pub block_number: u64,
+    /// Begin ordinal of the `add_reward` call
+    #[prost(uint64, tag="9")]
+    pub ordinal: u64,
+}
+/// Emitted from the Minter when CRV is claimed from a gauge. The `minted` value is the total amount
+/// of CRV minted to the recipient from the gauge so far, not the amount minted in this transaction.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CrvMintedEvent {
+    #[prost(string, tag="1")]
+    pub gauge: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub recipient: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="4")]
+    pub minted: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="6")]
+    pub tx_index: u32,
+    #[prost(uint32, tag="7")]
+    pub log_index: u32,
+    #[prost(uint64, tag="8")]
+    pub log_ordinal: u64,
+    #[prost(uint64, tag="9")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="10")]
+    pub block_number: u64,
+}
+/// Transfer of staked gauge tokens between two users. Mints and burns are not included, as these are
+/// already covered by the gauge `Deposit` and `Withdraw` events.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GaugeTransferEvent {
+    #[prost(string, tag="1")]
+    pub gauge: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub from: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub to: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="5")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="7")]
+    pub tx_index: u32,
+    #[prost(uint32, tag="8")]
+    pub log_index: u32,
+    #[prost(uint64, tag="9")]
+    pub log_ordinal: u64,
+    #[prost(uint64, tag="10")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="11")]
+    pub block_number: u64,
+}
+/// Permissionless reward tokens sent to a receiver when a user's rewards are claimed from a gauge
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardClaimEvent {
+    #[prost(string, tag="1")]
+    pub gauge: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub receiver: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub reward_token: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="6")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="8")]
+    pub tx_index: u32,
+    #[prost(uint32, tag="9")]
+    pub log_index: u32,
+    #[prost(uint64, tag="10")]
+    pub log_ordinal: u64,
+    #[prost(uint64, tag="11")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="12")]
+    pub block_number: u64,
+}
+/// Captured from `set_killed` calls on a gauge, as these do not emit events. Killed gauges stop
+/// accruing CRV rewards for their depositors.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GaugeKillStatusEvent {
+    #[prost(string, tag="1")]
+    pub gauge: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(bool, tag="3")]
+    pub is_killed: bool,
+    #[prost(string, tag="4")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="5")]
+    pub tx_index: u32,
+    #[prost(uint64, tag="6")]
+    pub ordinal: u64,
+    #[prost(uint64, tag="7")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="8")]
+    pub block_number: u64,
+}
+/// Captured from `set_reward_distributor` calls on a gauge, which change the account allowed to fund
+/// a reward token.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetRewardDistributorEvent {
+    #[prost(string, tag="1")]
+    pub gauge: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub reward_token: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub distributor: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="6")]
+    pub tx_index: u32,
+    #[prost(uint64, tag="7")]
+    pub ordinal: u64,
+    #[prost(uint64, tag="8")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="9")]
+    pub block_number: u64,
+}
+/// Captured from `deposit_reward_token` calls on a gauge, which fund a new reward period.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DepositRewardTokenEvent {
+    #[prost(string, tag="1")]
+    pub gauge: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub pool: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub reward_token: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub distributor: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="5")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="7")]
+    pub tx_index: u32,
+    #[prost(uint64, tag="8")]
+    pub ordinal: u64,
+    #[prost(uint64, tag="9")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="10")]
+    pub block_number: u64,
+}
+/// Emitted from the Minter when a root gauge mints the CRV it transmits to its child gauge.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RootGaugeEmissionEvent {
+    #[prost(string, tag="1")]
+    pub gauge: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub chain_id: u64,
+    /// String representation of BigInt
+    #[prost(string, tag="3")]
+    pub total_transmitted: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="5")]
+    pub tx_index: u32,
+    #[prost(uint32, tag="6")]
+    pub log_index: u32,
+    #[prost(uint64, tag="7")]
+    pub log_ordinal: u64,
+    #[prost(uint64, tag="8")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="9")]
+    pub block_number: u64,
+}
+/// CRV bridged to a child gauge on a sidechain, captured from the CRV `Transfer` into the gauge.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChildGaugeEmissionEvent {
+    #[prost(string, tag="1")]
+    pub gauge: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub pool: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="3")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="5")]
+    pub tx_index: u32,
+    #[prost(uint32, tag="6")]
+    pub log_index: u32,
+    #[prost(uint64, tag="7")]
+    pub log_ordinal: u64,
+    #[prost(uint64, tag="8")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="9")]
+    pub block_number: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ControllerNewGauge {
+    #[prost(string, tag="1")]
+    pub gauge: ::prost::alloc::string::String,
+    #[prost(enumeration="GaugeType", tag="2")]
+    pub r#type: i32,
+    /// String representation of BigInt
+    #[prost(string, tag="3")]
+    pub weight: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub created_at_timestamp: u64,
+    #[prost(uint64, tag="5")]
+    pub created_at_block_number: u64,
+    #[prost(uint64, tag="6")]
+    pub log_ordinal: u64,
+}
+/// This event is emitted from the CRV token, and allows us to keep track of in the inflation rate
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateMiningParametersEvent {
+    #[prost(string, tag="1")]
+    pub time: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub rate: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub supply: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub created_at_timestamp: u64,
+    #[prost(uint64, tag="5")]
+    pub created_at_block_number: u64,
+    #[prost(uint64, tag="6")]
+    pub log_ordinal: u64,
+}
+/// This includes pool and gauge deployments, and GaugeController add events.
+/// When already deployed gauges are added to the controller, they become eligible for CRV rewards.
+/// We also track UpdateMiningParametersEvent from the CRV contract, to keep track of inflation.
+/// Root gauges deployed for sidechains are tracked separately, as they do not belong to a local pool.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CurveEvents {
+    #[prost(message, repeated, tag="1")]
+    pub pools: ::prost::alloc::vec::Vec<Pool>,
+    #[prost(message, repeated, tag="2")]
+    pub gauges: ::prost::alloc::vec::Vec<LiquidityGauge>,
+    #[prost(message, repeated, tag="3")]
+    pub controller_gauges: ::prost::alloc::vec::Vec<ControllerNewGauge>,
+    #[prost(message, optional, tag="4")]
+    pub update_mining_parameters_event: ::core::option::Option<UpdateMiningParametersEvent>,
+    #[prost(message, repeated, tag="5")]
+    pub root_gauges: ::prost::alloc::vec::Vec<RootGauge>,
+    #[prost(message, repeated, tag="6")]
+    pub crvusd_markets: ::prost::alloc::vec::Vec<CrvUsdMarket>,
+    #[prost(message, repeated, tag="7")]
+    pub lending_markets: ::prost::alloc::vec::Vec<LendingMarket>,
+}
+/// Events emitted from the GaugeController contract, used to track gauge weight voting.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GaugeControllerEvents {
+    #[prost(message, repeated, tag="1")]
+    pub gauge_votes: ::prost::alloc::vec::Vec<GaugeVote>,
+    #[prost(message, repeated, tag="2")]
+    pub gauge_weight_updates: ::prost::alloc::vec::Vec<GaugeWeightUpdate>,
+    #[prost(message, repeated, tag="3")]
+    pub type_weight_updates: ::prost::alloc::vec::Vec<GaugeTypeWeightUpdate>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GaugeVote {
+    #[prost(string, tag="1")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub gauge: ::prost::alloc::string::String,
+    /// Share of the user's voting power in bps
+    #[prost(string, tag="3")]
+    pub weight: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="4")]
+    pub slope: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub time: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="7")]
+    pub tx_index: u32,
+    #[prost(uint32, tag="8")]
+    pub log_index: u32,
+    #[prost(uint64, tag="9")]
+    pub log_ordinal: u64,
+    #[prost(uint64, tag="10")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="11")]
+    pub block_number: u64,
+}
+/// Votes do not emit the resulting gauge weight, so it is fetched once per voted gauge in a block.
+/// Admin weight changes emit `NewGaugeWeight`, which already includes it.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GaugeWeightUpdate {
+    #[prost(string, tag="1")]
+    pub gauge: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="2")]
+    pub weight: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="3")]
+    pub total_weight: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub time: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="6")]
+    pub log_ordinal: u64,
+    #[prost(uint64, tag="7")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="8")]
+    pub block_number: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GaugeTypeWeightUpdate {
+    #[prost(enumeration="GaugeType", tag="1")]
+    pub r#type: i32,
+    /// String representation of BigInt
+    #[prost(string, tag="2")]
+    pub weight: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="3")]
+    pub total_weight: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub time: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="6")]
+    pub log_ordinal: u64,
+    #[prost(uint64, tag="7")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="8")]
+    pub block_number: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VotingEscrowEvents {
+    #[prost(message, repeated, tag="1")]
+    pub lock_events: ::prost::alloc::vec::Vec<VotingEscrowLockEvent>,
+    #[prost(message, repeated, tag="2")]
+    pub supply_events: ::prost::alloc::vec::Vec<VotingEscrowSupplyEvent>,
+}
+/// Emitted from the VotingEscrow `Deposit` and `Withdraw` events. The resulting lock is fetched
+/// from the contract, so downstream modules do not need to keep track of the previous lock state.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VotingEscrowLockEvent {
+    #[prost(string, tag="1")]
+    pub provider: ::prost::alloc::string::String,
+    #[prost(enumeration="VotingEscrowLockEventType", tag="2")]
+    pub r#type: i32,
+    /// String representation of BigInt
+    #[prost(string, tag="3")]
+    pub value: ::prost::alloc::string::String,
+    /// Amount of CRV locked after the event
+    #[prost(string, tag="4")]
+    pub locked_amount: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub unlock_time: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="7")]
+    pub tx_index: u32,
+    #[prost(uint32, tag="8")]
+    pub log_index: u32,
+    #[prost(uint64, tag="9")]
+    pub log_ordinal: u64,
+    #[prost(uint64, tag="10")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="11")]
+    pub block_number: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VotingEscrowSupplyEvent {
+    /// Amount of CRV locked before the event
+    #[prost(string, tag="1")]
+    pub previous_supply: ::prost::alloc::string::String,
+    /// Amount of CRV locked after the event
+    #[prost(string, tag="2")]
+    pub supply: ::prost::alloc::string::String,
+    /// veCRV total supply at the end of the block
+    #[prost(string, tag="3")]
+    pub total_voting_power: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="5")]
+    pub log_ordinal: u64,
+    #[prost(uint64, tag="6")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="7")]
+    pub block_number: u64,
+}
+/// The voting power of a lock decays linearly: `bias - slope * (t - updated_at_timestamp)`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VotingEscrowLock {
+    #[prost(string, tag="1")]
+    pub provider: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="2")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub unlock_time: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="4")]
+    pub slope: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="5")]
+    pub bias: ::prost::alloc::string::String,
+    #[prost(uint64, tag="6")]
+    pub updated_at_timestamp: u64,
+    #[prost(uint64, tag="7")]
+    pub updated_at_block_number: u64,
+}
+/// crvUSD markets are deployed by the ControllerFactory. Each market pairs a Controller, which tracks
+/// user loans, with a LLAMMA, which holds the collateral across its liquidation bands.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CrvUsdMarket {
+    #[prost(string, tag="1")]
+    pub controller: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub amm: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub monetary_policy: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub stablecoin: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="5")]
+    pub collateral_token: ::core::option::Option<Token>,
+    #[prost(uint64, tag="6")]
+    pub index: u64,
+    #[prost(uint64, tag="7")]
+    pub created_at_timestamp: u64,
+    #[prost(uint64, tag="8")]
+    pub created_at_block_number: u64,
+    #[prost(uint64, tag="9")]
+    pub log_ordinal: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CrvUsdEvents {
+    #[prost(message, repeated, tag="1")]
+    pub llamma_exchange_events: ::prost::alloc::vec::Vec<LlammaExchangeEvent>,
+    #[prost(message, repeated, tag="2")]
+    pub llamma_liquidity_events: ::prost::alloc::vec::Vec<LlammaLiquidityEvent>,
+    #[prost(message, repeated, tag="3")]
+    pub controller_loan_events: ::prost::alloc::vec::Vec<ControllerLoanEvent>,
+    #[prost(message, repeated, tag="4")]
+    pub controller_liquidation_events: ::prost::alloc::vec::Vec<ControllerLiquidationEvent>,
+    #[prost(message, repeated, tag="5")]
+    pub peg_keeper_events: ::prost::alloc::vec::Vec<PegKeeperEvent>,
+}
+/// Soft-liquidation trades against a LLAMMA. Coin 0 is always crvUSD, and coin 1 the market's collateral.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LlammaExchangeEvent {
+    #[prost(string, tag="1")]
+    pub amm: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub controller: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub buyer: ::prost::alloc::string::String,
+    #[prost(uint32, tag="4")]
+    pub sold_id: u32,
+    #[prost(string, tag="5")]
+    pub sold_token: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="6")]
+    pub tokens_sold: ::prost::alloc::string::String,
+    #[prost(uint32, tag="7")]
+    pub bought_id: u32,
+    #[prost(string, tag="8")]
+    pub bought_token: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="9")]
+    pub tokens_bought: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="11")]
+    pub tx_index: u32,
+    #[prost(uint32, tag="12")]
+    pub log_index: u32,
+    #[prost(uint64, tag="13")]
+    pub log_ordinal: u64,
+    #[prost(uint64, tag="14")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="15")]
+    pub block_number: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LlammaLiquidityEvent {
+    #[prost(string, tag="1")]
+    pub amm: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub controller: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub provider: ::prost::alloc::string::String,
+    #[prost(enumeration="llamma_liquidity_event::LiquidityEventType", tag="4")]
+    pub r#type: i32,
+    /// String representation of BigInt
+    #[prost(string, tag="5")]
+    pub amount_collateral: ::prost::alloc::string::String,
+    /// crvUSD withdrawn from the bands of a soft-liquidated position
+    #[prost(string, tag="6")]
+    pub amount_borrowed: ::prost::alloc::string::String,
+    /// Band range the collateral is deposited into, only set for deposits
+    #[prost(int32, optional, tag="7")]
+    pub n1: ::core::option::Option<i32>,
+    #[prost(int32, optional, tag="8")]
+    pub n2: ::core::option::Option<i32>,
+    #[prost(string, tag="9")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="10")]
+    pub tx_index: u32,
+    #[prost(uint32, tag="11")]
+    pub log_index: u32,
+    #[prost(uint64, tag="12")]
+    pub log_ordinal: u64,
+    #[prost(uint64, tag="13")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="14")]
+    pub block_number: u64,
 }
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct LiquidityGaugeEvents {
-    #[prost(message, repeated, tag="1")]
-    pub liquidity_events: ::prost::alloc::vec::Vec<LiquidityEvent>,
-    #[prost(message, repeated, tag="2")]
-    pub add_reward_events: ::prost::alloc::vec::Vec<AddRewardEvent>,
+/// Nested message and enum types in `LlammaLiquidityEvent`.
+pub mod llamma_liquidity_event {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum LiquidityEventType {
+        Deposit = 0,
+        Withdraw = 1,
+    }
+    impl LiquidityEventType {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                LiquidityEventType::Deposit => "DEPOSIT",
+                LiquidityEventType::Withdraw => "WITHDRAW",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "DEPOSIT" => Some(Self::Deposit),
+                "WITHDRAW" => Some(Self::Withdraw),
+                _ => None,
+            }
+        }
+    }
 }
+/// Emitted from the Controller when a loan is created or adjusted. Liquidations also emit a `Repay`
+/// event for the full debt. As debt accrues interest, the resulting loan is fetched from the contract.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct LiquidityEvent {
+pub struct ControllerLoanEvent {
     #[prost(string, tag="1")]
-    pub gauge: ::prost::alloc::string::String,
+    pub controller: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
-    pub pool: ::prost::alloc::string::String,
-    #[prost(string, tag="3")]
-    pub provider: ::prost::alloc::string::String,
-    #[prost(string, tag="4")]
-    pub value: ::prost::alloc::string::String,
-    #[prost(enumeration="GaugeLiquidityEventType", tag="5")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(enumeration="controller_loan_event::LoanEventType", tag="3")]
     pub r#type: i32,
+    /// String representation of BigInt
+    #[prost(string, tag="4")]
+    pub collateral_amount: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="5")]
+    pub debt_amount: ::prost::alloc::string::String,
+    /// Collateral held in the user's bands after the event
     #[prost(string, tag="6")]
-    pub working_supply: ::prost::alloc::string::String,
+    pub user_collateral: ::prost::alloc::string::String,
+    /// crvUSD held in the user's bands after the event, from soft-liquidation
     #[prost(string, tag="7")]
+    pub user_stablecoin: ::prost::alloc::string::String,
+    /// Debt of the user after the event, including accrued interest
+    #[prost(string, tag="8")]
+    pub user_debt: ::prost::alloc::string::String,
+    /// Total debt of the market after the event
+    #[prost(string, tag="9")]
+    pub total_debt: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
     pub transaction_hash: ::prost::alloc::string::String,
-    #[prost(uint32, tag="8")]
+    #[prost(uint32, tag="11")]
     pub tx_index: u32,
-    #[prost(uint32, tag="9")]
+    #[prost(uint32, tag="12")]
     pub log_index: u32,
-    #[prost(uint64, tag="10")]
+    #[prost(uint64, tag="13")]
     pub log_ordinal: u64,
-    #[prost(uint64, tag="11")]
+    #[prost(uint64, tag="14")]
     pub timestamp: u64,
-    #[prost(uint64, tag="12")]
+    #[prost(uint64, tag="15")]
     pub block_number: u64,
 }
+/// Nested message and enum types in `ControllerLoanEvent`.
+pub mod controller_loan_event {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum LoanEventType {
+        Borrow = 0,
+        Repay = 1,
+        RemoveCollateral = 2,
+    }
+    impl LoanEventType {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                LoanEventType::Borrow => "BORROW",
+                LoanEventType::Repay => "REPAY",
+                LoanEventType::RemoveCollateral => "REMOVE_COLLATERAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "BORROW" => Some(Self::Borrow),
+                "REPAY" => Some(Self::Repay),
+                "REMOVE_COLLATERAL" => Some(Self::RemoveCollateral),
+                _ => None,
+            }
+        }
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AddRewardEvent {
+pub struct ControllerLiquidationEvent {
     #[prost(string, tag="1")]
-    pub gauge: ::prost::alloc::string::String,
+    pub controller: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
-    pub pool: ::prost::alloc::string::String,
+    pub liquidator: ::prost::alloc::string::String,
     #[prost(string, tag="3")]
-    pub reward_token: ::prost::alloc::string::String,
+    pub user: ::prost::alloc::string::String,
+    /// String representation of BigInt
     #[prost(string, tag="4")]
-    pub distributor: ::prost::alloc::string::String,
+    pub collateral_received: ::prost::alloc::string::String,
+    /// String representation of BigInt
     #[prost(string, tag="5")]
+    pub stablecoin_received: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="6")]
+    pub debt: ::prost::alloc::string::String,
+    /// Total debt of the market after the liquidation
+    #[prost(string, tag="7")]
+    pub total_debt: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
     pub transaction_hash: ::prost::alloc::string::String,
-    #[prost(uint32, tag="6")]
+    #[prost(uint32, tag="9")]
     pub tx_index: u32,
-    #[prost(uint64, tag="7")]
+    #[prost(uint32, tag="10")]
+    pub log_index: u32,
+    #[prost(uint64, tag="11")]
+    pub log_ordinal: u64,
+    #[prost(uint64, tag="12")]
     pub timestamp: u64,
-    #[prost(uint64, tag="8")]
+    #[prost(uint64, tag="13")]
     pub block_number: u64,
 }
+/// PegKeepers mint crvUSD into their stableswap pool when crvUSD trades above peg, and withdraw it to
+/// repay their debt when it trades below.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ControllerNewGauge {
+pub struct PegKeeperEvent {
     #[prost(string, tag="1")]
-    pub gauge: ::prost::alloc::string::String,
-    #[prost(enumeration="GaugeType", tag="2")]
+    pub peg_keeper: ::prost::alloc::string::String,
+    #[prost(enumeration="peg_keeper_event::PegKeeperEventType", tag="2")]
     pub r#type: i32,
     /// String representation of BigInt
     #[prost(string, tag="3")]
-    pub weight: ::prost::alloc::string::String,
-    #[prost(uint64, tag="4")]
-    pub created_at_timestamp: u64,
-    #[prost(uint64, tag="5")]
-    pub created_at_block_number: u64,
-    #[prost(uint64, tag="6")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="5")]
+    pub tx_index: u32,
+    #[prost(uint32, tag="6")]
+    pub log_index: u32,
+    #[prost(uint64, tag="7")]
     pub log_ordinal: u64,
+    #[prost(uint64, tag="8")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="9")]
+    pub block_number: u64,
 }
-/// This event is emitted from the CRV token, and allows us to keep track of in the inflation rate
+/// Nested message and enum types in `PegKeeperEvent`.
+pub mod peg_keeper_event {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum PegKeeperEventType {
+        Provide = 0,
+        Withdraw = 1,
+    }
+    impl PegKeeperEventType {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                PegKeeperEventType::Provide => "PROVIDE",
+                PegKeeperEventType::Withdraw => "WITHDRAW",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "PROVIDE" => Some(Self::Provide),
+                "WITHDRAW" => Some(Self::Withdraw),
+                _ => None,
+            }
+        }
+    }
+}
+/// LlamaLend markets are deployed by the OneWayLendingFactory. Each pairs an ERC4626 vault, which lends
+/// out the borrowed token supplied to it, with a Controller and LLAMMA like those of crvUSD markets.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UpdateMiningParametersEvent {
+pub struct LendingMarket {
     #[prost(string, tag="1")]
-    pub time: ::prost::alloc::string::String,
+    pub vault: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
-    pub rate: ::prost::alloc::string::String,
+    pub controller: ::prost::alloc::string::String,
     #[prost(string, tag="3")]
-    pub supply: ::prost::alloc::string::String,
-    #[prost(uint64, tag="4")]
+    pub amm: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub price_oracle: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub monetary_policy: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="6")]
+    pub borrowed_token: ::core::option::Option<Token>,
+    #[prost(message, optional, tag="7")]
+    pub collateral_token: ::core::option::Option<Token>,
+    #[prost(uint64, tag="8")]
+    pub index: u64,
+    #[prost(uint64, tag="9")]
     pub created_at_timestamp: u64,
-    #[prost(uint64, tag="5")]
+    #[prost(uint64, tag="10")]
     pub created_at_block_number: u64,
-    #[prost(uint64, tag="6")]
+    #[prost(uint64, tag="11")]
     pub log_ordinal: u64,
 }
-/// This includes pool and gauge deployments, and GaugeController add events.
-/// When already deployed gauges are added to the controller, they become eligible for CRV rewards.
-/// We also track UpdateMiningParametersEvent from the CRV contract, to keep track of inflation.
+/// Lending Controllers emit the same events as crvUSD Controllers, with the borrowed token in place of crvUSD.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct CurveEvents {
+pub struct LendingEvents {
     #[prost(message, repeated, tag="1")]
-    pub pools: ::prost::alloc::vec::Vec<Pool>,
+    pub vault_events: ::prost::alloc::vec::Vec<VaultEvent>,
     #[prost(message, repeated, tag="2")]
-    pub gauges: ::prost::alloc::vec::Vec<LiquidityGauge>,
+    pub controller_loan_events: ::prost::alloc::vec::Vec<ControllerLoanEvent>,
     #[prost(message, repeated, tag="3")]
-    pub controller_gauges: ::prost::alloc::vec::Vec<ControllerNewGauge>,
-    #[prost(message, optional, tag="4")]
-    pub update_mining_parameters_event: ::core::option::Option<UpdateMiningParametersEvent>,
+    pub controller_liquidation_events: ::prost::alloc::vec::Vec<ControllerLiquidationEvent>,
+    #[prost(message, repeated, tag="4")]
+    pub market_states: ::prost::alloc::vec::Vec<LendingMarketState>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VaultEvent {
+    #[prost(string, tag="1")]
+    pub vault: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub controller: ::prost::alloc::string::String,
+    #[prost(enumeration="vault_event::VaultEventType", tag="3")]
+    pub r#type: i32,
+    #[prost(string, tag="4")]
+    pub sender: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub owner: ::prost::alloc::string::String,
+    /// Only set for withdrawals
+    #[prost(string, tag="6")]
+    pub receiver: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="7")]
+    pub assets: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="8")]
+    pub shares: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="10")]
+    pub tx_index: u32,
+    #[prost(uint32, tag="11")]
+    pub log_index: u32,
+    #[prost(uint64, tag="12")]
+    pub log_ordinal: u64,
+    #[prost(uint64, tag="13")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="14")]
+    pub block_number: u64,
+}
+/// Nested message and enum types in `VaultEvent`.
+pub mod vault_event {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum VaultEventType {
+        Deposit = 0,
+        Withdraw = 1,
+    }
+    impl VaultEventType {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                VaultEventType::Deposit => "DEPOSIT",
+                VaultEventType::Withdraw => "WITHDRAW",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "DEPOSIT" => Some(Self::Deposit),
+                "WITHDRAW" => Some(Self::Withdraw),
+                _ => None,
+            }
+        }
+    }
+}
+/// Supply, debt and rates change with every vault and loan event, and debt also accrues interest, so
+/// they are read from the contracts once per block for each market with activity.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LendingMarketState {
+    #[prost(string, tag="1")]
+    pub vault: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub controller: ::prost::alloc::string::String,
+    /// Borrowed token supplied to the vault, including what is lent out
+    #[prost(string, tag="3")]
+    pub total_assets: ::prost::alloc::string::String,
+    /// Total supply of vault shares
+    #[prost(string, tag="4")]
+    pub total_shares: ::prost::alloc::string::String,
+    /// String representation of BigInt
+    #[prost(string, tag="5")]
+    pub total_debt: ::prost::alloc::string::String,
+    /// 1e18 based
+    #[prost(string, tag="6")]
+    pub borrow_apr: ::prost::alloc::string::String,
+    /// 1e18 based
+    #[prost(string, tag="7")]
+    pub lend_apr: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="9")]
+    pub block_number: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -315,6 +1238,9 @@ pub struct PoolFees {
     pub protocol_fee: ::core::option::Option<PoolFee>,
     #[prost(message, optional, tag="3")]
     pub lp_fee: ::core::option::Option<PoolFee>,
+    /// BigDecimal string representation, only set for pools with dynamic fees
+    #[prost(string, optional, tag="4")]
+    pub offpeg_fee_multiplier: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -587,6 +1513,151 @@ pub mod events {
         pub admin_fee: ::core::option::Option<::prost::alloc::string::String>,
         #[prost(string, tag="9")]
         pub pool_address: ::prost::alloc::string::String,
+        #[prost(string, optional, tag="10")]
+        pub offpeg_fee_multiplier: ::core::option::Option<::prost::alloc::string::String>,
+    }
+}
+/// Pool events flattened into one message per token leg, for columnar and file based sinks.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FlatPoolEvents {
+    #[prost(message, repeated, tag="1")]
+    pub flat_pool_events: ::prost::alloc::vec::Vec<FlatPoolEvent>,
+}
+/// Swaps have a leg for each of the tokens sold and bought, while deposits and withdraws have a
+/// leg for each input token and one for the LP token minted or burned.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FlatPoolEvent {
+    #[prost(string, tag="1")]
+    pub pool_address: ::prost::alloc::string::String,
+    #[prost(enumeration="flat_pool_event::EventType", tag="2")]
+    pub event_type: i32,
+    /// Whether the token was transferred into or out of the pool
+    #[prost(enumeration="flat_pool_event::Direction", tag="3")]
+    pub direction: i32,
+    #[prost(string, tag="4")]
+    pub token_address: ::prost::alloc::string::String,
+    /// Raw amount in the token's native units, as a decimal string since it may exceed 64 bits
+    #[prost(string, tag="5")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(uint64, tag="6")]
+    pub decimals: u64,
+    /// USD values are decimal strings, to keep the precision of the pool event they come from
+    #[prost(string, tag="7")]
+    pub amount_usd: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub token_price: ::prost::alloc::string::String,
+    #[prost(enumeration="events::pool_event::PriceSource", tag="9")]
+    pub price_source: i32,
+    #[prost(string, tag="10")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(string, tag="11")]
+    pub transaction_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="12")]
+    pub log_index: u32,
+    #[prost(uint64, tag="13")]
+    pub log_ordinal: u64,
+    #[prost(uint64, tag="14")]
+    pub timestamp: u64,
+    #[prost(uint64, tag="15")]
+    pub block_number: u64,
+}
+/// Nested message and enum types in `FlatPoolEvent`.
+pub mod flat_pool_event {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum EventType {
+        Swap = 0,
+        SwapUnderlyingMeta = 1,
+        SwapUnderlyingLending = 2,
+        Deposit = 3,
+        Withdraw = 4,
+    }
+    impl EventType {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                EventType::Swap => "SWAP",
+                EventType::SwapUnderlyingMeta => "SWAP_UNDERLYING_META",
+                EventType::SwapUnderlyingLending => "SWAP_UNDERLYING_LENDING",
+                EventType::Deposit => "DEPOSIT",
+                EventType::Withdraw => "WITHDRAW",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "SWAP" => Some(Self::Swap),
+                "SWAP_UNDERLYING_META" => Some(Self::SwapUnderlyingMeta),
+                "SWAP_UNDERLYING_LENDING" => Some(Self::SwapUnderlyingLending),
+                "DEPOSIT" => Some(Self::Deposit),
+                "WITHDRAW" => Some(Self::Withdraw),
+                _ => None,
+            }
+        }
+    }
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Direction {
+        In = 0,
+        Out = 1,
+    }
+    impl Direction {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Direction::In => "IN",
+                Direction::Out => "OUT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "IN" => Some(Self::In),
+                "OUT" => Some(Self::Out),
+                _ => None,
+            }
+        }
+    }
+}
+/// Asset types supported by StableSwap-NG pools, as returned by `get_pool_asset_types`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AssetType {
+    Standard = 0,
+    Oracle = 1,
+    Rebasing = 2,
+    Erc4626 = 3,
+}
+impl AssetType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AssetType::Standard => "STANDARD",
+            AssetType::Oracle => "ORACLE",
+            AssetType::Rebasing => "REBASING",
+            AssetType::Erc4626 => "ERC4626",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "STANDARD" => Some(Self::Standard),
+            "ORACLE" => Some(Self::Oracle),
+            "REBASING" => Some(Self::Rebasing),
+            "ERC4626" => Some(Self::Erc4626),
+            _ => None,
+        }
     }
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
@@ -615,6 +1686,37 @@ impl GaugeLiquidityEventType {
         }
     }
 }
+/// Protocols that stake LP tokens in gauges on behalf of their own depositors, through a single
+/// wrapper contract such as Convex's VoterProxy or the Stake DAO locker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum StakingWrapper {
+    Direct = 0,
+    Convex = 1,
+    StakeDao = 2,
+}
+impl StakingWrapper {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            StakingWrapper::Direct => "DIRECT",
+            StakingWrapper::Convex => "CONVEX",
+            StakingWrapper::StakeDao => "STAKE_DAO",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DIRECT" => Some(Self::Direct),
+            "CONVEX" => Some(Self::Convex),
+            "STAKE_DAO" => Some(Self::StakeDao),
+            _ => None,
+        }
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum GaugeType {
@@ -674,6 +1776,42 @@ impl GaugeType {
         }
     }
 }
+/// Matches the deposit types emitted by the VotingEscrow contract, with an extra type for withdrawals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum VotingEscrowLockEventType {
+    DepositFor = 0,
+    CreateLock = 1,
+    IncreaseLockAmount = 2,
+    IncreaseUnlockTime = 3,
+    WithdrawLock = 4,
+}
+impl VotingEscrowLockEventType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            VotingEscrowLockEventType::DepositFor => "DEPOSIT_FOR",
+            VotingEscrowLockEventType::CreateLock => "CREATE_LOCK",
+            VotingEscrowLockEventType::IncreaseLockAmount => "INCREASE_LOCK_AMOUNT",
+            VotingEscrowLockEventType::IncreaseUnlockTime => "INCREASE_UNLOCK_TIME",
+            VotingEscrowLockEventType::WithdrawLock => "WITHDRAW_LOCK",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DEPOSIT_FOR" => Some(Self::DepositFor),
+            "CREATE_LOCK" => Some(Self::CreateLock),
+            "INCREASE_LOCK_AMOUNT" => Some(Self::IncreaseLockAmount),
+            "INCREASE_UNLOCK_TIME" => Some(Self::IncreaseUnlockTime),
+            "WITHDRAW_LOCK" => Some(Self::WithdrawLock),
+            _ => None,
+        }
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum LiquidityPoolFeeType {
@@ -0,0 +1,19 @@
+use substreams::scalar::BigInt;
+
+use crate::{abi::curve::crv_token, network_config::CRV_TOKEN_ADDRESS};
+
+pub fn get_crv_rate() -> Option<BigInt> {
+    let rate = crv_token::functions::Rate {}.call(CRV_TOKEN_ADDRESS.to_vec());
+    if rate.is_none() {
+        substreams::log::debug!("Failed to get rate from CRV token");
+    }
+    rate
+}
+
+pub fn get_crv_start_epoch_time() -> Option<BigInt> {
+    let start_epoch_time = crv_token::functions::StartEpochTime {}.call(CRV_TOKEN_ADDRESS.to_vec());
+    if start_epoch_time.is_none() {
+        substreams::log::debug!("Failed to get start epoch time from CRV token");
+    }
+    start_epoch_time
+}
@@ -0,0 +1,32 @@
+use substreams::{scalar::BigInt, Hex};
+
+use crate::abi::curve::crvusd::controller;
+
+// Returns the collateral and crvUSD held in the user's bands, and the user's debt.
+pub fn get_user_state(controller_address: &Vec<u8>, user: &Vec<u8>) -> (BigInt, BigInt, BigInt) {
+    match (controller::functions::UserState { user: user.clone() }.call(controller_address.clone()))
+    {
+        Some([collateral, stablecoin, debt, _]) => (collateral, stablecoin, debt),
+        None => {
+            substreams::log::debug!(
+                "Failed to get user state for user {} from controller {}",
+                Hex::encode(&user),
+                Hex::encode(&controller_address)
+            );
+            (BigInt::zero(), BigInt::zero(), BigInt::zero())
+        }
+    }
+}
+
+pub fn get_total_debt(controller_address: &Vec<u8>) -> BigInt {
+    match (controller::functions::TotalDebt {}.call(controller_address.clone())) {
+        Some(total_debt) => total_debt,
+        None => {
+            substreams::log::debug!(
+                "Failed to get total debt from controller {}",
+                Hex::encode(&controller_address)
+            );
+            BigInt::zero()
+        }
+    }
+}
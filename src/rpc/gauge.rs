@@ -6,12 +6,15 @@ use crate::{
     types::gauge::RewardData,
 };
 
+// Gauge weights are only tracked by the GaugeController on mainnet. Networks without one skip the
+// RPC call altogether, and report zero weights.
 pub fn get_gauge_relative_weight(gauge_address: &Vec<u8>) -> BigInt {
-    match (gauge_controller::functions::GaugeRelativeWeight1 {
-        addr: gauge_address.clone(),
-    }
-    .call(GAUGE_CONTROLLER_ADDRESS.to_vec()))
-    {
+    match GAUGE_CONTROLLER_ADDRESS.and_then(|controller| {
+        gauge_controller::functions::GaugeRelativeWeight1 {
+            addr: gauge_address.clone(),
+        }
+        .call(controller.to_vec())
+    }) {
         Some(weight) => weight,
         None => {
             substreams::log::debug!(
@@ -23,6 +26,96 @@ pub fn get_gauge_relative_weight(gauge_address: &Vec<u8>) -> BigInt {
     }
 }
 
+pub fn get_gauge_weight(gauge_address: &Vec<u8>) -> BigInt {
+    match GAUGE_CONTROLLER_ADDRESS.and_then(|controller| {
+        gauge_controller::functions::GetGaugeWeight {
+            addr: gauge_address.clone(),
+        }
+        .call(controller.to_vec())
+    }) {
+        Some(weight) => weight,
+        None => {
+            substreams::log::debug!(
+                "Failed to get gauge {} weight from gauge controller",
+                Hex::encode(&gauge_address)
+            );
+            BigInt::zero()
+        }
+    }
+}
+
+pub fn get_total_gauge_weight() -> BigInt {
+    match GAUGE_CONTROLLER_ADDRESS.and_then(|controller| {
+        gauge_controller::functions::GetTotalWeight {}.call(controller.to_vec())
+    }) {
+        Some(weight) => weight,
+        None => {
+            substreams::log::debug!("Failed to get total weight from gauge controller");
+            BigInt::zero()
+        }
+    }
+}
+
+pub fn get_vote_user_slope(user_address: &Vec<u8>, gauge_address: &Vec<u8>) -> BigInt {
+    match GAUGE_CONTROLLER_ADDRESS.and_then(|controller| {
+        gauge_controller::functions::VoteUserSlopes {
+            arg0: user_address.clone(),
+            arg1: gauge_address.clone(),
+        }
+        .call(controller.to_vec())
+    }) {
+        Some((slope, _power, _end)) => slope,
+        None => {
+            substreams::log::debug!(
+                "Failed to get vote slope for user {} on gauge {} from gauge controller",
+                Hex::encode(&user_address),
+                Hex::encode(&gauge_address)
+            );
+            BigInt::zero()
+        }
+    }
+}
+
+pub fn get_gauge_working_supply(gauge_address: &Vec<u8>) -> BigInt {
+    // The `working_supply` function ABI is the same across all gauge versions
+    match (gauges::liquidity_gauge_v1::functions::WorkingSupply {}.call(gauge_address.clone())) {
+        Some(working_supply) => working_supply,
+        None => {
+            substreams::log::debug!(
+                "Failed to get working supply for gauge {}",
+                Hex::encode(&gauge_address)
+            );
+            BigInt::zero()
+        }
+    }
+}
+
+pub fn get_gauge_total_supply(gauge_address: &Vec<u8>) -> BigInt {
+    // The `totalSupply` function ABI is the same across all gauge versions
+    match (gauges::liquidity_gauge_v1::functions::TotalSupply {}.call(gauge_address.clone())) {
+        Some(total_supply) => total_supply,
+        None => {
+            substreams::log::debug!(
+                "Failed to get total supply for gauge {}",
+                Hex::encode(&gauge_address)
+            );
+            BigInt::zero()
+        }
+    }
+}
+
+pub fn get_gauge_lp_token(gauge_address: &Vec<u8>) -> Option<Vec<u8>> {
+    // The `lp_token` function ABI is the same across all gauge versions
+    let lp_token = gauges::liquidity_gauge_v6::functions::LpToken {}.call(gauge_address.clone());
+    if lp_token.is_none() {
+        substreams::log::debug!(
+            "Failed to get LP token for gauge {}",
+            Hex::encode(&gauge_address)
+        );
+    }
+    lp_token
+}
+
 pub fn get_reward_token_data(
     gauge_address: &Vec<u8>,
     token_address: &Vec<u8>,
@@ -0,0 +1,79 @@
+use substreams::{scalar::BigInt, Hex};
+use substreams_ethereum::rpc::RpcBatch;
+
+use crate::{
+    abi::curve::{crvusd::controller, lending::vault},
+    pb::curve::types::v1::{LendingMarket, LendingMarketState},
+};
+
+use super::common::decode_rpc_response;
+
+// Reads the vault supply, market debt and current rates in a single batch.
+pub fn get_market_state(
+    market: &LendingMarket,
+    timestamp: u64,
+    block_number: u64,
+) -> LendingMarketState {
+    let vault_address = Hex::decode(&market.vault).unwrap();
+    let controller_address = Hex::decode(&market.controller).unwrap();
+
+    let mut state = LendingMarketState {
+        vault: market.vault.clone(),
+        controller: market.controller.clone(),
+        total_assets: BigInt::zero().to_string(),
+        total_shares: BigInt::zero().to_string(),
+        total_debt: BigInt::zero().to_string(),
+        borrow_apr: BigInt::zero().to_string(),
+        lend_apr: BigInt::zero().to_string(),
+        timestamp,
+        block_number,
+    };
+
+    let responses = match RpcBatch::new()
+        .add(vault::functions::TotalAssets {}, vault_address.clone())
+        .add(vault::functions::TotalSupply {}, vault_address.clone())
+        .add(controller::functions::TotalDebt {}, controller_address)
+        .add(vault::functions::BorrowApr {}, vault_address.clone())
+        .add(vault::functions::LendApr {}, vault_address)
+        .execute()
+    {
+        Ok(response) => response.responses,
+        Err(e) => {
+            substreams::log::debug!(
+                "RPC batch execution error in `get_market_state` for vault {}: {:?}",
+                market.vault,
+                e
+            );
+            return state;
+        }
+    };
+
+    let log_message = format!("Failed to read market state of vault {}", market.vault);
+    if let Some(total_assets) =
+        decode_rpc_response::<_, vault::functions::TotalAssets>(&responses[0], &log_message)
+    {
+        state.total_assets = total_assets.to_string();
+    }
+    if let Some(total_shares) =
+        decode_rpc_response::<_, vault::functions::TotalSupply>(&responses[1], &log_message)
+    {
+        state.total_shares = total_shares.to_string();
+    }
+    if let Some(total_debt) =
+        decode_rpc_response::<_, controller::functions::TotalDebt>(&responses[2], &log_message)
+    {
+        state.total_debt = total_debt.to_string();
+    }
+    if let Some(borrow_apr) =
+        decode_rpc_response::<_, vault::functions::BorrowApr>(&responses[3], &log_message)
+    {
+        state.borrow_apr = borrow_apr.to_string();
+    }
+    if let Some(lend_apr) =
+        decode_rpc_response::<_, vault::functions::LendApr>(&responses[4], &log_message)
+    {
+        state.lend_apr = lend_apr.to_string();
+    }
+
+    state
+}
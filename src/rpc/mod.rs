@@ -1,6 +1,10 @@
 pub mod common;
+pub mod crv_token;
+pub mod crvusd;
 pub mod gauge;
+pub mod lending;
 pub mod oracle;
 pub mod token;
 pub mod pool;
-pub mod registry;
\ No newline at end of file
+pub mod registry;
+pub mod voting_escrow;
\ No newline at end of file
@@ -22,8 +22,10 @@ pub fn get_usd_price_from_yearn(token_address: Vec<u8>) -> Option<BigDecimal> {
         return None;
     }
 
-    let price_opt =
-        yearn_lens::functions::GetPriceUsdcRecommended { token_address }.call(YEARN_LENS.to_vec());
+    let price_opt = YEARN_LENS.and_then(|oracle_address| {
+        yearn_lens::functions::GetPriceUsdcRecommended { token_address }
+            .call(oracle_address.to_vec())
+    });
 
     if let Some(price) = price_opt {
         return Some(price.to_decimal(default_usdc_decimals()));
@@ -38,7 +40,9 @@ pub fn get_usd_price_from_sushi(token_address: Vec<u8>) -> Option<BigDecimal> {
     {
         return None;
     }
-    let price_opt = sushiswap::functions::GetPriceUsdc { token_address }.call(SUSHISWAP.to_vec());
+    let price_opt = SUSHISWAP.and_then(|oracle_address| {
+        sushiswap::functions::GetPriceUsdc { token_address }.call(oracle_address.to_vec())
+    });
 
     if let Some(price) = price_opt {
         return Some(price.to_decimal(default_usdc_decimals()));
@@ -54,10 +58,12 @@ pub fn get_usd_price_from_curve_calc(token_address: Vec<u8>) -> Option<BigDecima
         return None;
     }
 
-    let price_opt = curve_calculations::functions::GetPriceUsdc {
-        asset_address: token_address.to_vec(),
-    }
-    .call(CURVE_CALCULATIONS.to_vec());
+    let price_opt = CURVE_CALCULATIONS.and_then(|oracle_address| {
+        curve_calculations::functions::GetPriceUsdc {
+            asset_address: token_address.to_vec(),
+        }
+        .call(oracle_address.to_vec())
+    });
 
     if let Some(price) = price_opt {
         return Some(price.to_decimal(default_usdc_decimals()));
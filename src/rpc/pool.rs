@@ -14,7 +14,9 @@ use crate::{
     common::format::format_address_vec,
     constants::{self, FEE_DECIMALS},
     key_management::entity_key_manager::EntityKey,
-    pb::curve::types::v1::{LiquidityPoolFeeType, PoolFee, PoolFees, Token},
+    pb::curve::types::v1::{
+        CryptoPool, LiquidityPoolFeeType, PoolFee, PoolFees, Token, TriCryptoPool,
+    },
 };
 
 use super::{common::decode_rpc_response, token::create_token};
@@ -124,6 +126,18 @@ pub fn get_old_metapool_underlying_coins(pool_address: &Vec<u8>) -> Result<Vec<T
     Ok(tokens)
 }
 
+// Rates used by StableSwap-NG pools to normalise balances, scaled by `10^(36 - decimals)`.
+pub fn get_pool_stored_rates(pool_address: &Vec<u8>) -> Option<Vec<BigInt>> {
+    functions::StoredRates {}.call(pool_address.clone())
+}
+
+pub fn get_pool_balance(pool_address: &Vec<u8>, index: usize) -> Option<BigInt> {
+    functions::Balances1 {
+        arg0: BigInt::from(index as u64),
+    }
+    .call(pool_address.clone())
+}
+
 pub fn get_pool_fee_and_admin_fee(pool_address: &Vec<u8>) -> Result<(BigInt, BigInt), Error> {
     let batch = RpcBatch::new();
     let responses = batch
@@ -178,14 +192,98 @@ pub fn get_pool_fee_and_admin_fee(pool_address: &Vec<u8>) -> Result<(BigInt, Big
     Ok((total_fee, admin_fee))
 }
 
+// Only pools with dynamic fees, such as StableSwap-NG and Aave-style lending pools, have an off-peg multiplier.
+pub fn get_pool_offpeg_fee_multiplier(pool_address: &Vec<u8>) -> Option<BigInt> {
+    functions::OffpegFeeMultiplier {}.call(pool_address.clone())
+}
+
+pub fn get_crypto_pool(pool_address: &Vec<u8>) -> CryptoPool {
+    let [a, gamma, mid_fee, out_fee, fee_gamma] = get_crypto_pool_curve_params(pool_address);
+    CryptoPool {
+        a,
+        gamma,
+        mid_fee,
+        out_fee,
+        fee_gamma,
+        price_scale: functions::PriceScale1 {}
+            .call(pool_address.clone())
+            .map(|price_scale| price_scale.to_string())
+            .unwrap_or_default(),
+    }
+}
+
+pub fn get_tricrypto_pool(pool_address: &Vec<u8>, n_coins: usize) -> TriCryptoPool {
+    let [a, gamma, mid_fee, out_fee, fee_gamma] = get_crypto_pool_curve_params(pool_address);
+    // `price_scale(k)` holds the price of coin `k + 1` in terms of coin 0.
+    let price_scale = (0..n_coins.saturating_sub(1))
+        .map(|k| {
+            functions::PriceScale2 {
+                k: BigInt::from(k as u64),
+            }
+            .call(pool_address.clone())
+            .map(|price_scale| price_scale.to_string())
+            .unwrap_or_default()
+        })
+        .collect();
+    TriCryptoPool {
+        a,
+        gamma,
+        mid_fee,
+        out_fee,
+        fee_gamma,
+        price_scale,
+    }
+}
+
+// Fetches the `A`, `gamma`, `mid_fee`, `out_fee` and `fee_gamma` parameters shared by all crypto pools.
+// A parameter is returned as an empty string if its `eth_call` fails.
+fn get_crypto_pool_curve_params(pool_address: &Vec<u8>) -> [String; 5] {
+    let responses = match RpcBatch::new()
+        .add(functions::A {}, pool_address.clone())
+        .add(functions::Gamma {}, pool_address.clone())
+        .add(functions::MidFee {}, pool_address.clone())
+        .add(functions::OutFee {}, pool_address.clone())
+        .add(functions::FeeGamma {}, pool_address.clone())
+        .execute()
+    {
+        Ok(responses) => responses.responses,
+        Err(e) => {
+            substreams::log::debug!(
+                "RPC batch execution error in `get_crypto_pool_curve_params` for pool {}: {:?}",
+                Hex::encode(pool_address),
+                e
+            );
+            return Default::default();
+        }
+    };
+
+    let log_message = |param: &str| {
+        format!(
+            "{} is not a crypto pool contract {} `eth_call` failed",
+            Hex::encode(pool_address),
+            param
+        )
+    };
+    [
+        decode_rpc_response::<_, functions::A>(&responses[0], &log_message("A")),
+        decode_rpc_response::<_, functions::Gamma>(&responses[1], &log_message("gamma")),
+        decode_rpc_response::<_, functions::MidFee>(&responses[2], &log_message("mid fee")),
+        decode_rpc_response::<_, functions::OutFee>(&responses[3], &log_message("out fee")),
+        decode_rpc_response::<_, functions::FeeGamma>(&responses[4], &log_message("fee gamma")),
+    ]
+    .map(|param| param.map(|value| value.to_string()).unwrap_or_default())
+}
+
 // Computes trading (total), protocol (admin), and LP fees for a given liquidity pool from total and admin fee values.
 // - `total_fee`: The raw BigInt fee charged by the pool.
 // - `admin_fee`: The portion of the total fee allocated to the protocol.
+// - `offpeg_fee_multiplier`: The raw BigInt multiplier applied to the fee when the pool is imbalanced, if any.
 // - `pool_address`: The address of the liquidity pool.
 // Returns a `PoolFees` struct containing detailed fee information.
 pub fn calculate_pool_fees(
     total_fee: BigInt,
     admin_fee: BigInt,
+    offpeg_fee_multiplier: Option<BigInt>,
     pool_address: &Vec<u8>,
 ) -> PoolFees {
     // Shadowing as do not need BigInt val anymore.
@@ -244,5 +342,7 @@ pub fn calculate_pool_fees(
         trading_fee: Some(trading_fee),
         protocol_fee: Some(protocol_fee),
         lp_fee: Some(lp_fee),
+        offpeg_fee_multiplier: offpeg_fee_multiplier
+            .map(|multiplier| multiplier.to_decimal(FEE_DECIMALS).to_string()),
     }
 }
@@ -1,9 +1,12 @@
 use anyhow::anyhow;
-use substreams::{errors::Error, Hex};
+use substreams::{errors::Error, scalar::BigInt, Hex};
 use substreams_ethereum::NULL_ADDRESS;
 
 use crate::{
-    abi::curve::{address_provider as address_provider_abi, registry},
+    abi::curve::{
+        address_provider as address_provider_abi, child_registries::stable_swap_factory_ng,
+        registry,
+    },
     constants,
 };
 
@@ -49,3 +52,19 @@ pub fn get_lp_token_address_from_registry(
     }
     Ok(address)
 }
+
+// Asset types of each coin in a StableSwap-NG pool, indexed the same as the pool's `coins`.
+pub fn get_ng_pool_asset_types(pool_address: &Vec<u8>, factory_address: &Vec<u8>) -> Vec<BigInt> {
+    stable_swap_factory_ng::functions::GetPoolAssetTypes {
+        u_pool: pool_address.clone(),
+    }
+    .call(factory_address.clone())
+    .unwrap_or_else(|| {
+        substreams::log::debug!(
+            "Unable to get asset types for pool {} from factory {}",
+            Hex::encode(&pool_address),
+            Hex::encode(&factory_address)
+        );
+        Vec::new()
+    })
+}
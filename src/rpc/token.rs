@@ -6,7 +6,7 @@ use crate::{
     abi::common::erc20::functions,
     common::utils,
     constants,
-    pb::curve::types::v1::Token,
+    pb::curve::types::v1::{AssetType, Token},
     rpc::{common::decode_rpc_response, registry::is_main_registry_pool},
 };
 
@@ -37,6 +37,7 @@ pub fn create_token(
             total_supply: total_supply.to_string(),
             is_base_pool_lp_token: false,
             gauge,
+            asset_type: AssetType::Standard as i32,
         });
     }
 
@@ -97,6 +98,7 @@ pub fn create_token(
         is_base_pool_lp_token: utils::is_base_pool_lp_token(&token_address)
             || is_main_registry_pool(&pool_address),
         gauge,
+        asset_type: AssetType::Standard as i32,
     });
 }
 
@@ -0,0 +1,34 @@
+use substreams::{scalar::BigInt, Hex};
+
+use crate::{abi::curve::voting_escrow, network_config::VOTING_ESCROW_ADDRESS};
+
+// Returns the locked CRV amount and unlock time of a provider's lock.
+pub fn get_locked(provider: &Vec<u8>) -> (BigInt, BigInt) {
+    match VOTING_ESCROW_ADDRESS.and_then(|voting_escrow_address| {
+        voting_escrow::functions::Locked {
+            arg0: provider.clone(),
+        }
+        .call(voting_escrow_address.to_vec())
+    }) {
+        Some((amount, end)) => (amount, end),
+        None => {
+            substreams::log::debug!(
+                "Failed to get lock for provider {} from voting escrow",
+                Hex::encode(&provider)
+            );
+            (BigInt::zero(), BigInt::zero())
+        }
+    }
+}
+
+pub fn get_total_voting_power() -> BigInt {
+    match VOTING_ESCROW_ADDRESS.and_then(|voting_escrow_address| {
+        voting_escrow::functions::TotalSupply {}.call(voting_escrow_address.to_vec())
+    }) {
+        Some(total_supply) => total_supply,
+        None => {
+            substreams::log::debug!("Failed to get total supply from voting escrow");
+            BigInt::zero()
+        }
+    }
+}
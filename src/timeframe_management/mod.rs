@@ -19,9 +19,13 @@
 //   dynamic actions such as snapshot creation or data pruning to be automatically triggered
 //   in response to the passage of time.
 //
+// - `RollingWindow`: Calculates rolling window aggregates (e.g. 7d/30d volume, 7d unique users)
+//   from the daily data retained in the stores, and updates the protocol and pool entities with them.
+//
 // - `Utils`: Utility functions supporting both snapshot creation and pruning operations.
 //
 pub mod pruning;
+pub mod rolling_window;
 pub mod snapshot;
 pub mod timeframe_change_handler;
 pub mod utils;
@@ -1,6 +1,7 @@
 use substreams::store::{DeltaInt64, Deltas};
 
 use crate::{
+    constants::DEFAULT_PRUNE_RETENTION,
     timeframe_management::{
         timeframe_change_handler::TimeframeChangeHandler, utils::separate_timeframe_deltas,
     },
@@ -12,6 +13,12 @@ pub mod pruners;
 // Pruner trait allows for implementing custom store pruning logic based on timeframes.
 pub trait Pruner {
     fn prune(&self, prune_time_frame_id: i64, timeframe: Timeframe);
+
+    // The number of completed timeframes to keep in the store before they are pruned.
+    // Pruners can override this to retain data for longer, such as for rolling window aggregates.
+    fn retention(&self, _timeframe: &Timeframe) -> i64 {
+        DEFAULT_PRUNE_RETENTION
+    }
 }
 
 // Configures and initiates the pruning process based on detected changes in timeframes.
@@ -21,35 +28,60 @@ pub fn setup_timeframe_pruning<'a>(
     current_time_deltas: &'a Deltas<DeltaInt64>,
     pruners: &[&'a dyn Pruner],
 ) {
-    let (daily_deltas, hourly_deltas) = separate_timeframe_deltas(current_time_deltas);
+    let (daily_deltas, hourly_deltas, weekly_deltas, monthly_deltas) =
+        separate_timeframe_deltas(current_time_deltas);
 
     // Defines a closure to execute daily pruning actions.
     let on_new_day = Box::new(move |time_frame_id: i64| {
-        // Calculate the timeframe ID for pruning to ensure we're pruning data
-        // from two timeframes ago, avoiding premature data deletion.
-        let time_frame_id = time_frame_id - 1;
         for pruner in pruners {
-            pruner.prune(time_frame_id, Timeframe::Daily)
+            // Calculate the timeframe ID for pruning based on the pruner's retention, ensuring we
+            // only prune data that falls outside of the timeframes it needs to keep.
+            let prune_time_frame_id = time_frame_id - pruner.retention(&Timeframe::Daily);
+            pruner.prune(prune_time_frame_id, Timeframe::Daily)
         }
     });
 
     // Defines a closure to execute hourly pruning actions.
     let on_new_hour = Box::new(move |time_frame_id: i64| {
-        // Calculate the timeframe ID for pruning to ensure we're pruning data
-        // from two timeframes ago, avoiding premature data deletion.
-        let time_frame_id = time_frame_id - 1;
         for pruner in pruners {
-            pruner.prune(time_frame_id, Timeframe::Hourly)
+            // Calculate the timeframe ID for pruning based on the pruner's retention, ensuring we
+            // only prune data that falls outside of the timeframes it needs to keep.
+            let prune_time_frame_id = time_frame_id - pruner.retention(&Timeframe::Hourly);
+            pruner.prune(prune_time_frame_id, Timeframe::Hourly)
+        }
+    });
+
+    // Defines a closure to execute weekly pruning actions.
+    let on_new_week = Box::new(move |time_frame_id: i64| {
+        for pruner in pruners {
+            // Calculate the timeframe ID for pruning based on the pruner's retention, ensuring we
+            // only prune data that falls outside of the timeframes it needs to keep.
+            let prune_time_frame_id = time_frame_id - pruner.retention(&Timeframe::Weekly);
+            pruner.prune(prune_time_frame_id, Timeframe::Weekly)
+        }
+    });
+
+    // Defines a closure to execute monthly pruning actions.
+    let on_new_month = Box::new(move |time_frame_id: i64| {
+        for pruner in pruners {
+            // Calculate the timeframe ID for pruning based on the pruner's retention, ensuring we
+            // only prune data that falls outside of the timeframes it needs to keep.
+            let prune_time_frame_id = time_frame_id - pruner.retention(&Timeframe::Monthly);
+            pruner.prune(prune_time_frame_id, Timeframe::Monthly)
         }
     });
 
-    // Initialise the TimeframeChangeHandler with the daily and hourly deltas and closures.
+    // Initialise the TimeframeChangeHandler with the timeframe deltas and closures.
     // This handler will listen for changes in the timeframe and trigger the appropriate pruning actions.
     let mut timeframe_change_handler = TimeframeChangeHandler {
         daily_deltas: &daily_deltas,
         hourly_deltas: &hourly_deltas,
         on_new_day,
         on_new_hour: Some(on_new_hour),
+        weekly_deltas: &weekly_deltas,
+        monthly_deltas: &monthly_deltas,
+        on_new_week: Some(on_new_week),
+        on_new_month: Some(on_new_month),
     };
 
     // Executes the timeframe change handler to process any detected changes and perform pruning.
@@ -0,0 +1,24 @@
+use substreams::store::{StoreAddBigInt, StoreDelete};
+
+use crate::{
+    key_management::store_key_manager::StoreKey, timeframe_management::pruning::Pruner,
+    types::timeframe::Timeframe,
+};
+
+pub struct GaugeCrvMintedPruneAction<'a> {
+    pub store: &'a StoreAddBigInt,
+}
+
+impl<'a> Pruner for GaugeCrvMintedPruneAction<'a> {
+    // Prunes the daily CRV minted per gauge. Only the cumulative amounts are kept indefinitely.
+    fn prune(&self, prune_time_frame_id: i64, timeframe: Timeframe) {
+        match timeframe {
+            Timeframe::Daily => self.store.delete_prefix(
+                0,
+                &StoreKey::gauge_daily_crv_minted_prune_key(&prune_time_frame_id),
+            ),
+            // Minted CRV is only tracked on a daily basis.
+            Timeframe::Hourly | Timeframe::Weekly | Timeframe::Monthly => {}
+        }
+    }
+}
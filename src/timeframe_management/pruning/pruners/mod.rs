@@ -1,6 +1,12 @@
+pub mod gauge_crv_minted_pruner;
+pub mod pool_active_user_pruner;
+pub mod pool_revenue_usd_pruner;
+pub mod pool_usage_metrics_pruner;
 pub mod pool_volume_usd_pruner;
 pub mod protocol_active_user_pruner;
+pub mod protocol_revenue_usd_pruner;
 pub mod protocol_usage_metrics_pruner;
 pub mod protocol_volume_usd_pruner;
+pub mod rolling_active_users_pruner;
 pub mod token_volume_native_pruner;
 pub mod token_volume_usd_pruner;
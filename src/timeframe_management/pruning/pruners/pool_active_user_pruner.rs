@@ -0,0 +1,23 @@
+use substreams::store::{StoreDelete, StoreSetIfNotExistsInt64};
+
+use crate::{
+    key_management::store_key_manager::StoreKey, timeframe_management::pruning::Pruner,
+    types::timeframe::Timeframe,
+};
+
+pub struct PoolActiveUserPruneAction<'a> {
+    pub store: &'a StoreSetIfNotExistsInt64,
+}
+
+impl<'a> Pruner for PoolActiveUserPruneAction<'a> {
+    // Prunes pool active user data for a specific timeframe.
+    fn prune(&self, prune_time_frame_id: i64, timeframe: Timeframe) {
+        let pool_active_user_key = match timeframe {
+            Timeframe::Daily => StoreKey::pool_active_user_daily_prune_key(&prune_time_frame_id),
+            Timeframe::Hourly => StoreKey::pool_active_user_hourly_prune_key(&prune_time_frame_id),
+            // Active users are not tracked on a weekly or monthly basis.
+            Timeframe::Weekly | Timeframe::Monthly => return,
+        };
+        self.store.delete_prefix(0, &pool_active_user_key);
+    }
+}
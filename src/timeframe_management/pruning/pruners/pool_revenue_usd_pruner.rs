@@ -0,0 +1,25 @@
+use substreams::store::{StoreAddBigDecimal, StoreDelete};
+
+use crate::{
+    key_management::store_key_manager::StoreKey, timeframe_management::pruning::Pruner,
+    types::timeframe::Timeframe,
+};
+
+pub struct PoolRevenueUsdPruner<'a> {
+    pub store: &'a StoreAddBigDecimal,
+}
+
+impl<'a> Pruner for PoolRevenueUsdPruner<'a> {
+    // Prunes daily/hourly/weekly/monthly pool revenue usd data for every revenue side.
+    fn prune(&self, prune_time_frame_id: i64, timeframe: Timeframe) {
+        let pool_revenue_usd_key = match timeframe {
+            Timeframe::Daily => StoreKey::pool_revenue_usd_daily_prune_key(&prune_time_frame_id),
+            Timeframe::Hourly => StoreKey::pool_revenue_usd_hourly_prune_key(&prune_time_frame_id),
+            Timeframe::Weekly => StoreKey::pool_revenue_usd_weekly_prune_key(&prune_time_frame_id),
+            Timeframe::Monthly => {
+                StoreKey::pool_revenue_usd_monthly_prune_key(&prune_time_frame_id)
+            }
+        };
+        self.store.delete_prefix(0, &pool_revenue_usd_key);
+    }
+}
@@ -0,0 +1,35 @@
+use substreams::store::{StoreAddInt64, StoreDelete};
+
+use crate::{
+    key_management::store_key_manager::StoreKey, timeframe_management::pruning::Pruner,
+    types::timeframe::Timeframe,
+};
+
+pub struct PoolUsageMetricsPruneAction<'a> {
+    pub store: &'a StoreAddInt64,
+}
+
+impl<'a> Pruner for PoolUsageMetricsPruneAction<'a> {
+    // Prunes pool usage metrics data for a specific timeframe.
+    fn prune(&self, prune_time_frame_id: i64, timeframe: Timeframe) {
+        let prune_keys = match timeframe {
+            Timeframe::Daily => vec![
+                StoreKey::pool_active_user_daily_count_prune_key(&prune_time_frame_id),
+                StoreKey::pool_swap_daily_count_prune_key(&prune_time_frame_id),
+                StoreKey::pool_deposit_daily_count_prune_key(&prune_time_frame_id),
+                StoreKey::pool_withdraw_daily_count_prune_key(&prune_time_frame_id),
+            ],
+            Timeframe::Hourly => vec![
+                StoreKey::pool_active_user_hourly_count_prune_key(&prune_time_frame_id),
+                StoreKey::pool_swap_hourly_count_prune_key(&prune_time_frame_id),
+                StoreKey::pool_deposit_hourly_count_prune_key(&prune_time_frame_id),
+                StoreKey::pool_withdraw_hourly_count_prune_key(&prune_time_frame_id),
+            ],
+            // Usage metrics are not tracked on a weekly or monthly basis.
+            Timeframe::Weekly | Timeframe::Monthly => return,
+        };
+        for prune_key in prune_keys {
+            self.store.delete_prefix(0, &prune_key);
+        }
+    }
+}
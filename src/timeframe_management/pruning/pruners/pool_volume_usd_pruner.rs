@@ -1,7 +1,10 @@
 use substreams::store::{StoreAddBigDecimal, StoreDelete};
 
 use crate::{
-    key_management::store_key_manager::StoreKey, timeframe_management::pruning::Pruner, types::timeframe::Timeframe
+    constants::{DEFAULT_PRUNE_RETENTION, ROLLING_WINDOW_30D},
+    key_management::store_key_manager::StoreKey,
+    timeframe_management::pruning::Pruner,
+    types::timeframe::Timeframe,
 };
 
 pub struct PoolVolumeUsdPruner<'a> {
@@ -10,11 +13,22 @@ pub struct PoolVolumeUsdPruner<'a> {
 
 impl<'a> Pruner for PoolVolumeUsdPruner<'a> {
     fn prune(&self, prune_time_frame_id: i64, timeframe: Timeframe) {
-        // Prune daily/hourly pool volume usd data
+        // Prune daily/hourly/weekly/monthly pool volume usd data
         let pool_volume_usd_key = match timeframe {
             Timeframe::Daily => StoreKey::pool_volume_usd_daily_prune_key(&prune_time_frame_id),
             Timeframe::Hourly => StoreKey::pool_volume_usd_hourly_prune_key(&prune_time_frame_id),
+            Timeframe::Weekly => StoreKey::pool_volume_usd_weekly_prune_key(&prune_time_frame_id),
+            Timeframe::Monthly => StoreKey::pool_volume_usd_monthly_prune_key(&prune_time_frame_id),
         };
         self.store.delete_prefix(0, &pool_volume_usd_key);
     }
+
+    // Daily pool volumes are kept for the length of the longest rolling window,
+    // as they are summed to calculate the pool's rolling volume.
+    fn retention(&self, timeframe: &Timeframe) -> i64 {
+        match timeframe {
+            Timeframe::Daily => ROLLING_WINDOW_30D - 1,
+            _ => DEFAULT_PRUNE_RETENTION,
+        }
+    }
 }
@@ -15,6 +15,8 @@ impl<'a> Pruner for ProtocolActiveUserPruneAction<'a> {
         let active_user_key = match timeframe {
             Timeframe::Daily => StoreKey::active_user_daily_prune_key(&prune_time_frame_id),
             Timeframe::Hourly => StoreKey::active_user_hourly_prune_key(&prune_time_frame_id),
+            // Active users are not tracked on a weekly or monthly basis.
+            Timeframe::Weekly | Timeframe::Monthly => return,
         };
         self.store.delete_prefix(0, &active_user_key);
     }
@@ -0,0 +1,34 @@
+use substreams::store::{StoreAddBigDecimal, StoreDelete};
+
+use crate::{
+    key_management::store_key_manager::StoreKey,
+    timeframe_management::pruning::Pruner,
+    types::{pool_fees::RevenueSide, timeframe::Timeframe},
+};
+
+pub struct ProtocolRevenueUsdPruner<'a> {
+    pub store: &'a StoreAddBigDecimal,
+}
+
+impl<'a> Pruner for ProtocolRevenueUsdPruner<'a> {
+    // Prunes the protocol revenue usd data of each revenue side for a timeframe.
+    fn prune(&self, prune_time_frame_id: i64, timeframe: Timeframe) {
+        for side in RevenueSide::ALL {
+            let key = match timeframe {
+                Timeframe::Daily => {
+                    StoreKey::protocol_daily_revenue_usd_key(&prune_time_frame_id, &side)
+                }
+                Timeframe::Hourly => {
+                    StoreKey::protocol_hourly_revenue_usd_key(&prune_time_frame_id, &side)
+                }
+                Timeframe::Weekly => {
+                    StoreKey::protocol_weekly_revenue_usd_key(&prune_time_frame_id, &side)
+                }
+                Timeframe::Monthly => {
+                    StoreKey::protocol_monthly_revenue_usd_key(&prune_time_frame_id, &side)
+                }
+            };
+            self.store.delete_prefix(0, &key);
+        }
+    }
+}
@@ -47,6 +47,8 @@ impl<'a> Pruner for ProtocolUsageMetricsPruneAction<'a> {
                     &StoreKey::withdraw_hourly_count_key(&prune_time_frame_id),
                 );
             }
+            // Usage metrics are not tracked on a weekly or monthly basis.
+            Timeframe::Weekly | Timeframe::Monthly => {}
         }
     }
 }
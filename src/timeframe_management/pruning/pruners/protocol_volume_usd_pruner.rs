@@ -1,7 +1,9 @@
 use substreams::store::{StoreAddBigDecimal, StoreDelete};
 
 use crate::{
-    key_management::store_key_manager::StoreKey, timeframe_management::pruning::Pruner,
+    constants::{DEFAULT_PRUNE_RETENTION, ROLLING_WINDOW_30D},
+    key_management::store_key_manager::StoreKey,
+    timeframe_management::pruning::Pruner,
     types::timeframe::Timeframe,
 };
 
@@ -17,7 +19,27 @@ impl<'a> Pruner for ProtocolVolumeUsdPruneAction<'a> {
                 let key = StoreKey::protocol_daily_volume_usd_key(&prune_time_frame_id);
                 self.store.delete_prefix(0, &key);
             }
-            Timeframe::Hourly => {}
+            Timeframe::Hourly => {
+                let key = StoreKey::protocol_hourly_volume_usd_key(&prune_time_frame_id);
+                self.store.delete_prefix(0, &key);
+            }
+            Timeframe::Weekly => {
+                let key = StoreKey::protocol_weekly_volume_usd_key(&prune_time_frame_id);
+                self.store.delete_prefix(0, &key);
+            }
+            Timeframe::Monthly => {
+                let key = StoreKey::protocol_monthly_volume_usd_key(&prune_time_frame_id);
+                self.store.delete_prefix(0, &key);
+            }
+        }
+    }
+
+    // Daily protocol volumes are kept for the length of the longest rolling window,
+    // as they are summed to calculate the protocol's rolling volume.
+    fn retention(&self, timeframe: &Timeframe) -> i64 {
+        match timeframe {
+            Timeframe::Daily => ROLLING_WINDOW_30D - 1,
+            _ => DEFAULT_PRUNE_RETENTION,
         }
     }
 }
@@ -0,0 +1,39 @@
+use substreams::store::{StoreAddInt64, StoreDelete};
+
+use crate::{
+    constants::{DEFAULT_PRUNE_RETENTION, ROLLING_WINDOW_7D},
+    key_management::store_key_manager::StoreKey,
+    timeframe_management::pruning::Pruner,
+    types::timeframe::Timeframe,
+};
+
+pub struct RollingActiveUsersPruner<'a> {
+    pub store: &'a StoreAddInt64,
+}
+
+impl<'a> Pruner for RollingActiveUsersPruner<'a> {
+    // Prunes the count of users last active on a given day, once the day falls outside of the rolling window.
+    fn prune(&self, prune_time_frame_id: i64, timeframe: Timeframe) {
+        match timeframe {
+            Timeframe::Daily => {
+                self.store.delete_prefix(
+                    0,
+                    &StoreKey::user_last_active_day_count_key(&prune_time_frame_id),
+                );
+                self.store.delete_prefix(
+                    0,
+                    &StoreKey::pool_user_last_active_day_count_prune_key(&prune_time_frame_id),
+                );
+            }
+            // Rolling active users are only calculated from daily data.
+            Timeframe::Hourly | Timeframe::Weekly | Timeframe::Monthly => {}
+        }
+    }
+
+    fn retention(&self, timeframe: &Timeframe) -> i64 {
+        match timeframe {
+            Timeframe::Daily => ROLLING_WINDOW_7D - 1,
+            _ => DEFAULT_PRUNE_RETENTION,
+        }
+    }
+}
@@ -18,6 +18,12 @@ impl<'a> Pruner for TokenVolumeNativePruner<'a> {
             Timeframe::Hourly => {
                 StoreKey::pool_token_volume_native_hourly_prune_key(&prune_time_frame_id)
             }
+            Timeframe::Weekly => {
+                StoreKey::pool_token_volume_native_weekly_prune_key(&prune_time_frame_id)
+            }
+            Timeframe::Monthly => {
+                StoreKey::pool_token_volume_native_monthly_prune_key(&prune_time_frame_id)
+            }
         };
         self.store.delete_prefix(0, &volume_native_key);
     }
@@ -18,6 +18,12 @@ impl<'a> Pruner for TokenVolumeUsdPruner<'a> {
             Timeframe::Hourly => {
                 StoreKey::pool_token_volume_usd_hourly_prune_key(&prune_time_frame_id)
             }
+            Timeframe::Weekly => {
+                StoreKey::pool_token_volume_usd_weekly_prune_key(&prune_time_frame_id)
+            }
+            Timeframe::Monthly => {
+                StoreKey::pool_token_volume_usd_monthly_prune_key(&prune_time_frame_id)
+            }
         };
         self.store.delete_prefix(0, &volume_usd_key);
     }
@@ -0,0 +1,176 @@
+use std::collections::HashSet;
+
+use substreams::{
+    pb::substreams::{store_delta::Operation, Clock},
+    scalar::BigDecimal,
+    store::{DeltaInt64, Deltas, StoreGet, StoreGetBigDecimal, StoreGetInt64, StoreGetString},
+};
+use substreams_entity_change::tables::Tables;
+
+use crate::{
+    common::conversion::convert_i64_to_i32,
+    constants::{ROLLING_WINDOW_30D, ROLLING_WINDOW_7D},
+    key_management::{entity_key_manager::EntityKey, store_key_manager::StoreKey},
+    timeframe_management::utils::{calculate_day_hour_id, separate_timeframe_deltas},
+};
+
+// Updates the rolling window aggregates (7d/30d volume and 7d unique users) on the protocol entity,
+// and on the pools that had activity in this block. When a new day starts, the windows shift for
+// every pool, so all pools are updated to ensure inactive pools don't hold stale values.
+pub fn update_rolling_window_aggregates(
+    tables: &mut Tables,
+    clock: &Clock,
+    active_pool_addresses: &HashSet<String>,
+    current_time_deltas: &Deltas<DeltaInt64>,
+    pool_count_store: &StoreGetInt64,
+    pool_addresses_store: &StoreGetString,
+    pool_volume_usd_store: &StoreGetBigDecimal,
+    protocol_volume_store: &StoreGetBigDecimal,
+    usage_metrics_store: &StoreGetInt64,
+) {
+    let (day_id, _) = calculate_day_hour_id(clock.timestamp.clone().unwrap().seconds);
+
+    let (daily_deltas, _, _, _) = separate_timeframe_deltas(current_time_deltas);
+    let is_new_day = daily_deltas
+        .iter()
+        .any(|delta| delta.operation == Operation::Update && delta.old_value != delta.new_value);
+
+    if !is_new_day && active_pool_addresses.is_empty() {
+        return;
+    }
+
+    let pool_addresses: Vec<String> = if is_new_day {
+        let pool_count = pool_count_store
+            .get_last(StoreKey::protocol_pool_count_key())
+            .unwrap_or_default();
+        (1..=pool_count)
+            .filter_map(|i| pool_addresses_store.get_last(StoreKey::pool_address_key(&i)))
+            .collect()
+    } else {
+        active_pool_addresses.iter().cloned().collect()
+    };
+
+    for pool_address in pool_addresses {
+        tables
+            .update_row(
+                "LiquidityPool",
+                EntityKey::liquidity_pool_key(&pool_address),
+            )
+            .set(
+                "_volume7dUSD",
+                get_pool_rolling_volume_usd(
+                    pool_volume_usd_store,
+                    &pool_address,
+                    &day_id,
+                    ROLLING_WINDOW_7D,
+                ),
+            )
+            .set(
+                "_volume30dUSD",
+                get_pool_rolling_volume_usd(
+                    pool_volume_usd_store,
+                    &pool_address,
+                    &day_id,
+                    ROLLING_WINDOW_30D,
+                ),
+            )
+            .set(
+                "_uniqueUsers7d",
+                convert_i64_to_i32(get_pool_rolling_unique_users(
+                    usage_metrics_store,
+                    &pool_address,
+                    &day_id,
+                    ROLLING_WINDOW_7D,
+                )),
+            );
+    }
+
+    tables
+        .update_row("DexAmmProtocol", EntityKey::protocol_key())
+        .set(
+            "_volume7dUSD",
+            get_protocol_rolling_volume_usd(protocol_volume_store, &day_id, ROLLING_WINDOW_7D),
+        )
+        .set(
+            "_volume30dUSD",
+            get_protocol_rolling_volume_usd(protocol_volume_store, &day_id, ROLLING_WINDOW_30D),
+        )
+        .set(
+            "_uniqueUsers7d",
+            convert_i64_to_i32(get_rolling_unique_users(
+                usage_metrics_store,
+                &day_id,
+                ROLLING_WINDOW_7D,
+            )),
+        );
+}
+
+// Sums a pool's daily volume over the window, up to and including the current day.
+pub fn get_pool_rolling_volume_usd(
+    pool_volume_usd_store: &StoreGetBigDecimal,
+    pool_address: &str,
+    day_id: &i64,
+    window_days: i64,
+) -> BigDecimal {
+    window_day_ids(day_id, window_days).fold(BigDecimal::zero(), |total, window_day_id| {
+        total
+            + pool_volume_usd_store
+                .get_last(StoreKey::pool_volume_usd_daily_key(
+                    &window_day_id,
+                    pool_address,
+                ))
+                .unwrap_or_else(|| BigDecimal::zero())
+    })
+}
+
+// Sums the protocol's daily volume over the window, up to and including the current day.
+pub fn get_protocol_rolling_volume_usd(
+    protocol_volume_store: &StoreGetBigDecimal,
+    day_id: &i64,
+    window_days: i64,
+) -> BigDecimal {
+    window_day_ids(day_id, window_days).fold(BigDecimal::zero(), |total, window_day_id| {
+        total
+            + protocol_volume_store
+                .get_last(StoreKey::protocol_daily_volume_usd_key(&window_day_id))
+                .unwrap_or_else(|| BigDecimal::zero())
+    })
+}
+
+// Each user is counted against the day they were last active, so summing these
+// counts over the window gives the number of unique users within it.
+pub fn get_rolling_unique_users(
+    usage_metrics_store: &StoreGetInt64,
+    day_id: &i64,
+    window_days: i64,
+) -> i64 {
+    window_day_ids(day_id, window_days)
+        .map(|window_day_id| {
+            usage_metrics_store
+                .get_last(StoreKey::user_last_active_day_count_key(&window_day_id))
+                .unwrap_or_default()
+        })
+        .sum()
+}
+
+pub fn get_pool_rolling_unique_users(
+    usage_metrics_store: &StoreGetInt64,
+    pool_address: &str,
+    day_id: &i64,
+    window_days: i64,
+) -> i64 {
+    window_day_ids(day_id, window_days)
+        .map(|window_day_id| {
+            usage_metrics_store
+                .get_last(StoreKey::pool_user_last_active_day_count_key(
+                    &window_day_id,
+                    pool_address,
+                ))
+                .unwrap_or_default()
+        })
+        .sum()
+}
+
+fn window_day_ids(day_id: &i64, window_days: i64) -> impl Iterator<Item = i64> {
+    (day_id - window_days + 1)..=*day_id
+}
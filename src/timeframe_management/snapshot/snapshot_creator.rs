@@ -12,8 +12,27 @@ use crate::key_management::entity_key_manager::EntityKey;
 use crate::key_management::store_key_manager::StoreKey;
 use crate::pb::curve::types::v1::{Pool, PoolRewards};
 use crate::pb::uniswap_pricing::v1::Erc20Price;
+use crate::types::pool_fees::RevenueSide;
 use crate::types::timeframe::Timeframe;
 
+// Per pool usage counts for a single daily or hourly timeframe.
+struct PoolUsageMetrics {
+    active_users: i64,
+    swap_count: i64,
+    deposit_count: i64,
+    withdraw_count: i64,
+}
+
+// USD revenue within a single timeframe, and cumulatively up to the snapshot.
+struct RevenueMetrics {
+    total: BigDecimal,
+    cumulative_total: BigDecimal,
+    protocol_side: BigDecimal,
+    cumulative_protocol_side: BigDecimal,
+    supply_side: BigDecimal,
+    cumulative_supply_side: BigDecimal,
+}
+
 pub struct SnapshotCreator<'a> {
     tables: &'a mut Tables,
     clock: &'a Clock,
@@ -26,6 +45,8 @@ pub struct SnapshotCreator<'a> {
     pool_volume_native_store: &'a StoreGetBigInt,
     protocol_tvl_store: &'a StoreGetBigDecimal,
     protocol_volume_store: &'a StoreGetBigDecimal,
+    pool_revenue_store: &'a StoreGetBigDecimal,
+    protocol_revenue_store: &'a StoreGetBigDecimal,
     input_token_balances_store: &'a StoreGetBigInt,
     output_token_supply_store: &'a StoreGetBigInt,
     pool_rewards_store: &'a StoreGetProto<PoolRewards>,
@@ -46,6 +67,8 @@ impl<'a> SnapshotCreator<'a> {
         pool_volume_native_store: &'a StoreGetBigInt,
         protocol_tvl_store: &'a StoreGetBigDecimal,
         protocol_volume_store: &'a StoreGetBigDecimal,
+        pool_revenue_store: &'a StoreGetBigDecimal,
+        protocol_revenue_store: &'a StoreGetBigDecimal,
         input_token_balances_store: &'a StoreGetBigInt,
         output_token_supply_store: &'a StoreGetBigInt,
         pool_rewards_store: &'a StoreGetProto<PoolRewards>,
@@ -64,6 +87,8 @@ impl<'a> SnapshotCreator<'a> {
             pool_volume_native_store,
             protocol_tvl_store,
             protocol_volume_store,
+            pool_revenue_store,
+            protocol_revenue_store,
             input_token_balances_store,
             output_token_supply_store,
             pool_rewards_store,
@@ -77,65 +102,55 @@ impl<'a> SnapshotCreator<'a> {
         snapshot_type: &Timeframe,
         time_frame_id: &i64,
     ) {
-        let timeframe_active_users = match snapshot_type {
-            Timeframe::Daily => self
-                .usage_metrics_store
-                .get_last(StoreKey::active_user_daily_count_key(&time_frame_id))
-                .unwrap_or_default(),
-            Timeframe::Hourly => self
-                .usage_metrics_store
-                .get_last(StoreKey::active_user_hourly_count_key(&time_frame_id))
-                .unwrap_or_default(),
-        };
+        let (active_users_key, tx_count_key, swap_count_key, deposit_count_key, withdraw_count_key) =
+            match snapshot_type {
+                Timeframe::Daily => (
+                    StoreKey::active_user_daily_count_key(&time_frame_id),
+                    StoreKey::transaction_daily_count_key(&time_frame_id),
+                    StoreKey::swap_daily_count_key(&time_frame_id),
+                    StoreKey::deposit_daily_count_key(&time_frame_id),
+                    StoreKey::withdraw_daily_count_key(&time_frame_id),
+                ),
+                Timeframe::Hourly => (
+                    StoreKey::active_user_hourly_count_key(&time_frame_id),
+                    StoreKey::transaction_hourly_count_key(&time_frame_id),
+                    StoreKey::swap_hourly_count_key(&time_frame_id),
+                    StoreKey::deposit_hourly_count_key(&time_frame_id),
+                    StoreKey::withdraw_hourly_count_key(&time_frame_id),
+                ),
+                // Usage metrics are only tracked on a daily and hourly basis.
+                Timeframe::Weekly | Timeframe::Monthly => return,
+            };
+
+        let timeframe_active_users = self
+            .usage_metrics_store
+            .get_last(active_users_key)
+            .unwrap_or_default();
 
         let cumulative_active_users = self
             .usage_metrics_store
             .get_last(StoreKey::active_user_count_key())
             .unwrap_or_default();
 
-        let timeframe_tx_count = match snapshot_type {
-            Timeframe::Daily => self
-                .usage_metrics_store
-                .get_last(StoreKey::transaction_daily_count_key(&time_frame_id))
-                .unwrap_or_default(),
-            Timeframe::Hourly => self
-                .usage_metrics_store
-                .get_last(StoreKey::transaction_hourly_count_key(&time_frame_id))
-                .unwrap_or_default(),
-        };
+        let timeframe_tx_count = self
+            .usage_metrics_store
+            .get_last(tx_count_key)
+            .unwrap_or_default();
 
-        let timeframe_swap_count = match snapshot_type {
-            Timeframe::Daily => self
-                .usage_metrics_store
-                .get_last(StoreKey::swap_daily_count_key(&time_frame_id))
-                .unwrap_or_default(),
-            Timeframe::Hourly => self
-                .usage_metrics_store
-                .get_last(StoreKey::swap_hourly_count_key(&time_frame_id))
-                .unwrap_or_default(),
-        };
+        let timeframe_swap_count = self
+            .usage_metrics_store
+            .get_last(swap_count_key)
+            .unwrap_or_default();
 
-        let timeframe_deposit_count = match snapshot_type {
-            Timeframe::Daily => self
-                .usage_metrics_store
-                .get_last(StoreKey::deposit_daily_count_key(&time_frame_id))
-                .unwrap_or_default(),
-            Timeframe::Hourly => self
-                .usage_metrics_store
-                .get_last(StoreKey::deposit_hourly_count_key(&time_frame_id))
-                .unwrap_or_default(),
-        };
+        let timeframe_deposit_count = self
+            .usage_metrics_store
+            .get_last(deposit_count_key)
+            .unwrap_or_default();
 
-        let timeframe_withdraw_count = match snapshot_type {
-            Timeframe::Daily => self
-                .usage_metrics_store
-                .get_last(StoreKey::withdraw_daily_count_key(&time_frame_id))
-                .unwrap_or_default(),
-            Timeframe::Hourly => self
-                .usage_metrics_store
-                .get_last(StoreKey::withdraw_hourly_count_key(&time_frame_id))
-                .unwrap_or_default(),
-        };
+        let timeframe_withdraw_count = self
+            .usage_metrics_store
+            .get_last(withdraw_count_key)
+            .unwrap_or_default();
 
         let pool_count = self
             .pool_count_store
@@ -167,6 +182,7 @@ impl<'a> SnapshotCreator<'a> {
                 timeframe_withdraw_count,
                 pool_count,
             ),
+            Timeframe::Weekly | Timeframe::Monthly => {}
         }
     }
 
@@ -247,6 +263,7 @@ impl<'a> SnapshotCreator<'a> {
             .protocol_volume_store
             .get_last(StoreKey::protocol_volume_usd_key())
             .unwrap_or_else(|| BigDecimal::zero());
+        let revenue = self.get_protocol_revenue_metrics(&Timeframe::Daily, day_id);
         self.tables
             .create_row(
                 "FinancialsDailySnapshot",
@@ -256,12 +273,144 @@ impl<'a> SnapshotCreator<'a> {
             .set("totalValueLockedUSD", tvl_usd)
             .set("dailyVolumeUSD", daily_volume)
             .set("cumulativeVolumeUSD", cumulative_volume)
-            .set("dailySupplySideRevenueUSD", BigDecimal::zero())
-            .set("cumulativeSupplySideRevenueUSD", BigDecimal::zero())
-            .set("dailyProtocolSideRevenueUSD", BigDecimal::zero())
-            .set("cumulativeProtocolSideRevenueUSD", BigDecimal::zero())
-            .set("dailyTotalRevenueUSD", BigDecimal::zero())
-            .set("cumulativeTotalRevenueUSD", BigDecimal::zero())
+            .set("dailySupplySideRevenueUSD", revenue.supply_side)
+            .set(
+                "cumulativeSupplySideRevenueUSD",
+                revenue.cumulative_supply_side,
+            )
+            .set("dailyProtocolSideRevenueUSD", revenue.protocol_side)
+            .set(
+                "cumulativeProtocolSideRevenueUSD",
+                revenue.cumulative_protocol_side,
+            )
+            .set("dailyTotalRevenueUSD", revenue.total)
+            .set("cumulativeTotalRevenueUSD", revenue.cumulative_total)
+            .set("blockNumber", BigInt::from(self.clock.number))
+            .set(
+                "timestamp",
+                BigInt::from(self.clock.timestamp.clone().unwrap().seconds),
+            );
+    }
+
+    pub fn create_protocol_financials_hourly_snapshot(&mut self, hour_id: &i64) {
+        let tvl_usd = self
+            .protocol_tvl_store
+            .get_last(StoreKey::protocol_tvl_key())
+            .unwrap_or_else(|| BigDecimal::zero());
+        let hourly_volume = self
+            .protocol_volume_store
+            .get_last(StoreKey::protocol_hourly_volume_usd_key(&hour_id))
+            .unwrap_or_else(|| BigDecimal::zero());
+        let cumulative_volume = self
+            .protocol_volume_store
+            .get_last(StoreKey::protocol_volume_usd_key())
+            .unwrap_or_else(|| BigDecimal::zero());
+        let revenue = self.get_protocol_revenue_metrics(&Timeframe::Hourly, hour_id);
+        self.tables
+            .create_row(
+                "FinancialsHourlySnapshot",
+                EntityKey::protocol_hourly_financials_key(&hour_id),
+            )
+            .set("protocol", EntityKey::protocol_key())
+            .set("totalValueLockedUSD", tvl_usd)
+            .set("hourlyVolumeUSD", hourly_volume)
+            .set("cumulativeVolumeUSD", cumulative_volume)
+            .set("hourlySupplySideRevenueUSD", revenue.supply_side)
+            .set(
+                "cumulativeSupplySideRevenueUSD",
+                revenue.cumulative_supply_side,
+            )
+            .set("hourlyProtocolSideRevenueUSD", revenue.protocol_side)
+            .set(
+                "cumulativeProtocolSideRevenueUSD",
+                revenue.cumulative_protocol_side,
+            )
+            .set("hourlyTotalRevenueUSD", revenue.total)
+            .set("cumulativeTotalRevenueUSD", revenue.cumulative_total)
+            .set("blockNumber", BigInt::from(self.clock.number))
+            .set(
+                "timestamp",
+                BigInt::from(self.clock.timestamp.clone().unwrap().seconds),
+            );
+    }
+
+    pub fn create_protocol_financials_weekly_snapshot(&mut self, week_id: &i64) {
+        let tvl_usd = self
+            .protocol_tvl_store
+            .get_last(StoreKey::protocol_tvl_key())
+            .unwrap_or_else(|| BigDecimal::zero());
+        let weekly_volume = self
+            .protocol_volume_store
+            .get_last(StoreKey::protocol_weekly_volume_usd_key(&week_id))
+            .unwrap_or_else(|| BigDecimal::zero());
+        let cumulative_volume = self
+            .protocol_volume_store
+            .get_last(StoreKey::protocol_volume_usd_key())
+            .unwrap_or_else(|| BigDecimal::zero());
+        let revenue = self.get_protocol_revenue_metrics(&Timeframe::Weekly, week_id);
+        self.tables
+            .create_row(
+                "FinancialsWeeklySnapshot",
+                EntityKey::protocol_weekly_financials_key(&week_id),
+            )
+            .set("protocol", EntityKey::protocol_key())
+            .set("totalValueLockedUSD", tvl_usd)
+            .set("weeklyVolumeUSD", weekly_volume)
+            .set("cumulativeVolumeUSD", cumulative_volume)
+            .set("weeklySupplySideRevenueUSD", revenue.supply_side)
+            .set(
+                "cumulativeSupplySideRevenueUSD",
+                revenue.cumulative_supply_side,
+            )
+            .set("weeklyProtocolSideRevenueUSD", revenue.protocol_side)
+            .set(
+                "cumulativeProtocolSideRevenueUSD",
+                revenue.cumulative_protocol_side,
+            )
+            .set("weeklyTotalRevenueUSD", revenue.total)
+            .set("cumulativeTotalRevenueUSD", revenue.cumulative_total)
+            .set("blockNumber", BigInt::from(self.clock.number))
+            .set(
+                "timestamp",
+                BigInt::from(self.clock.timestamp.clone().unwrap().seconds),
+            );
+    }
+
+    pub fn create_protocol_financials_monthly_snapshot(&mut self, month_id: &i64) {
+        let tvl_usd = self
+            .protocol_tvl_store
+            .get_last(StoreKey::protocol_tvl_key())
+            .unwrap_or_else(|| BigDecimal::zero());
+        let monthly_volume = self
+            .protocol_volume_store
+            .get_last(StoreKey::protocol_monthly_volume_usd_key(&month_id))
+            .unwrap_or_else(|| BigDecimal::zero());
+        let cumulative_volume = self
+            .protocol_volume_store
+            .get_last(StoreKey::protocol_volume_usd_key())
+            .unwrap_or_else(|| BigDecimal::zero());
+        let revenue = self.get_protocol_revenue_metrics(&Timeframe::Monthly, month_id);
+        self.tables
+            .create_row(
+                "FinancialsMonthlySnapshot",
+                EntityKey::protocol_monthly_financials_key(&month_id),
+            )
+            .set("protocol", EntityKey::protocol_key())
+            .set("totalValueLockedUSD", tvl_usd)
+            .set("monthlyVolumeUSD", monthly_volume)
+            .set("cumulativeVolumeUSD", cumulative_volume)
+            .set("monthlySupplySideRevenueUSD", revenue.supply_side)
+            .set(
+                "cumulativeSupplySideRevenueUSD",
+                revenue.cumulative_supply_side,
+            )
+            .set("monthlyProtocolSideRevenueUSD", revenue.protocol_side)
+            .set(
+                "cumulativeProtocolSideRevenueUSD",
+                revenue.cumulative_protocol_side,
+            )
+            .set("monthlyTotalRevenueUSD", revenue.total)
+            .set("cumulativeTotalRevenueUSD", revenue.cumulative_total)
             .set("blockNumber", BigInt::from(self.clock.number))
             .set(
                 "timestamp",
@@ -282,18 +431,26 @@ impl<'a> SnapshotCreator<'a> {
             None => return,
         };
 
+        // Iterate over every pool the protocol has indexed. A missing pool is skipped rather than
+        // ending the iteration, so that a single bad entry doesn't leave gaps for all later pools.
         for i in 1..=pool_count {
             let pool_address = match self
                 .pool_addresses_store
                 .get_last(StoreKey::pool_address_key(&i))
             {
                 Some(address) => address,
-                None => return,
+                None => {
+                    substreams::log::debug!("No pool address found for pool index {}", i);
+                    continue;
+                }
             };
 
             let pool = match self.pools_store.get_last(StoreKey::pool_key(&pool_address)) {
                 Some(pool) => pool,
-                None => return,
+                None => {
+                    substreams::log::debug!("No pool found for address {}", pool_address);
+                    continue;
+                }
             };
 
             let pool_tvl_usd = self
@@ -301,25 +458,29 @@ impl<'a> SnapshotCreator<'a> {
                 .get_last(StoreKey::pool_tvl_key(&pool.address))
                 .unwrap_or_else(|| BigDecimal::zero());
 
-            // Get the volume in the pool for a given timeframe (Daily/Hourly)
-            let pool_volume = match snapshot_type {
-                Timeframe::Daily => self
-                    .pool_volume_usd_store
-                    .get_last(StoreKey::pool_volume_usd_daily_key(
-                        &time_frame_id,
-                        &pool.address,
-                    ))
-                    .unwrap_or_else(|| BigDecimal::zero()),
-                Timeframe::Hourly => self
-                    .pool_volume_usd_store
-                    .get_last(StoreKey::pool_volume_usd_hourly_key(
-                        &time_frame_id,
-                        &pool.address,
-                    ))
-                    .unwrap_or_else(|| BigDecimal::zero()),
+            // Get the volume in the pool for a given timeframe (Daily/Hourly/Weekly/Monthly)
+            let pool_volume_key = match snapshot_type {
+                Timeframe::Daily => {
+                    StoreKey::pool_volume_usd_daily_key(&time_frame_id, &pool.address)
+                }
+                Timeframe::Hourly => {
+                    StoreKey::pool_volume_usd_hourly_key(&time_frame_id, &pool.address)
+                }
+                Timeframe::Weekly => {
+                    StoreKey::pool_volume_usd_weekly_key(&time_frame_id, &pool.address)
+                }
+                Timeframe::Monthly => {
+                    StoreKey::pool_volume_usd_monthly_key(&time_frame_id, &pool.address)
+                }
             };
+            // Pools without volume in this timeframe are still snapshotted so pool timeseries don't
+            // contain gaps, with the remaining values carried forward from the latest store values.
+            let pool_volume = self
+                .pool_volume_usd_store
+                .get_last(pool_volume_key)
+                .unwrap_or_else(|| BigDecimal::zero());
 
-            // Get the volumes of each pool input token for a given timeframe (Daily/Hourly)
+            // Get the volumes of each pool input token for a given timeframe (Daily/Hourly/Weekly/Monthly)
             let (volume_by_token_native, volume_by_token_usd) = get_pool_token_volumes_in_timeframe(
                 &pool,
                 time_frame_id,
@@ -362,9 +523,18 @@ impl<'a> SnapshotCreator<'a> {
                     staked_output_token_amount: "0".to_string(),
                     reward_token_emissions_native: Vec::new(),
                     reward_token_emissions_usd: Vec::new(),
+                    reward_tokens: Vec::new(),
+                    reward_token_base_aprs: Vec::new(),
+                    reward_token_max_boosted_aprs: Vec::new(),
                 },
             };
 
+            let pool_usage_metrics =
+                self.get_pool_usage_metrics(snapshot_type, time_frame_id, &pool_address);
+
+            let pool_revenue =
+                self.get_pool_revenue_metrics(snapshot_type, time_frame_id, &pool_address);
+
             // Create the relevant timeframe snapshot
             match snapshot_type {
                 Timeframe::Daily => Self::create_pool_daily_snapshot(
@@ -372,6 +542,7 @@ impl<'a> SnapshotCreator<'a> {
                     self.clock,
                     time_frame_id,
                     &pool_address,
+                    &pool_usage_metrics,
                     &pool_tvl_usd,
                     &pool_volume,
                     &volume_by_token_native,
@@ -382,8 +553,44 @@ impl<'a> SnapshotCreator<'a> {
                     &output_token_supply,
                     &output_token_price,
                     &pool_rewards,
+                    &pool_revenue,
                 ),
                 Timeframe::Hourly => Self::create_pool_hourly_snapshot(
+                    self.tables,
+                    self.clock,
+                    time_frame_id,
+                    &pool_address,
+                    &pool_usage_metrics,
+                    &pool_tvl_usd,
+                    &pool_volume,
+                    &volume_by_token_native,
+                    &volume_by_token_usd,
+                    &pool_cumulative_volume_usd,
+                    &input_token_balances,
+                    &input_token_weights,
+                    &output_token_supply,
+                    &output_token_price,
+                    &pool_rewards,
+                    &pool_revenue,
+                ),
+                Timeframe::Weekly => Self::create_pool_weekly_snapshot(
+                    self.tables,
+                    self.clock,
+                    time_frame_id,
+                    &pool_address,
+                    &pool_tvl_usd,
+                    &pool_volume,
+                    &volume_by_token_native,
+                    &volume_by_token_usd,
+                    &pool_cumulative_volume_usd,
+                    &input_token_balances,
+                    &input_token_weights,
+                    &output_token_supply,
+                    &output_token_price,
+                    &pool_rewards,
+                    &pool_revenue,
+                ),
+                Timeframe::Monthly => Self::create_pool_monthly_snapshot(
                     self.tables,
                     self.clock,
                     time_frame_id,
@@ -398,8 +605,128 @@ impl<'a> SnapshotCreator<'a> {
                     &output_token_supply,
                     &output_token_price,
                     &pool_rewards,
+                    &pool_revenue,
+                ),
+            }
+        }
+    }
+
+    fn get_pool_usage_metrics(
+        &self,
+        snapshot_type: &Timeframe,
+        time_frame_id: &i64,
+        pool_address: &str,
+    ) -> PoolUsageMetrics {
+        let (active_users_key, swap_count_key, deposit_count_key, withdraw_count_key) =
+            match snapshot_type {
+                Timeframe::Daily => (
+                    StoreKey::pool_active_user_daily_count_key(time_frame_id, pool_address),
+                    StoreKey::pool_swap_daily_count_key(time_frame_id, pool_address),
+                    StoreKey::pool_deposit_daily_count_key(time_frame_id, pool_address),
+                    StoreKey::pool_withdraw_daily_count_key(time_frame_id, pool_address),
+                ),
+                Timeframe::Hourly => (
+                    StoreKey::pool_active_user_hourly_count_key(time_frame_id, pool_address),
+                    StoreKey::pool_swap_hourly_count_key(time_frame_id, pool_address),
+                    StoreKey::pool_deposit_hourly_count_key(time_frame_id, pool_address),
+                    StoreKey::pool_withdraw_hourly_count_key(time_frame_id, pool_address),
                 ),
+                // Pool usage metrics are only tracked on a daily and hourly basis.
+                Timeframe::Weekly | Timeframe::Monthly => {
+                    return PoolUsageMetrics {
+                        active_users: 0,
+                        swap_count: 0,
+                        deposit_count: 0,
+                        withdraw_count: 0,
+                    }
+                }
+            };
+
+        PoolUsageMetrics {
+            active_users: self
+                .usage_metrics_store
+                .get_last(active_users_key)
+                .unwrap_or_default(),
+            swap_count: self
+                .usage_metrics_store
+                .get_last(swap_count_key)
+                .unwrap_or_default(),
+            deposit_count: self
+                .usage_metrics_store
+                .get_last(deposit_count_key)
+                .unwrap_or_default(),
+            withdraw_count: self
+                .usage_metrics_store
+                .get_last(withdraw_count_key)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn get_pool_revenue_metrics(
+        &self,
+        snapshot_type: &Timeframe,
+        time_frame_id: &i64,
+        pool_address: &str,
+    ) -> RevenueMetrics {
+        Self::get_revenue_metrics(self.pool_revenue_store, |side, cumulative| {
+            if cumulative {
+                return StoreKey::pool_revenue_usd_key(side, pool_address);
+            }
+            match snapshot_type {
+                Timeframe::Daily => {
+                    StoreKey::pool_revenue_usd_daily_key(time_frame_id, side, pool_address)
+                }
+                Timeframe::Hourly => {
+                    StoreKey::pool_revenue_usd_hourly_key(time_frame_id, side, pool_address)
+                }
+                Timeframe::Weekly => {
+                    StoreKey::pool_revenue_usd_weekly_key(time_frame_id, side, pool_address)
+                }
+                Timeframe::Monthly => {
+                    StoreKey::pool_revenue_usd_monthly_key(time_frame_id, side, pool_address)
+                }
             }
+        })
+    }
+
+    fn get_protocol_revenue_metrics(
+        &self,
+        snapshot_type: &Timeframe,
+        time_frame_id: &i64,
+    ) -> RevenueMetrics {
+        Self::get_revenue_metrics(self.protocol_revenue_store, |side, cumulative| {
+            if cumulative {
+                return StoreKey::protocol_revenue_usd_key(side);
+            }
+            match snapshot_type {
+                Timeframe::Daily => StoreKey::protocol_daily_revenue_usd_key(time_frame_id, side),
+                Timeframe::Hourly => StoreKey::protocol_hourly_revenue_usd_key(time_frame_id, side),
+                Timeframe::Weekly => StoreKey::protocol_weekly_revenue_usd_key(time_frame_id, side),
+                Timeframe::Monthly => {
+                    StoreKey::protocol_monthly_revenue_usd_key(time_frame_id, side)
+                }
+            }
+        })
+    }
+
+    // Reads the revenue of each side from the store. `key` builds the store key for a side, holding
+    // either its cumulative revenue or its revenue within the timeframe.
+    fn get_revenue_metrics(
+        store: &StoreGetBigDecimal,
+        key: impl Fn(&RevenueSide, bool) -> String,
+    ) -> RevenueMetrics {
+        let get = |side: RevenueSide, cumulative: bool| {
+            store
+                .get_last(key(&side, cumulative))
+                .unwrap_or_else(|| BigDecimal::zero())
+        };
+        RevenueMetrics {
+            total: get(RevenueSide::Total, false),
+            cumulative_total: get(RevenueSide::Total, true),
+            protocol_side: get(RevenueSide::ProtocolSide, false),
+            cumulative_protocol_side: get(RevenueSide::ProtocolSide, true),
+            supply_side: get(RevenueSide::SupplySide, false),
+            cumulative_supply_side: get(RevenueSide::SupplySide, true),
         }
     }
 
@@ -408,6 +735,7 @@ impl<'a> SnapshotCreator<'a> {
         clock: &Clock,
         day_id: &i64,
         pool_address: &str,
+        pool_usage_metrics: &PoolUsageMetrics,
         pool_tvl_usd: &BigDecimal,
         pool_volume_daily: &BigDecimal,
         volume_by_token_native: &Vec<BigInt>,
@@ -418,6 +746,7 @@ impl<'a> SnapshotCreator<'a> {
         output_token_supply: &BigInt,
         output_token_price: &BigDecimal,
         pool_rewards: &PoolRewards,
+        pool_revenue: &RevenueMetrics,
     ) {
         tables
             .create_row(
@@ -432,13 +761,18 @@ impl<'a> SnapshotCreator<'a> {
                 BigInt::from(clock.timestamp.clone().unwrap().seconds),
             )
             .set("totalValueLockedUSD", pool_tvl_usd)
-            // Revenue related data is currently set to a default of zero until fees are implemented
-            .set("cumulativeSupplySideRevenueUSD", BigDecimal::zero())
-            .set("dailySupplySideRevenueUSD", BigDecimal::zero())
-            .set("cumulativeProtocolSideRevenueUSD", BigDecimal::zero())
-            .set("dailyProtocolSideRevenueUSD", BigDecimal::zero())
-            .set("cumulativeTotalRevenueUSD", BigDecimal::zero())
-            .set("dailyTotalRevenueUSD", BigDecimal::zero())
+            .set(
+                "cumulativeSupplySideRevenueUSD",
+                &pool_revenue.cumulative_supply_side,
+            )
+            .set("dailySupplySideRevenueUSD", &pool_revenue.supply_side)
+            .set(
+                "cumulativeProtocolSideRevenueUSD",
+                &pool_revenue.cumulative_protocol_side,
+            )
+            .set("dailyProtocolSideRevenueUSD", &pool_revenue.protocol_side)
+            .set("cumulativeTotalRevenueUSD", &pool_revenue.cumulative_total)
+            .set("dailyTotalRevenueUSD", &pool_revenue.total)
             .set("dailyVolumeUSD", pool_volume_daily)
             .set("dailyVolumeByTokenAmount", volume_by_token_native)
             .set("dailyVolumeByTokenUSD", volume_by_token_usd)
@@ -458,6 +792,27 @@ impl<'a> SnapshotCreator<'a> {
             .set(
                 "rewardTokenEmissionsUSD",
                 pool_rewards.parse_reward_token_emissions_usd(),
+            )
+            .set("_baseRewardAPR", pool_rewards.base_reward_apr())
+            .set(
+                "_maxBoostedRewardAPR",
+                pool_rewards.max_boosted_reward_apr(),
+            )
+            .set(
+                "_dailyActiveUsers",
+                convert_i64_to_i32(pool_usage_metrics.active_users),
+            )
+            .set(
+                "_dailySwapCount",
+                convert_i64_to_i32(pool_usage_metrics.swap_count),
+            )
+            .set(
+                "_dailyDepositCount",
+                convert_i64_to_i32(pool_usage_metrics.deposit_count),
+            )
+            .set(
+                "_dailyWithdrawCount",
+                convert_i64_to_i32(pool_usage_metrics.withdraw_count),
             );
     }
 
@@ -466,6 +821,7 @@ impl<'a> SnapshotCreator<'a> {
         clock: &Clock,
         hour_id: &i64,
         pool_address: &str,
+        pool_usage_metrics: &PoolUsageMetrics,
         pool_tvl_usd: &BigDecimal,
         pool_volume_hourly: &BigDecimal,
         volume_by_token_native: &Vec<BigInt>,
@@ -476,6 +832,7 @@ impl<'a> SnapshotCreator<'a> {
         output_token_supply: &BigInt,
         output_token_price: &BigDecimal,
         pool_rewards: &PoolRewards,
+        pool_revenue: &RevenueMetrics,
     ) {
         tables
             .create_row(
@@ -490,13 +847,18 @@ impl<'a> SnapshotCreator<'a> {
                 BigInt::from(clock.timestamp.clone().unwrap().seconds),
             )
             .set("totalValueLockedUSD", pool_tvl_usd)
-            // Revenue related data is currently set to a default of zero until fees are implemented
-            .set("cumulativeSupplySideRevenueUSD", BigDecimal::zero())
-            .set("hourlySupplySideRevenueUSD", BigDecimal::zero())
-            .set("cumulativeProtocolSideRevenueUSD", BigDecimal::zero())
-            .set("hourlyProtocolSideRevenueUSD", BigDecimal::zero())
-            .set("cumulativeTotalRevenueUSD", BigDecimal::zero())
-            .set("hourlyTotalRevenueUSD", BigDecimal::zero())
+            .set(
+                "cumulativeSupplySideRevenueUSD",
+                &pool_revenue.cumulative_supply_side,
+            )
+            .set("hourlySupplySideRevenueUSD", &pool_revenue.supply_side)
+            .set(
+                "cumulativeProtocolSideRevenueUSD",
+                &pool_revenue.cumulative_protocol_side,
+            )
+            .set("hourlyProtocolSideRevenueUSD", &pool_revenue.protocol_side)
+            .set("cumulativeTotalRevenueUSD", &pool_revenue.cumulative_total)
+            .set("hourlyTotalRevenueUSD", &pool_revenue.total)
             .set("hourlyVolumeUSD", pool_volume_hourly)
             .set("hourlyVolumeByTokenAmount", volume_by_token_native)
             .set("hourlyVolumeByTokenUSD", volume_by_token_usd)
@@ -516,6 +878,164 @@ impl<'a> SnapshotCreator<'a> {
             .set(
                 "rewardTokenEmissionsUSD",
                 pool_rewards.parse_reward_token_emissions_usd(),
+            )
+            .set("_baseRewardAPR", pool_rewards.base_reward_apr())
+            .set(
+                "_maxBoostedRewardAPR",
+                pool_rewards.max_boosted_reward_apr(),
+            )
+            .set(
+                "_hourlyActiveUsers",
+                convert_i64_to_i32(pool_usage_metrics.active_users),
+            )
+            .set(
+                "_hourlySwapCount",
+                convert_i64_to_i32(pool_usage_metrics.swap_count),
+            )
+            .set(
+                "_hourlyDepositCount",
+                convert_i64_to_i32(pool_usage_metrics.deposit_count),
+            )
+            .set(
+                "_hourlyWithdrawCount",
+                convert_i64_to_i32(pool_usage_metrics.withdraw_count),
+            );
+    }
+    fn create_pool_weekly_snapshot(
+        tables: &mut Tables,
+        clock: &Clock,
+        week_id: &i64,
+        pool_address: &str,
+        pool_tvl_usd: &BigDecimal,
+        pool_volume_weekly: &BigDecimal,
+        volume_by_token_native: &Vec<BigInt>,
+        volume_by_token_usd: &Vec<BigDecimal>,
+        pool_cumulative_volume_usd: &BigDecimal,
+        input_token_balances: &Vec<BigInt>,
+        input_token_weights: &Vec<BigDecimal>,
+        output_token_supply: &BigInt,
+        output_token_price: &BigDecimal,
+        pool_rewards: &PoolRewards,
+        pool_revenue: &RevenueMetrics,
+    ) {
+        tables
+            .create_row(
+                "LiquidityPoolWeeklySnapshot",
+                EntityKey::pool_weekly_snapshot_key(&pool_address, week_id),
+            )
+            .set("protocol", EntityKey::protocol_key())
+            .set("pool", EntityKey::liquidity_pool_key(pool_address))
+            .set("blockNumber", BigInt::from(clock.number))
+            .set(
+                "timestamp",
+                BigInt::from(clock.timestamp.clone().unwrap().seconds),
+            )
+            .set("totalValueLockedUSD", pool_tvl_usd)
+            .set(
+                "cumulativeSupplySideRevenueUSD",
+                &pool_revenue.cumulative_supply_side,
+            )
+            .set("weeklySupplySideRevenueUSD", &pool_revenue.supply_side)
+            .set(
+                "cumulativeProtocolSideRevenueUSD",
+                &pool_revenue.cumulative_protocol_side,
+            )
+            .set("weeklyProtocolSideRevenueUSD", &pool_revenue.protocol_side)
+            .set("cumulativeTotalRevenueUSD", &pool_revenue.cumulative_total)
+            .set("weeklyTotalRevenueUSD", &pool_revenue.total)
+            .set("weeklyVolumeUSD", pool_volume_weekly)
+            .set("weeklyVolumeByTokenAmount", volume_by_token_native)
+            .set("weeklyVolumeByTokenUSD", volume_by_token_usd)
+            .set("cumulativeVolumeUSD", pool_cumulative_volume_usd)
+            .set("inputTokenBalances", input_token_balances)
+            .set("inputTokenWeights", input_token_weights)
+            .set("outputTokenSupply", output_token_supply)
+            .set("outputTokenPriceUSD", output_token_price)
+            .set(
+                "stakedOutputTokenAmount",
+                pool_rewards.parse_staked_output_token_amount(),
+            )
+            .set(
+                "rewardTokenEmissionsAmount",
+                pool_rewards.parse_reward_token_emissions_native(),
+            )
+            .set(
+                "rewardTokenEmissionsUSD",
+                pool_rewards.parse_reward_token_emissions_usd(),
+            )
+            .set("_baseRewardAPR", pool_rewards.base_reward_apr())
+            .set(
+                "_maxBoostedRewardAPR",
+                pool_rewards.max_boosted_reward_apr(),
+            );
+    }
+
+    fn create_pool_monthly_snapshot(
+        tables: &mut Tables,
+        clock: &Clock,
+        month_id: &i64,
+        pool_address: &str,
+        pool_tvl_usd: &BigDecimal,
+        pool_volume_monthly: &BigDecimal,
+        volume_by_token_native: &Vec<BigInt>,
+        volume_by_token_usd: &Vec<BigDecimal>,
+        pool_cumulative_volume_usd: &BigDecimal,
+        input_token_balances: &Vec<BigInt>,
+        input_token_weights: &Vec<BigDecimal>,
+        output_token_supply: &BigInt,
+        output_token_price: &BigDecimal,
+        pool_rewards: &PoolRewards,
+        pool_revenue: &RevenueMetrics,
+    ) {
+        tables
+            .create_row(
+                "LiquidityPoolMonthlySnapshot",
+                EntityKey::pool_monthly_snapshot_key(&pool_address, month_id),
+            )
+            .set("protocol", EntityKey::protocol_key())
+            .set("pool", EntityKey::liquidity_pool_key(pool_address))
+            .set("blockNumber", BigInt::from(clock.number))
+            .set(
+                "timestamp",
+                BigInt::from(clock.timestamp.clone().unwrap().seconds),
+            )
+            .set("totalValueLockedUSD", pool_tvl_usd)
+            .set(
+                "cumulativeSupplySideRevenueUSD",
+                &pool_revenue.cumulative_supply_side,
+            )
+            .set("monthlySupplySideRevenueUSD", &pool_revenue.supply_side)
+            .set(
+                "cumulativeProtocolSideRevenueUSD",
+                &pool_revenue.cumulative_protocol_side,
+            )
+            .set("monthlyProtocolSideRevenueUSD", &pool_revenue.protocol_side)
+            .set("cumulativeTotalRevenueUSD", &pool_revenue.cumulative_total)
+            .set("monthlyTotalRevenueUSD", &pool_revenue.total)
+            .set("monthlyVolumeUSD", pool_volume_monthly)
+            .set("monthlyVolumeByTokenAmount", volume_by_token_native)
+            .set("monthlyVolumeByTokenUSD", volume_by_token_usd)
+            .set("cumulativeVolumeUSD", pool_cumulative_volume_usd)
+            .set("inputTokenBalances", input_token_balances)
+            .set("inputTokenWeights", input_token_weights)
+            .set("outputTokenSupply", output_token_supply)
+            .set("outputTokenPriceUSD", output_token_price)
+            .set(
+                "stakedOutputTokenAmount",
+                pool_rewards.parse_staked_output_token_amount(),
+            )
+            .set(
+                "rewardTokenEmissionsAmount",
+                pool_rewards.parse_reward_token_emissions_native(),
+            )
+            .set(
+                "rewardTokenEmissionsUSD",
+                pool_rewards.parse_reward_token_emissions_usd(),
+            )
+            .set("_baseRewardAPR", pool_rewards.base_reward_apr())
+            .set(
+                "_maxBoostedRewardAPR",
+                pool_rewards.max_boosted_reward_apr(),
             );
     }
 }
@@ -542,6 +1062,16 @@ fn get_pool_token_volumes_in_timeframe(
                 &pool.address,
                 &token.address,
             ),
+            Timeframe::Weekly => StoreKey::pool_token_volume_native_weekly_key(
+                &time_frame_id,
+                &pool.address,
+                &token.address,
+            ),
+            Timeframe::Monthly => StoreKey::pool_token_volume_native_monthly_key(
+                &time_frame_id,
+                &pool.address,
+                &token.address,
+            ),
         };
         let usd_volume_key = match snapshot_type {
             Timeframe::Daily => StoreKey::pool_token_volume_usd_daily_key(
@@ -554,6 +1084,16 @@ fn get_pool_token_volumes_in_timeframe(
                 &pool.address,
                 &token.address,
             ),
+            Timeframe::Weekly => StoreKey::pool_token_volume_usd_weekly_key(
+                &time_frame_id,
+                &pool.address,
+                &token.address,
+            ),
+            Timeframe::Monthly => StoreKey::pool_token_volume_usd_monthly_key(
+                &time_frame_id,
+                &pool.address,
+                &token.address,
+            ),
         };
 
         // Fetch and push the native volume for the token
@@ -19,9 +19,9 @@ use crate::{
 
 use super::snapshot_creator::SnapshotCreator;
 
-/// Manages the creation of snapshots by monitoring changes in timeframes (daily and hourly)
+/// Manages the creation of snapshots by monitoring changes in timeframes (daily, hourly, weekly and monthly)
 /// and triggering snapshot creation through a `SnapshotCreator`. It sets up a `TimeframeChangeHandler`
-/// with closures that are called when a new day or hour is detected based on the deltas provided.
+/// with closures that are called when a new day, hour, week or month is detected based on the deltas provided.
 pub fn manage_timeframe_snapshots(
     clock: &Clock,
     deltas: &Deltas<DeltaInt64>,
@@ -35,6 +35,8 @@ pub fn manage_timeframe_snapshots(
     pool_volume_native_store: &StoreGetBigInt,
     protocol_tvl_store: &StoreGetBigDecimal,
     protocol_volume_store: &StoreGetBigDecimal,
+    pool_revenue_store: &StoreGetBigDecimal,
+    protocol_revenue_store: &StoreGetBigDecimal,
     input_token_balances_store: &StoreGetBigInt,
     output_token_supply_store: &StoreGetBigInt,
     pool_rewards_store: &StoreGetProto<PoolRewards>,
@@ -53,6 +55,8 @@ pub fn manage_timeframe_snapshots(
         pool_volume_native_store,
         protocol_tvl_store,
         protocol_volume_store,
+        pool_revenue_store,
+        protocol_revenue_store,
         input_token_balances_store,
         output_token_supply_store,
         pool_rewards_store,
@@ -60,21 +64,28 @@ pub fn manage_timeframe_snapshots(
         chainlink_prices,
     )));
 
-    let (daily_deltas, hourly_deltas) = separate_timeframe_deltas(deltas);
+    let (daily_deltas, hourly_deltas, weekly_deltas, monthly_deltas) =
+        separate_timeframe_deltas(deltas);
 
-    // Prepare closures for handling new day and new hour events.
+    // Prepare closures for handling new day, hour, week and month events.
     // These closures will utilize the SnapshotCreator to generate snapshots.
     // We use Rc::clone to ensure the SnapshotCreator can be shared among closures without taking ownership.
     let on_new_day = prepare_snapshot_closure(Rc::clone(&snapshot_creator), Timeframe::Daily);
     let on_new_hour = prepare_snapshot_closure(Rc::clone(&snapshot_creator), Timeframe::Hourly);
+    let on_new_week = prepare_snapshot_closure(Rc::clone(&snapshot_creator), Timeframe::Weekly);
+    let on_new_month = prepare_snapshot_closure(Rc::clone(&snapshot_creator), Timeframe::Monthly);
 
     // Initialise the TimeframeChangeHandler with the separated deltas and the prepared closures.
-    // This handler will check for updates in daily and hourly deltas and trigger the appropriate closures.
+    // This handler will check for updates in each timeframe's deltas and trigger the appropriate closures.
     let mut timeframe_change_handler = TimeframeChangeHandler {
         daily_deltas: &daily_deltas,
         hourly_deltas: &hourly_deltas,
         on_new_day: Box::new(on_new_day),
         on_new_hour: Some(Box::new(on_new_hour)),
+        weekly_deltas: &weekly_deltas,
+        monthly_deltas: &monthly_deltas,
+        on_new_week: Some(Box::new(on_new_week)),
+        on_new_month: Some(Box::new(on_new_month)),
     };
 
     // Process the timeframe changes by iterating over deltas and triggering closures if conditions are met.
@@ -83,7 +94,7 @@ pub fn manage_timeframe_snapshots(
 
 /// Prepares a closure to create snapshots based on the specified timeframe.
 /// This closure captures a `SnapshotCreator` and, depending on the timeframe,
-/// calls methods on the `SnapshotCreator` to generate daily or hourly snapshots.
+/// calls methods on the `SnapshotCreator` to generate daily, hourly, weekly or monthly snapshots.
 pub fn prepare_snapshot_closure(
     snapshot_creator: Rc<RefCell<SnapshotCreator>>,
     snapshot_type: Timeframe,
@@ -98,6 +109,15 @@ pub fn prepare_snapshot_closure(
             }
             Timeframe::Hourly => {
                 creator.create_usage_metrics_snapshots(&snapshot_type, &time_frame_id);
+                creator.create_protocol_financials_hourly_snapshot(&time_frame_id);
+                creator.create_liquidity_pool_snapshots(&snapshot_type, &time_frame_id);
+            }
+            Timeframe::Weekly => {
+                creator.create_protocol_financials_weekly_snapshot(&time_frame_id);
+                creator.create_liquidity_pool_snapshots(&snapshot_type, &time_frame_id);
+            }
+            Timeframe::Monthly => {
+                creator.create_protocol_financials_monthly_snapshot(&time_frame_id);
                 creator.create_liquidity_pool_snapshots(&snapshot_type, &time_frame_id);
             }
         }
@@ -1,14 +1,18 @@
 use substreams::pb::substreams::store_delta::Operation;
 use substreams::store::{DeltaInt64, Deltas};
 
-// TimeframeChangeHandler is designed to manage changes in timeframes, specifically daily and hourly changes.
-// It holds references to deltas for both timeframes and closures that define the actions to be taken when a new day or hour is detected.
+// TimeframeChangeHandler is designed to manage changes in timeframes, specifically daily, hourly, weekly and monthly changes.
+// It holds references to deltas for each timeframe and closures that define the actions to be taken when a new day, hour, week or month is detected.
 // This allows us to pass in closures that can create snapshots, or prune old data when a new timeframe change occurs.
 pub struct TimeframeChangeHandler<'a> {
     pub daily_deltas: &'a Deltas<DeltaInt64>,
     pub hourly_deltas: &'a Deltas<DeltaInt64>,
     pub on_new_day: Box<dyn FnMut(i64) + 'a>,
     pub on_new_hour: Option<Box<dyn FnMut(i64) + 'a>>,
+    pub weekly_deltas: &'a Deltas<DeltaInt64>,
+    pub monthly_deltas: &'a Deltas<DeltaInt64>,
+    pub on_new_week: Option<Box<dyn FnMut(i64) + 'a>>,
+    pub on_new_month: Option<Box<dyn FnMut(i64) + 'a>>,
 }
 
 impl<'a> TimeframeChangeHandler<'a> {
@@ -28,6 +32,24 @@ impl<'a> TimeframeChangeHandler<'a> {
                 }
             }
         }
+
+        if let Some(ref mut on_new_week) = self.on_new_week {
+            for delta in &self.weekly_deltas.deltas {
+                if delta.operation == Operation::Update && delta.old_value != delta.new_value {
+                    // Trigger the on_new_week closure, passing in the old value (previous week ID) as the argument.
+                    on_new_week(delta.old_value);
+                }
+            }
+        }
+
+        if let Some(ref mut on_new_month) = self.on_new_month {
+            for delta in &self.monthly_deltas.deltas {
+                if delta.operation == Operation::Update && delta.old_value != delta.new_value {
+                    // Trigger the on_new_month closure, passing in the old value (previous month ID) as the argument.
+                    on_new_month(delta.old_value);
+                }
+            }
+        }
     }
 }
 
@@ -67,6 +89,10 @@ mod tests {
             hourly_deltas: &Deltas { deltas: vec![] }, // No changes in hourly deltas for this test
             on_new_day: Box::new(move |_| *day_triggered_clone.borrow_mut() = true),
             on_new_hour: None,
+            weekly_deltas: &Deltas { deltas: vec![] },
+            monthly_deltas: &Deltas { deltas: vec![] },
+            on_new_week: None,
+            on_new_month: None,
         };
 
         handler.handle_timeframe_changes();
@@ -88,6 +114,10 @@ mod tests {
             hourly_deltas: &hourly_deltas,
             on_new_day: Box::new(|_| {}),
             on_new_hour: Some(Box::new(move |_| *hour_triggered_clone.borrow_mut() = true)),
+            weekly_deltas: &Deltas { deltas: vec![] },
+            monthly_deltas: &Deltas { deltas: vec![] },
+            on_new_week: None,
+            on_new_month: None,
         };
 
         handler.handle_timeframe_changes();
@@ -112,6 +142,10 @@ mod tests {
             hourly_deltas: &hourly_deltas,
             on_new_day: Box::new(|_| *day_triggered_clone.borrow_mut() = true),
             on_new_hour: Some(Box::new(|_| *hour_triggered_clone.borrow_mut() = true)),
+            weekly_deltas: &Deltas { deltas: vec![] },
+            monthly_deltas: &Deltas { deltas: vec![] },
+            on_new_week: None,
+            on_new_month: None,
         };
 
         handler.handle_timeframe_changes();
@@ -140,6 +174,10 @@ mod tests {
             hourly_deltas: &hourly_deltas,
             on_new_day: Box::new(|_| *day_triggered_clone.borrow_mut() = true),
             on_new_hour: Some(Box::new(|_| *hour_triggered_clone.borrow_mut() = true)),
+            weekly_deltas: &Deltas { deltas: vec![] },
+            monthly_deltas: &Deltas { deltas: vec![] },
+            on_new_week: None,
+            on_new_month: None,
         };
 
         handler.handle_timeframe_changes();
@@ -166,6 +204,10 @@ mod tests {
             hourly_deltas: &Deltas { deltas: vec![] }, // No changes in hourly deltas for this test
             on_new_day: Box::new(|_| *day_triggered_clone.borrow_mut() = true),
             on_new_hour: Some(Box::new(|_| *hour_triggered_clone.borrow_mut() = true)),
+            weekly_deltas: &Deltas { deltas: vec![] },
+            monthly_deltas: &Deltas { deltas: vec![] },
+            on_new_week: None,
+            on_new_month: None,
         };
 
         handler.handle_timeframe_changes();
@@ -180,5 +222,93 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_handle_timeframe_changes_new_week() {
+        let week_triggered = Rc::new(RefCell::new(None));
+        let week_triggered_clone = week_triggered.clone();
+        let weekly_deltas = create_test_deltas(Operation::Update, 2821, 2822); // Simulating a change from week 2821 to week 2822
+
+        let mut handler = TimeframeChangeHandler {
+            daily_deltas: &Deltas { deltas: vec![] },
+            hourly_deltas: &Deltas { deltas: vec![] },
+            on_new_day: Box::new(|_| {}),
+            on_new_hour: None,
+            weekly_deltas: &weekly_deltas,
+            monthly_deltas: &Deltas { deltas: vec![] },
+            on_new_week: Some(Box::new(move |week_id| {
+                *week_triggered_clone.borrow_mut() = Some(week_id)
+            })),
+            on_new_month: None,
+        };
+
+        handler.handle_timeframe_changes();
+
+        assert_eq!(
+            *week_triggered.borrow(),
+            Some(2821),
+            "Expected on_new_week to be triggered with the previous week ID"
+        );
+    }
+
+    #[test]
+    fn test_handle_timeframe_changes_new_month() {
+        let month_triggered = Rc::new(RefCell::new(None));
+        let month_triggered_clone = month_triggered.clone();
+        let monthly_deltas = create_test_deltas(Operation::Update, 648, 649); // Simulating a change from month 648 to month 649
+
+        let mut handler = TimeframeChangeHandler {
+            daily_deltas: &Deltas { deltas: vec![] },
+            hourly_deltas: &Deltas { deltas: vec![] },
+            on_new_day: Box::new(|_| {}),
+            on_new_hour: None,
+            weekly_deltas: &Deltas { deltas: vec![] },
+            monthly_deltas: &monthly_deltas,
+            on_new_week: None,
+            on_new_month: Some(Box::new(move |month_id| {
+                *month_triggered_clone.borrow_mut() = Some(month_id)
+            })),
+        };
+
+        handler.handle_timeframe_changes();
+
+        assert_eq!(
+            *month_triggered.borrow(),
+            Some(648),
+            "Expected on_new_month to be triggered with the previous month ID"
+        );
+    }
+
+    #[test]
+    fn test_no_week_or_month_change_no_trigger() {
+        let week_triggered = Rc::new(RefCell::new(false));
+        let month_triggered = Rc::new(RefCell::new(false));
+        let week_triggered_clone = week_triggered.clone();
+        let month_triggered_clone = month_triggered.clone();
+        let weekly_deltas = create_test_deltas(Operation::Update, 2822, 2822); // No actual change in week
+        let monthly_deltas = create_test_deltas(Operation::Create, 0, 649); // Creation of the first monthly timeframe
+
+        let mut handler = TimeframeChangeHandler {
+            daily_deltas: &Deltas { deltas: vec![] },
+            hourly_deltas: &Deltas { deltas: vec![] },
+            on_new_day: Box::new(|_| {}),
+            on_new_hour: None,
+            weekly_deltas: &weekly_deltas,
+            monthly_deltas: &monthly_deltas,
+            on_new_week: Some(Box::new(|_| *week_triggered_clone.borrow_mut() = true)),
+            on_new_month: Some(Box::new(|_| *month_triggered_clone.borrow_mut() = true)),
+        };
+
+        handler.handle_timeframe_changes();
+
+        assert!(
+            !*week_triggered.borrow(),
+            "on_new_week should not be triggered without a week change"
+        );
+        assert!(
+            !*month_triggered.borrow(),
+            "on_new_month should not be triggered when delta is create operation"
+        );
+    }
+
     // Add more tests as necessary...
 }
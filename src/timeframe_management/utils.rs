@@ -1,30 +1,55 @@
 use substreams::store::{DeltaInt64, Deltas};
 
-use crate::key_management::store_key_manager::StoreKey;
+use crate::{
+    key_management::store_key_manager::StoreKey,
+    timeframe_management::timeframe_change_handler::TimeframeChangeHandler,
+};
 
 pub fn separate_timeframe_deltas(
     deltas: &Deltas<DeltaInt64>,
-) -> (Deltas<DeltaInt64>, Deltas<DeltaInt64>) {
-    let daily_deltas: Vec<DeltaInt64> = deltas
-        .iter()
-        .filter(|delta| delta.key == StoreKey::current_day_id_key())
-        .cloned()
-        .collect();
-
-    let hourly_deltas: Vec<DeltaInt64> = deltas
-        .iter()
-        .filter(|delta| delta.key == StoreKey::current_hour_id_key())
-        .cloned()
-        .collect();
-
-    (
-        Deltas {
-            deltas: daily_deltas,
-        },
-        Deltas {
-            deltas: hourly_deltas,
-        },
-    )
+) -> (
+    Deltas<DeltaInt64>,
+    Deltas<DeltaInt64>,
+    Deltas<DeltaInt64>,
+    Deltas<DeltaInt64>,
+) {
+    let daily_deltas = filter_timeframe_deltas(deltas, &StoreKey::current_day_id_key());
+    let hourly_deltas = filter_timeframe_deltas(deltas, &StoreKey::current_hour_id_key());
+    let weekly_deltas = filter_timeframe_deltas(deltas, &StoreKey::current_week_id_key());
+    let monthly_deltas = filter_timeframe_deltas(deltas, &StoreKey::current_month_id_key());
+
+    (daily_deltas, hourly_deltas, weekly_deltas, monthly_deltas)
+}
+
+// Returns whether the current block is the first block of a new day.
+pub fn is_new_day(current_time_deltas: &Deltas<DeltaInt64>) -> bool {
+    let (daily_deltas, hourly_deltas, weekly_deltas, monthly_deltas) =
+        separate_timeframe_deltas(current_time_deltas);
+
+    let mut new_day = false;
+    TimeframeChangeHandler {
+        daily_deltas: &daily_deltas,
+        hourly_deltas: &hourly_deltas,
+        on_new_day: Box::new(|_| new_day = true),
+        on_new_hour: None,
+        weekly_deltas: &weekly_deltas,
+        monthly_deltas: &monthly_deltas,
+        on_new_week: None,
+        on_new_month: None,
+    }
+    .handle_timeframe_changes();
+
+    new_day
+}
+
+fn filter_timeframe_deltas(deltas: &Deltas<DeltaInt64>, key: &str) -> Deltas<DeltaInt64> {
+    Deltas {
+        deltas: deltas
+            .iter()
+            .filter(|delta| delta.key == key)
+            .cloned()
+            .collect(),
+    }
 }
 
 pub fn calculate_day_hour_id(timestamp_seconds: i64) -> (i64, i64) {
@@ -32,3 +57,30 @@ pub fn calculate_day_hour_id(timestamp_seconds: i64) -> (i64, i64) {
     let hour_id = timestamp_seconds / 3600; // Number of seconds in an hour
     (day_id, hour_id)
 }
+
+// Calculates the week and month IDs for a given timestamp.
+// The Unix epoch fell on a Thursday, so dividing by the number of seconds in a week gives us
+// weeks that start on Thursday 00:00 UTC, which lines up with Curve's gauge voting epochs.
+// The month ID is the number of calendar months since January 1970.
+pub fn calculate_week_month_id(timestamp_seconds: i64) -> (i64, i64) {
+    let week_id = timestamp_seconds / 604800; // Number of seconds in a week
+    let (year, month) = civil_year_month_from_days(timestamp_seconds / 86400);
+    let month_id = (year - 1970) * 12 + (month - 1);
+    (week_id, month_id)
+}
+
+// Converts a number of days since the Unix epoch into a (year, month) pair of the proleptic
+// Gregorian calendar, where month is in the range 1..=12.
+// Based on Howard Hinnant's `civil_from_days` algorithm.
+fn civil_year_month_from_days(days: i64) -> (i64, i64) {
+    let z = days + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let day_of_era = z - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month)
+}
@@ -0,0 +1,78 @@
+use std::str::FromStr;
+
+use substreams::scalar::BigInt;
+
+use crate::pb::curve::types::v1::{
+    ControllerLiquidationEvent, ControllerLoanEvent, LlammaExchangeEvent, LlammaLiquidityEvent,
+    PegKeeperEvent,
+};
+
+impl LlammaExchangeEvent {
+    pub fn parse_tokens_sold(&self) -> BigInt {
+        BigInt::from_str(&self.tokens_sold).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_tokens_bought(&self) -> BigInt {
+        BigInt::from_str(&self.tokens_bought).unwrap_or_else(|_| BigInt::zero())
+    }
+}
+
+impl LlammaLiquidityEvent {
+    pub fn parse_amount_collateral(&self) -> BigInt {
+        BigInt::from_str(&self.amount_collateral).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_amount_borrowed(&self) -> BigInt {
+        BigInt::from_str(&self.amount_borrowed).unwrap_or_else(|_| BigInt::zero())
+    }
+}
+
+impl ControllerLoanEvent {
+    pub fn parse_collateral_amount(&self) -> BigInt {
+        BigInt::from_str(&self.collateral_amount).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_debt_amount(&self) -> BigInt {
+        BigInt::from_str(&self.debt_amount).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_user_collateral(&self) -> BigInt {
+        BigInt::from_str(&self.user_collateral).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_user_stablecoin(&self) -> BigInt {
+        BigInt::from_str(&self.user_stablecoin).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_user_debt(&self) -> BigInt {
+        BigInt::from_str(&self.user_debt).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_total_debt(&self) -> BigInt {
+        BigInt::from_str(&self.total_debt).unwrap_or_else(|_| BigInt::zero())
+    }
+}
+
+impl ControllerLiquidationEvent {
+    pub fn parse_collateral_received(&self) -> BigInt {
+        BigInt::from_str(&self.collateral_received).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_stablecoin_received(&self) -> BigInt {
+        BigInt::from_str(&self.stablecoin_received).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_debt(&self) -> BigInt {
+        BigInt::from_str(&self.debt).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_total_debt(&self) -> BigInt {
+        BigInt::from_str(&self.total_debt).unwrap_or_else(|_| BigInt::zero())
+    }
+}
+
+impl PegKeeperEvent {
+    pub fn parse_amount(&self) -> BigInt {
+        BigInt::from_str(&self.amount).unwrap_or_else(|_| BigInt::zero())
+    }
+}
@@ -1,6 +1,15 @@
-use substreams::{scalar::BigInt, Hex};
+use std::{ops::Div, str::FromStr};
 
-use crate::pb::curve::types::v1::LiquidityGauge;
+use substreams::{
+    scalar::{BigDecimal, BigInt},
+    Hex,
+};
+
+use crate::pb::curve::types::v1::{
+    ChildGaugeEmissionEvent, CrvMintedEvent, DepositRewardTokenEvent, GaugeTransferEvent,
+    GaugeVote, GaugeWeightUpdate, LiquidityEvent, LiquidityGauge, RewardClaimEvent,
+    RootGaugeEmissionEvent,
+};
 
 impl LiquidityGauge {
     pub fn address_vec(&self) -> Vec<u8> {
@@ -8,6 +17,77 @@ impl LiquidityGauge {
     }
 }
 
+impl CrvMintedEvent {
+    pub fn parse_minted(&self) -> BigInt {
+        BigInt::from_str(&self.minted).unwrap_or_else(|_| BigInt::zero())
+    }
+}
+
+impl LiquidityEvent {
+    pub fn parse_value(&self) -> BigInt {
+        BigInt::from_str(&self.value).unwrap_or_else(|_| BigInt::zero())
+    }
+}
+
+impl GaugeTransferEvent {
+    pub fn parse_value(&self) -> BigInt {
+        BigInt::from_str(&self.value).unwrap_or_else(|_| BigInt::zero())
+    }
+}
+
+impl RewardClaimEvent {
+    pub fn parse_amount(&self) -> BigInt {
+        BigInt::from_str(&self.amount).unwrap_or_else(|_| BigInt::zero())
+    }
+}
+
+impl DepositRewardTokenEvent {
+    pub fn parse_amount(&self) -> BigInt {
+        BigInt::from_str(&self.amount).unwrap_or_else(|_| BigInt::zero())
+    }
+}
+
+impl RootGaugeEmissionEvent {
+    pub fn parse_total_transmitted(&self) -> BigInt {
+        BigInt::from_str(&self.total_transmitted).unwrap_or_else(|_| BigInt::zero())
+    }
+}
+
+impl ChildGaugeEmissionEvent {
+    pub fn parse_amount(&self) -> BigInt {
+        BigInt::from_str(&self.amount).unwrap_or_else(|_| BigInt::zero())
+    }
+}
+
+impl GaugeVote {
+    pub fn parse_weight(&self) -> BigInt {
+        BigInt::from_str(&self.weight).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_slope(&self) -> BigInt {
+        BigInt::from_str(&self.slope).unwrap_or_else(|_| BigInt::zero())
+    }
+}
+
+impl GaugeWeightUpdate {
+    pub fn parse_weight(&self) -> BigInt {
+        BigInt::from_str(&self.weight).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_total_weight(&self) -> BigInt {
+        BigInt::from_str(&self.total_weight).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    // The share of the total gauge weight allocated to this gauge, as a value between 0 and 1.
+    pub fn relative_weight(&self) -> BigDecimal {
+        let total_weight = self.parse_total_weight();
+        if total_weight == BigInt::zero() {
+            return BigDecimal::zero();
+        }
+        BigDecimal::from(self.parse_weight()).div(&BigDecimal::from(total_weight))
+    }
+}
+
 pub struct RewardData {
     pub token: Vec<u8>,
     pub distributor: Vec<u8>,
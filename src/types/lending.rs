@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use substreams::scalar::{BigDecimal, BigInt};
+
+use crate::pb::curve::types::v1::{LendingMarketState, VaultEvent};
+
+impl VaultEvent {
+    pub fn parse_assets(&self) -> BigInt {
+        BigInt::from_str(&self.assets).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_shares(&self) -> BigInt {
+        BigInt::from_str(&self.shares).unwrap_or_else(|_| BigInt::zero())
+    }
+}
+
+impl LendingMarketState {
+    pub fn parse_total_assets(&self) -> BigInt {
+        BigInt::from_str(&self.total_assets).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_total_shares(&self) -> BigInt {
+        BigInt::from_str(&self.total_shares).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_total_debt(&self) -> BigInt {
+        BigInt::from_str(&self.total_debt).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    // Share of the supplied assets that is currently borrowed, as a percentage.
+    pub fn utilization(&self) -> BigDecimal {
+        let total_assets = self.parse_total_assets();
+        if total_assets == BigInt::zero() {
+            return BigDecimal::zero();
+        }
+        self.parse_total_debt().to_decimal(0) / total_assets.to_decimal(0) * BigDecimal::from(100)
+    }
+
+    // Rates are reported as 1e18 based fractions, and converted to percentages.
+    pub fn borrow_apr(&self) -> BigDecimal {
+        parse_apr(&self.borrow_apr)
+    }
+
+    pub fn lend_apr(&self) -> BigDecimal {
+        parse_apr(&self.lend_apr)
+    }
+}
+
+fn parse_apr(apr: &String) -> BigDecimal {
+    BigInt::from_str(apr)
+        .unwrap_or_else(|_| BigInt::zero())
+        .to_decimal(16)
+}
@@ -1,5 +1,7 @@
+pub mod crvusd;
 pub mod event_traits;
 pub mod gauge;
+pub mod lending;
 pub mod pool;
 pub mod pool_event;
 pub mod pool_fees;
@@ -8,3 +10,4 @@ pub mod registry;
 pub mod timeframe;
 pub mod token;
 pub mod transfer;
+pub mod voting_escrow;
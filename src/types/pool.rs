@@ -1,6 +1,6 @@
 use substreams::Hex;
 
-use crate::pb::curve::types::v1::{Pool, Token};
+use crate::pb::curve::types::v1::{pool::PoolType, Pool, Token};
 
 impl Pool {
     pub fn address_vec(&self) -> Vec<u8> {
@@ -17,4 +17,30 @@ impl Pool {
             .chain(self.input_tokens.clone().into_iter())
             .collect()
     }
+
+    // Find a pool token by address, including the underlying tokens of metapools and lending pools.
+    pub fn find_token(&self, token_address: &str) -> Option<&Token> {
+        let underlying_tokens: &[Token] = match &self.pool_type {
+            Some(PoolType::MetaPool(meta_pool)) => &meta_pool.underlying_tokens,
+            Some(PoolType::LendingPool(lending_pool)) => &lending_pool.underlying_tokens,
+            _ => &[],
+        };
+        std::iter::once(self.output_token_ref())
+            .chain(self.input_tokens.iter())
+            .chain(underlying_tokens.iter())
+            .find(|token| token.address == token_address)
+    }
+
+    // Name of the pool's type, as used by the `PoolType` enum of the subgraph schema.
+    pub fn pool_type_name(&self) -> &'static str {
+        match &self.pool_type {
+            Some(PoolType::PlainPool(_)) | None => "PLAIN",
+            Some(PoolType::CryptoPool(_)) => "CRYPTO",
+            Some(PoolType::TricryptoPool(_)) => "TRICRYPTO",
+            Some(PoolType::TwocryptoPool(_)) => "TWOCRYPTO",
+            Some(PoolType::MetaPool(_)) => "META",
+            Some(PoolType::LendingPool(_)) => "LENDING",
+            Some(PoolType::WildcardPool(_)) => "WILDCARD",
+        }
+    }
 }
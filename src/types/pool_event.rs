@@ -143,4 +143,10 @@ impl FeeChangeEvent {
             None => default_admin_fee(),
         }
     }
+
+    pub fn offpeg_fee_multiplier_big(&self) -> Option<BigInt> {
+        self.offpeg_fee_multiplier
+            .as_ref()
+            .map(|multiplier| BigInt::from_str(multiplier.as_str()).unwrap())
+    }
 }
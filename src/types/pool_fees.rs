@@ -23,6 +23,36 @@ impl PoolFees {
         self.lp_fee.as_ref().unwrap()
     }
 
+    pub fn offpeg_fee_multiplier_big_decimal(&self) -> Option<BigDecimal> {
+        self.offpeg_fee_multiplier
+            .as_ref()
+            .map(|multiplier| BigDecimal::from_str(multiplier.as_str()).unwrap())
+    }
+
+    // Pools with an off-peg fee multiplier scale their fee up as the pool becomes imbalanced. This mirrors
+    // `_dynamic_fee` in the StableSwap-NG contracts, where `xpi` and `xpj` are the normalised balances of
+    // the tokens being exchanged. Returns the effective trading fee as a percentage.
+    pub fn effective_trading_fee_percentage(
+        &self,
+        xpi: &BigDecimal,
+        xpj: &BigDecimal,
+    ) -> BigDecimal {
+        let fee = self.trading_fee().fee_percentage_big_decimal();
+        let multiplier = match self.offpeg_fee_multiplier_big_decimal() {
+            Some(multiplier) if multiplier > BigDecimal::one() => multiplier,
+            _ => return fee,
+        };
+        let xps2 = (xpi.clone() + xpj.clone()) * (xpi.clone() + xpj.clone());
+        if xps2 == BigDecimal::zero() {
+            return fee;
+        }
+
+        (multiplier.clone() * fee)
+            / ((multiplier - BigDecimal::one()) * BigDecimal::from(4) * xpi.clone() * xpj.clone()
+                / xps2
+                + BigDecimal::one())
+    }
+
     pub fn string_ids(&self) -> Vec<String> {
         vec![
             self.trading_fee().id.clone(),
@@ -31,3 +61,91 @@ impl PoolFees {
         ]
     }
 }
+
+// The share of swap fee revenue a value refers to. Supply side revenue goes to liquidity providers,
+// and protocol side revenue is the admin fee taken by the protocol.
+#[derive(Clone, Copy)]
+pub enum RevenueSide {
+    Total,
+    ProtocolSide,
+    SupplySide,
+}
+
+impl RevenueSide {
+    pub const ALL: [RevenueSide; 3] = [
+        RevenueSide::Total,
+        RevenueSide::ProtocolSide,
+        RevenueSide::SupplySide,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RevenueSide::Total => "Total",
+            RevenueSide::ProtocolSide => "ProtocolSide",
+            RevenueSide::SupplySide => "SupplySide",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use substreams::scalar::{BigDecimal, BigInt};
+
+    use super::*;
+    use crate::rpc::pool::calculate_pool_fees;
+
+    // A 0.04% fee with a 50% admin fee, as the raw values returned by the pool contracts.
+    fn pool_fees(offpeg_fee_multiplier: Option<i64>) -> PoolFees {
+        calculate_pool_fees(
+            BigInt::from(4000000),
+            BigInt::from(5000000000 as i64),
+            offpeg_fee_multiplier.map(BigInt::from),
+            &vec![0u8; 20],
+        )
+    }
+
+    fn decimal(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_effective_trading_fee_balanced_pool() {
+        let fees = pool_fees(Some(20000000000));
+        assert_eq!(
+            fees.effective_trading_fee_percentage(&decimal("1000"), &decimal("1000")),
+            decimal("0.04")
+        );
+    }
+
+    #[test]
+    fn test_effective_trading_fee_imbalanced_pool() {
+        let fees = pool_fees(Some(20000000000));
+        let fee = fees.effective_trading_fee_percentage(&decimal("1500"), &decimal("500"));
+
+        // 2 * 0.04 / ((2 - 1) * 4 * 1500 * 500 / 2000^2 + 1) = 0.08 / 1.75
+        assert!(fee > decimal("0.045714285714285"));
+        assert!(fee < decimal("0.045714285714286"));
+    }
+
+    #[test]
+    fn test_effective_trading_fee_multiplier_at_most_one() {
+        for multiplier in [10000000000, 5000000000] {
+            let fees = pool_fees(Some(multiplier));
+            assert_eq!(
+                fees.effective_trading_fee_percentage(&decimal("1500"), &decimal("500")),
+                decimal("0.04")
+            );
+        }
+    }
+
+    #[test]
+    fn test_effective_trading_fee_without_multiplier() {
+        let fees = pool_fees(None);
+        assert_eq!(
+            fees.effective_trading_fee_percentage(&decimal("1500"), &decimal("500")),
+            decimal("0.04")
+        );
+    }
+}
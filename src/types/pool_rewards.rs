@@ -22,4 +22,31 @@ impl PoolRewards {
     pub fn parse_staked_output_token_amount(&self) -> BigInt {
         BigInt::from_str(&self.staked_output_token_amount).unwrap_or_else(|_| BigInt::zero())
     }
+
+    pub fn parse_reward_token_base_aprs(&self) -> Vec<BigDecimal> {
+        parse_aprs(&self.reward_token_base_aprs)
+    }
+
+    pub fn parse_reward_token_max_boosted_aprs(&self) -> Vec<BigDecimal> {
+        parse_aprs(&self.reward_token_max_boosted_aprs)
+    }
+
+    // The reward APR of the pool is the sum of the APRs of each of its reward tokens
+    pub fn base_reward_apr(&self) -> BigDecimal {
+        self.parse_reward_token_base_aprs()
+            .into_iter()
+            .fold(BigDecimal::zero(), |total, apr| total + apr)
+    }
+
+    pub fn max_boosted_reward_apr(&self) -> BigDecimal {
+        self.parse_reward_token_max_boosted_aprs()
+            .into_iter()
+            .fold(BigDecimal::zero(), |total, apr| total + apr)
+    }
+}
+
+fn parse_aprs(aprs: &Vec<String>) -> Vec<BigDecimal> {
+    aprs.iter()
+        .map(|apr| BigDecimal::from_str(apr).unwrap_or_else(|_| BigDecimal::zero()))
+        .collect()
 }
@@ -1,4 +1,6 @@
 pub enum Timeframe {
     Daily,
     Hourly,
+    Weekly,
+    Monthly,
 }
@@ -1,9 +1,14 @@
 use substreams::Hex;
 
-use crate::pb::curve::types::v1::Token;
+use crate::pb::curve::types::v1::{AssetType, Token};
 
 impl Token {
     pub fn address_vec(&self) -> Vec<u8> {
         Hex::decode(&self.address).unwrap()
     }
+
+    // Oracle and ERC4626 assets in StableSwap-NG pools are valued by the pool using a rate multiplier.
+    pub fn is_rated_asset(&self) -> bool {
+        matches!(self.asset_type(), AssetType::Oracle | AssetType::Erc4626)
+    }
 }
@@ -0,0 +1,89 @@
+use std::str::FromStr;
+
+use substreams::scalar::BigInt;
+
+use crate::{
+    constants::VOTING_ESCROW_MAX_LOCK_TIME,
+    pb::curve::types::v1::{VotingEscrowLock, VotingEscrowLockEvent, VotingEscrowSupplyEvent},
+};
+
+impl VotingEscrowLockEvent {
+    pub fn parse_value(&self) -> BigInt {
+        BigInt::from_str(&self.value).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_locked_amount(&self) -> BigInt {
+        BigInt::from_str(&self.locked_amount).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_unlock_time(&self) -> BigInt {
+        BigInt::from_str(&self.unlock_time).unwrap_or_else(|_| BigInt::zero())
+    }
+}
+
+impl VotingEscrowSupplyEvent {
+    pub fn parse_supply(&self) -> BigInt {
+        BigInt::from_str(&self.supply).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_total_voting_power(&self) -> BigInt {
+        BigInt::from_str(&self.total_voting_power).unwrap_or_else(|_| BigInt::zero())
+    }
+}
+
+impl VotingEscrowLock {
+    // Mirrors the checkpoint logic of the VotingEscrow contract, where the slope is derived from the
+    // locked amount, and the bias is the voting power at the time of the checkpoint.
+    pub fn from_lock_event(event: &VotingEscrowLockEvent) -> Self {
+        let amount = event.parse_locked_amount();
+        let unlock_time = event.parse_unlock_time();
+        let timestamp = BigInt::from(event.timestamp);
+
+        let (slope, bias) = if amount > BigInt::zero() && unlock_time > timestamp {
+            let slope = amount.clone() / BigInt::from(VOTING_ESCROW_MAX_LOCK_TIME);
+            let bias = slope.clone() * (unlock_time.clone() - timestamp);
+            (slope, bias)
+        } else {
+            (BigInt::zero(), BigInt::zero())
+        };
+
+        VotingEscrowLock {
+            provider: event.provider.clone(),
+            amount: amount.to_string(),
+            unlock_time: unlock_time.to_string(),
+            slope: slope.to_string(),
+            bias: bias.to_string(),
+            updated_at_timestamp: event.timestamp,
+            updated_at_block_number: event.block_number,
+        }
+    }
+
+    pub fn parse_amount(&self) -> BigInt {
+        BigInt::from_str(&self.amount).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_unlock_time(&self) -> BigInt {
+        BigInt::from_str(&self.unlock_time).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_slope(&self) -> BigInt {
+        BigInt::from_str(&self.slope).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    pub fn parse_bias(&self) -> BigInt {
+        BigInt::from_str(&self.bias).unwrap_or_else(|_| BigInt::zero())
+    }
+
+    // Voting power decays linearly from the last checkpoint, reaching zero at the unlock time.
+    pub fn voting_power_at(&self, timestamp: u64) -> BigInt {
+        if timestamp <= self.updated_at_timestamp {
+            return self.parse_bias();
+        }
+        let elapsed = BigInt::from(timestamp - self.updated_at_timestamp);
+        let voting_power = self.parse_bias() - self.parse_slope() * elapsed;
+        if voting_power < BigInt::zero() {
+            return BigInt::zero();
+        }
+        voting_power
+    }
+}